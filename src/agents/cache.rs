@@ -130,7 +130,7 @@ impl GitHubCache {
     }
 
     /// Get the cache file path
-    fn cache_path() -> Option<PathBuf> {
+    pub(crate) fn cache_path() -> Option<PathBuf> {
         dirs::config_dir().map(|p| p.join("models").join(CACHE_FILENAME))
     }
 
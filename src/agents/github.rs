@@ -7,7 +7,7 @@ use tokio::sync::RwLock;
 use super::cache::{CachedGitHubData, GitHubCache, SerializableGitHubData};
 use super::{GitHubData, Release};
 
-const GITHUB_API_BASE: &str = "https://api.github.com";
+pub(crate) const GITHUB_API_BASE: &str = "https://api.github.com";
 
 /// Detect a GitHub token for authenticated API access (5,000 req/hr vs 60).
 /// Tries `gh auth token` first (works if user has gh CLI installed and logged in),
@@ -180,13 +180,8 @@ impl AsyncGitHubClient {
     }
 
     pub fn with_disk_cache(token: Option<String>, disk_cache: Arc<RwLock<GitHubCache>>) -> Self {
-        let client = reqwest::Client::builder()
-            .user_agent("models-tui")
-            .build()
-            .expect("Failed to build HTTP client");
-
         Self {
-            client,
+            client: crate::http::client("models-tui"),
             disk_cache,
             token,
         }
@@ -199,7 +194,10 @@ impl AsyncGitHubClient {
             request = request.header("Authorization", format!("Bearer {}", token));
         }
 
-        let response = request.send().await?;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!(crate::http::describe_error(&e)))?;
 
         if response.status() == 403 {
             return Err(anyhow!("GitHub API rate limit exceeded"));
@@ -209,12 +207,27 @@ impl AsyncGitHubClient {
             return Err(anyhow!("GitHub API error: {}", response.status()));
         }
 
-        Ok(response.json().await?)
+        response
+            .json()
+            .await
+            .map_err(|e| anyhow!(crate::http::describe_error(&e)))
+    }
+
+    /// Serve a repo from the disk cache without a request (`--offline`).
+    async fn offline_result(&self, repo: &str) -> ConditionalFetchResult {
+        if self.disk_cache.read().await.get(repo).is_some() {
+            ConditionalFetchResult::NotModified
+        } else {
+            ConditionalFetchResult::Error("No cached data (offline mode)".to_string())
+        }
     }
 
     /// Fetch only releases for a repo (single API call, no repo metadata).
     /// Used by CLI commands that don't need stars/issues/license.
     pub async fn fetch_releases_only(&self, repo: &str) -> ConditionalFetchResult {
+        if crate::offline::is_offline() {
+            return self.offline_result(repo).await;
+        }
         let releases_url = format!("{}/repos/{}/releases", GITHUB_API_BASE, repo);
         match self.get_json::<Vec<ReleaseResponse>>(&releases_url).await {
             Ok(releases) => {
@@ -273,6 +286,9 @@ impl AsyncGitHubClient {
     /// If we have cached data with an ETag for this repo, we send an If-None-Match header.
     /// If GitHub returns 304 Not Modified, we know our cached data is still valid.
     pub async fn fetch_conditional(&self, repo: &str) -> ConditionalFetchResult {
+        if crate::offline::is_offline() {
+            return self.offline_result(repo).await;
+        }
         // Check disk cache for existing ETag
         let (cached_etag, cached_entry) = {
             let cache = self.disk_cache.read().await;
@@ -297,7 +313,7 @@ impl AsyncGitHubClient {
         // Send the request
         let response = match request.send().await {
             Ok(resp) => resp,
-            Err(e) => return ConditionalFetchResult::Error(e.to_string()),
+            Err(e) => return ConditionalFetchResult::Error(crate::http::describe_error(&e)),
         };
 
         // Handle 304 Not Modified
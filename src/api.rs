@@ -1,14 +1,374 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 
 use crate::data::ProvidersMap;
+use crate::formatting::format_relative_time;
+use crate::offline::OfflineUnavailable;
 
-const API_URL: &str = "https://models.dev/api.json";
+pub(crate) const DEFAULT_API_URL: &str = "https://models.dev/api.json";
 
-pub fn fetch_providers() -> Result<ProvidersMap> {
-    let response =
-        reqwest::blocking::get(API_URL).context("Failed to fetch data from models.dev API")?;
+/// Environment override for the providers URL (a mirror or fixture server)
+const API_URL_ENV: &str = "MODELS_DEV_URL";
+
+static API_URL_FLAG: OnceLock<String> = OnceLock::new();
+
+static DATA_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+static LAST_RESPONSE: OnceLock<ResponseInfo> = OnceLock::new();
+
+/// A fetch parsing fewer than this fraction of the cached model count is
+/// flagged as possible upstream schema breakage.
+const MODEL_DROP_RATIO: f64 = 0.5;
+
+/// Set once from `--api-url`, before any fetch starts.
+pub fn set_api_url(url: String) {
+    let _ = API_URL_FLAG.set(url);
+}
 
-    let providers: ProvidersMap = response.json().context("Failed to parse API response")?;
+/// Set once from `--data-file`: read providers from this file instead of fetching.
+pub fn set_data_file(path: PathBuf) {
+    let _ = DATA_FILE.set(path);
+}
+
+/// The `--data-file` path, if one was given.
+pub fn data_file() -> Option<&'static Path> {
+    DATA_FILE.get().map(PathBuf::as_path)
+}
+
+/// clap value parser for `--api-url`: an absolute http(s) URL.
+pub fn parse_api_url(url: &str) -> Result<String, String> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(url.to_string()),
+        Ok(parsed) => Err(format!("unsupported scheme '{}'", parsed.scheme())),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// `--api-url`, then `MODELS_DEV_URL`, then models.dev. An invalid
+/// environment value falls back to the default.
+fn resolve_api_url(flag: Option<&str>, env: Option<&str>) -> String {
+    flag.or(env.filter(|url| parse_api_url(url).is_ok()))
+        .unwrap_or(DEFAULT_API_URL)
+        .to_string()
+}
 
+/// The providers URL every fetch uses.
+pub fn api_url() -> String {
+    let env = std::env::var(API_URL_ENV).ok();
+    resolve_api_url(API_URL_FLAG.get().map(String::as_str), env.as_deref())
+}
+
+/// Last successful API response, used as a fallback when the network fetch fails
+const CACHE_FILENAME: &str = "api-cache.json";
+
+/// Where the provider data shown to the user came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataSource {
+    /// Fetched from models.dev during this run
+    #[default]
+    Live,
+    /// Loaded from the on-disk cache after the network fetch failed
+    Cached { fetched_at: DateTime<Utc> },
+    /// Read from `--data-file`
+    File,
+}
+
+impl DataSource {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Live => "live".to_string(),
+            Self::Cached { fetched_at } => {
+                format!("cached {}", format_relative_time(fetched_at))
+            }
+            Self::File => "local file".to_string(),
+        }
+    }
+}
+
+/// Version headers and parsed size of this run's live models.dev response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseInfo {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub model_count: usize,
+    /// Model count of the cached response this fetch replaced
+    pub previous_count: Option<usize>,
+}
+
+impl ResponseInfo {
+    /// `etag "abc", last modified <date>`, or `None` without either header.
+    pub fn version_label(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.etag.as_ref().map(|etag| format!("etag {}", etag)),
+            self.last_modified
+                .as_ref()
+                .map(|date| format!("last modified {}", date)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    /// Warning when far fewer models parsed than last time: renamed or
+    /// retyped upstream fields can make serde skip models silently.
+    pub fn schema_warning(&self) -> Option<String> {
+        let previous = self.previous_count?;
+        ((self.model_count as f64) < previous as f64 * MODEL_DROP_RATIO).then(|| {
+            format!(
+                "models.dev returned {} models, down from {} cached; the API schema may have changed",
+                self.model_count, previous
+            )
+        })
+    }
+
+    /// Whether the response may replace the cache: an empty or suspiciously
+    /// shrunken one is still shown this run but never overwrites a good cache.
+    fn worth_caching(&self) -> bool {
+        self.model_count > 0 && self.schema_warning().is_none()
+    }
+}
+
+/// What this run's live fetch saw, if one succeeded.
+pub fn last_response() -> Option<&'static ResponseInfo> {
+    LAST_RESPONSE.get()
+}
+
+/// Read the version headers off a models.dev response (GET or HEAD).
+pub(crate) fn version_headers(
+    headers: &reqwest::header::HeaderMap,
+) -> (Option<String>, Option<String>) {
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    (
+        header(reqwest::header::ETAG),
+        header(reqwest::header::LAST_MODIFIED),
+    )
+}
+
+fn count_models(providers: &ProvidersMap) -> usize {
+    providers.values().map(|p| p.models.len()).sum()
+}
+
+/// CLI entry point: like `fetch_providers_with_source`, but notes a cache
+/// fallback or a suspicious drop in models on stderr (silenced by `--quiet`).
+pub fn fetch_providers() -> Result<ProvidersMap> {
+    let (providers, source) = fetch_providers_with_source()?;
+    if matches!(source, DataSource::Cached { .. }) {
+        crate::notice::info(&format!(
+            "models.dev unreachable; using {} data",
+            source.label()
+        ));
+    }
+    if let Some(warning) = last_response().and_then(ResponseInfo::schema_warning) {
+        crate::notice::info(&format!("warning: {}", warning));
+    }
     Ok(providers)
 }
+
+/// Providers for callers that don't need fresh data: `--data-file`, else the
+/// cache at any age, fetching only when nothing is cached yet.
+pub fn cached_providers() -> Result<ProvidersMap> {
+    if let Some(path) = data_file() {
+        return load_data_file(path);
+    }
+    match load_cache() {
+        Some((providers, _)) => Ok(providers),
+        None => fetch_providers(),
+    }
+}
+
+/// Fetch providers from models.dev, falling back to the last cached response
+/// when the request fails, returns an error status, or doesn't parse. The
+/// cache is refreshed by every successful fetch that isn't empty or far
+/// smaller than the cache (see `ResponseInfo::schema_warning`).
+/// With `--offline`, only the cache is consulted. `--data-file` replaces both
+/// the network and the cache.
+pub fn fetch_providers_with_source() -> Result<(ProvidersMap, DataSource)> {
+    if let Some(path) = data_file() {
+        return Ok((load_data_file(path)?, DataSource::File));
+    }
+    if crate::offline::is_offline() {
+        return load_cache().ok_or_else(|| {
+            OfflineUnavailable(
+                "No cached model data; run once online to populate the cache".to_string(),
+            )
+            .into()
+        });
+    }
+    // A body that doesn't parse falls back to the cache like a network error
+    let fetched = fetch_live().and_then(|(body, info)| {
+        let providers: ProvidersMap =
+            serde_json::from_str(&body).context("Failed to parse API response")?;
+        Ok((body, info, providers))
+    });
+    match fetched {
+        Ok((body, mut info, providers)) => {
+            info.model_count = count_models(&providers);
+            info.previous_count = load_cache().map(|(cached, _)| count_models(&cached));
+            if info.worth_caching() {
+                save_cache(&body);
+            }
+            let _ = LAST_RESPONSE.set(info);
+            Ok((providers, DataSource::Live))
+        }
+        Err(err) => match load_cache() {
+            Some(cached) => Ok(cached),
+            None => Err(err),
+        },
+    }
+}
+
+/// Parse a saved `api.json`, the same way as a network response.
+pub(crate) fn load_data_file(path: &Path) -> Result<ProvidersMap> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read data file {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse data file {}", path.display()))
+}
+
+/// The response body plus its version headers (counts are filled in by the caller).
+fn fetch_live() -> Result<(String, ResponseInfo)> {
+    let client = crate::http::blocking_client("models");
+    let response = client.get(api_url()).send().map_err(|err| {
+        let msg = if err.is_timeout() {
+            "models.dev API request timed out".to_string()
+        } else if let Some(hint) = crate::http::tls_hint(&err) {
+            format!("Failed to fetch data from models.dev API ({})", hint)
+        } else {
+            "Failed to fetch data from models.dev API".to_string()
+        };
+        anyhow::Error::new(err).context(msg)
+    })?;
+    let response = response
+        .error_for_status()
+        .context("models.dev API returned an error")?;
+    let (etag, last_modified) = version_headers(response.headers());
+    let body = response.text().map_err(|err| {
+        let msg = if err.is_timeout() {
+            "models.dev API request timed out"
+        } else {
+            "Failed to read API response"
+        };
+        anyhow::Error::new(err).context(msg)
+    })?;
+    let info = ResponseInfo {
+        etag,
+        last_modified,
+        ..ResponseInfo::default()
+    };
+    Ok((body, info))
+}
+
+pub(crate) fn cache_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("models").join(CACHE_FILENAME))
+}
+
+/// Best-effort write; a failed cache write never fails the fetch.
+fn save_cache(body: &str) {
+    let Some(path) = cache_path() else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let _ = fs::write(path, body);
+}
+
+fn load_cache() -> Option<(ProvidersMap, DataSource)> {
+    let path = cache_path()?;
+    let contents = fs::read_to_string(&path).ok()?;
+    let providers: ProvidersMap = serde_json::from_str(&contents).ok()?;
+    let fetched_at: DateTime<Utc> = fs::metadata(&path).ok()?.modified().ok()?.into();
+    Some((providers, DataSource::Cached { fetched_at }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_url_prefers_flag_then_valid_env() {
+        assert_eq!(resolve_api_url(None, None), DEFAULT_API_URL);
+        assert_eq!(
+            resolve_api_url(None, Some("http://localhost:8000/api.json")),
+            "http://localhost:8000/api.json"
+        );
+        assert_eq!(resolve_api_url(None, Some("not a url")), DEFAULT_API_URL);
+        assert_eq!(
+            resolve_api_url(Some("https://mirror.example/api.json"), Some("http://x/")),
+            "https://mirror.example/api.json"
+        );
+        assert!(parse_api_url("ftp://mirror.example/api.json").is_err());
+    }
+
+    #[test]
+    fn load_data_file_reads_and_reports_path() {
+        let dir = std::env::temp_dir().join(format!("models-data-file-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let valid = dir.join("api.json");
+        fs::write(
+            &valid,
+            r#"{"openai": {"id": "openai", "name": "OpenAI", "env": [], "models": {}}}"#,
+        )
+        .unwrap();
+        let providers = load_data_file(&valid).expect("valid file should parse");
+        assert!(providers.contains_key("openai"));
+
+        let malformed = dir.join("broken.json");
+        fs::write(&malformed, "{not json").unwrap();
+        let err = format!("{:#}", load_data_file(&malformed).unwrap_err());
+        assert!(err.contains("Failed to parse data file"));
+        assert!(err.contains("broken.json"));
+
+        let err = format!(
+            "{:#}",
+            load_data_file(&dir.join("missing.json")).unwrap_err()
+        );
+        assert!(err.contains("Failed to read data file"));
+        assert!(err.contains("missing.json"));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn response_info_flags_sharp_model_drops() {
+        let mut info = ResponseInfo {
+            etag: Some("\"abc\"".to_string()),
+            model_count: 900,
+            previous_count: Some(1000),
+            ..ResponseInfo::default()
+        };
+        assert_eq!(info.version_label().as_deref(), Some("etag \"abc\""));
+        assert_eq!(info.schema_warning(), None);
+        assert!(info.worth_caching());
+
+        info.model_count = 300;
+        let warning = info.schema_warning().expect("drop should warn");
+        assert!(warning.contains("300 models, down from 1000"));
+        assert!(!info.worth_caching());
+
+        info.previous_count = None;
+        assert_eq!(info.schema_warning(), None);
+        assert!(info.worth_caching());
+        info.model_count = 0;
+        assert!(!info.worth_caching());
+        assert_eq!(ResponseInfo::default().version_label(), None);
+    }
+
+    #[test]
+    fn data_source_label_reports_cache_age() {
+        assert_eq!(DataSource::Live.label(), "live");
+        let fetched_at = Utc::now() - chrono::Duration::days(3);
+        assert_eq!(DataSource::Cached { fetched_at }.label(), "cached 3d ago");
+    }
+}
@@ -0,0 +1,132 @@
+//! Disk cache for the benchmark CDN payload.
+//!
+//! Lets launches within the TTL skip the CDN fetch, and serves the last
+//! payload when the fetch fails or `--offline` is set. The payload a fresh
+//! fetch replaces is kept as the previous snapshot for `BenchmarkDiff`.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::BenchmarkEntry;
+
+/// Cache file version for future migration support
+pub const CACHE_VERSION: u32 = 1;
+
+/// Cache file name
+const CACHE_FILENAME: &str = "benchmarks-cache.json";
+
+/// The cache as it was before the last fresh fetch
+const PREVIOUS_FILENAME: &str = "benchmarks-previous.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkCache {
+    pub version: u32,
+    pub fetched_at: DateTime<Utc>,
+    pub entries: Vec<BenchmarkEntry>,
+}
+
+impl BenchmarkCache {
+    /// Wrap a fresh CDN payload, stamped with the current time
+    pub fn new(entries: Vec<BenchmarkEntry>) -> Self {
+        Self {
+            version: CACHE_VERSION,
+            fetched_at: Utc::now(),
+            entries,
+        }
+    }
+
+    pub(crate) fn cache_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("models").join(CACHE_FILENAME))
+    }
+
+    fn previous_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("models").join(PREVIOUS_FILENAME))
+    }
+
+    /// Load the cache from disk; `None` if missing, unreadable, or from another version
+    pub fn load() -> Option<Self> {
+        Self::load_from_path(&Self::cache_path()?)
+    }
+
+    fn load_from_path(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        let cache: Self = serde_json::from_str(&contents).ok()?;
+        (cache.version == CACHE_VERSION).then_some(cache)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::cache_path()
+            .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
+        self.save_to_path(&path)
+    }
+
+    /// Load the snapshot replaced by the last fresh fetch
+    pub fn load_previous() -> Option<Self> {
+        Self::load_from_path(&Self::previous_path()?)
+    }
+
+    /// Keep this payload as the previous snapshot
+    pub fn save_as_previous(&self) -> Result<()> {
+        let path = Self::previous_path()
+            .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
+        self.save_to_path(&path)
+    }
+
+    /// Drop the previous snapshot, so a first fetch after the cache was
+    /// cleared isn't compared against stale data
+    pub fn clear_previous() {
+        if let Some(path) = Self::previous_path() {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    fn save_to_path(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Whether the cached payload is younger than `ttl`
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        let age = Utc::now().signed_duration_since(self.fetched_at);
+        age.to_std().is_ok_and(|age| age < ttl)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_fresh_respects_ttl() {
+        let mut cache = BenchmarkCache::new(Vec::new());
+        let ttl = Duration::from_secs(3600);
+        assert!(cache.is_fresh(ttl));
+
+        cache.fetched_at = Utc::now() - chrono::Duration::hours(2);
+        assert!(!cache.is_fresh(ttl));
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = std::env::temp_dir().join(format!("models-bench-cache-{}", std::process::id()));
+        let path = dir.join(CACHE_FILENAME);
+        let entry: BenchmarkEntry =
+            serde_json::from_str(r#"{"name": "GPT-4o", "slug": "gpt-4o"}"#).unwrap();
+        let cache = BenchmarkCache::new(vec![entry]);
+        cache.save_to_path(&path).unwrap();
+
+        let loaded = BenchmarkCache::load_from_path(&path).expect("cache should load");
+        assert_eq!(loaded.entries.len(), 1);
+        assert_eq!(loaded.entries[0].slug, "gpt-4o");
+        assert_eq!(loaded.fetched_at, cache.fetched_at);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
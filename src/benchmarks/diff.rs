@@ -0,0 +1,191 @@
+//! What changed between the previous benchmark snapshot and a fresh fetch.
+//!
+//! `fetch_with_cache` keeps the payload it replaces as `benchmarks-previous.json`,
+//! so the TUI can summarize a fresh fetch and `benchmarks changes` can list it.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::{BenchmarkCache, BenchmarkEntry};
+
+type ScoreExtract = fn(&BenchmarkEntry) -> Option<f64>;
+
+/// Scores compared between snapshots: (CLI metric name, field).
+const SCORE_FIELDS: &[(&str, ScoreExtract)] = &[
+    ("intelligence", |e| e.intelligence_index),
+    ("coding", |e| e.coding_index),
+    ("math", |e| e.math_index),
+    ("mmlu-pro", |e| e.mmlu_pro),
+    ("gpqa", |e| e.gpqa),
+    ("hle", |e| e.hle),
+    ("livecodebench", |e| e.livecodebench),
+    ("scicode", |e| e.scicode),
+    ("ifbench", |e| e.ifbench),
+    ("lcr", |e| e.lcr),
+    ("terminalbench", |e| e.terminalbench_hard),
+    ("tau2", |e| e.tau2),
+    ("math-500", |e| e.math_500),
+    ("aime", |e| e.aime),
+    ("aime-25", |e| e.aime_25),
+];
+
+/// A model present in only one of the two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SnapshotModel {
+    pub slug: String,
+    pub name: String,
+}
+
+impl SnapshotModel {
+    fn from_entry(entry: &BenchmarkEntry) -> Self {
+        Self {
+            slug: entry.slug.clone(),
+            name: entry.name.clone(),
+        }
+    }
+}
+
+/// One score that differs between snapshots. `None` means the score was
+/// missing on that side.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreChange {
+    pub slug: String,
+    pub name: String,
+    pub metric: &'static str,
+    pub old: Option<f64>,
+    pub new: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BenchmarkDiff {
+    pub added: Vec<SnapshotModel>,
+    pub removed: Vec<SnapshotModel>,
+    pub changed: Vec<ScoreChange>,
+}
+
+impl BenchmarkDiff {
+    /// Compare two payloads by slug. Output follows the order of `new`
+    /// (`removed` follows `old`).
+    pub fn between(old: &[BenchmarkEntry], new: &[BenchmarkEntry]) -> Self {
+        let old_by_slug: HashMap<&str, &BenchmarkEntry> =
+            old.iter().map(|e| (e.slug.as_str(), e)).collect();
+        let new_by_slug: HashMap<&str, &BenchmarkEntry> =
+            new.iter().map(|e| (e.slug.as_str(), e)).collect();
+
+        let mut diff = Self::default();
+        for entry in new {
+            let Some(previous) = old_by_slug.get(entry.slug.as_str()) else {
+                diff.added.push(SnapshotModel::from_entry(entry));
+                continue;
+            };
+            for (metric, extract) in SCORE_FIELDS {
+                let (before, after) = (extract(previous), extract(entry));
+                if !same_score(before, after) {
+                    diff.changed.push(ScoreChange {
+                        slug: entry.slug.clone(),
+                        name: entry.name.clone(),
+                        metric,
+                        old: before,
+                        new: after,
+                    });
+                }
+            }
+        }
+        diff.removed = old
+            .iter()
+            .filter(|e| !new_by_slug.contains_key(e.slug.as_str()))
+            .map(SnapshotModel::from_entry)
+            .collect();
+        diff
+    }
+
+    /// Diff `entries` against the previous snapshot on disk; `None` when there
+    /// is no previous snapshot.
+    pub fn since_previous(entries: &[BenchmarkEntry]) -> Option<Self> {
+        let previous = BenchmarkCache::load_previous()?;
+        Some(Self::between(&previous.entries, entries))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// One-line summary such as "3 new models, 5 score updates"; `None` when
+    /// nothing changed.
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            (self.added.len(), "new model", "new models"),
+            (self.changed.len(), "score update", "score updates"),
+            (self.removed.len(), "removed model", "removed models"),
+        ]
+        .into_iter()
+        .filter(|(count, _, _)| *count > 0)
+        .map(|(count, one, many)| format!("{count} {}", if count == 1 { one } else { many }))
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+fn same_score(a: Option<f64>, b: Option<f64>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => (a - b).abs() < 1e-9,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(slug: &str, intelligence: Option<f64>, coding: Option<f64>) -> BenchmarkEntry {
+        let mut entry: BenchmarkEntry =
+            serde_json::from_value(serde_json::json!({ "slug": slug, "name": slug })).unwrap();
+        entry.intelligence_index = intelligence;
+        entry.coding_index = coding;
+        entry
+    }
+
+    #[test]
+    fn between_reports_added_removed_and_changed_scores() {
+        let old = vec![
+            entry("gpt-4o", Some(40.0), Some(30.0)),
+            entry("o1", Some(50.0), None),
+            entry("retired", Some(10.0), None),
+        ];
+        let new = vec![
+            entry("gpt-4o", Some(40.0), Some(30.0)),
+            entry("o1", Some(52.5), Some(45.0)),
+            entry("o3", Some(60.0), None),
+        ];
+
+        let diff = BenchmarkDiff::between(&old, &new);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].slug, "o3");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].slug, "retired");
+        assert_eq!(
+            diff.changed
+                .iter()
+                .map(|c| (c.slug.as_str(), c.metric, c.old, c.new))
+                .collect::<Vec<_>>(),
+            vec![
+                ("o1", "intelligence", Some(50.0), Some(52.5)),
+                ("o1", "coding", None, Some(45.0)),
+            ]
+        );
+        assert_eq!(
+            diff.summary().as_deref(),
+            Some("1 new model, 2 score updates, 1 removed model")
+        );
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_summary() {
+        let entries = vec![entry("gpt-4o", Some(40.0), None)];
+        let diff = BenchmarkDiff::between(&entries, &entries);
+        assert!(diff.is_empty());
+        assert_eq!(diff.summary(), None);
+    }
+}
@@ -1,14 +1,28 @@
 //! Async HTTP client for fetching benchmark data from jsDelivr CDN.
 
-use super::BenchmarkEntry;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use super::{BenchmarkCache, BenchmarkEntry};
 
 const CDN_URL: &str = "https://cdn.jsdelivr.net/gh/arimxyer/models@main/data/benchmarks.json";
 
+/// Minimum share of entries that must carry a non-empty `slug` and `name`
+/// for a payload to be accepted.
+const MIN_VALID_FRACTION: f64 = 0.9;
+
 /// Result of a fetch operation.
 #[derive(Debug)]
 pub enum BenchmarkFetchResult {
     /// New data fetched successfully.
     Fresh(Vec<BenchmarkEntry>),
+    /// Served from the disk cache (still within TTL, offline, or after a failed fetch).
+    Cached(Vec<BenchmarkEntry>, DateTime<Utc>),
+    /// The request exceeded the HTTP timeout.
+    TimedOut,
+    /// The CDN's TLS certificate was rejected (see `http::tls_hint`).
+    CertificateRejected,
     /// Fetch failed.
     Error,
 }
@@ -19,18 +33,52 @@ pub struct BenchmarkFetcher {
 
 impl BenchmarkFetcher {
     pub fn new() -> Self {
-        let client = reqwest::Client::builder()
-            .user_agent("models-tui")
-            .build()
-            .expect("Failed to build HTTP client");
+        Self {
+            client: crate::http::client("models-tui"),
+        }
+    }
+
+    /// Serve the disk cache while it is younger than `ttl`, otherwise fetch from
+    /// the CDN and refresh the cache, keeping the replaced cache as the previous
+    /// snapshot. `force` skips the freshness check. A failed fetch falls back to
+    /// the cache regardless of age.
+    pub async fn fetch_with_cache(&self, ttl: Duration, force: bool) -> BenchmarkFetchResult {
+        let cache = BenchmarkCache::load();
+        if let Some(cache) = cache.as_ref().filter(|c| !force && c.is_fresh(ttl)) {
+            return BenchmarkFetchResult::Cached(cache.entries.clone(), cache.fetched_at);
+        }
 
-        Self { client }
+        match self.fetch().await {
+            BenchmarkFetchResult::Fresh(entries) => {
+                // Best-effort: a failed cache write never fails the fetch
+                match cache.as_ref() {
+                    Some(cache) => {
+                        let _ = cache.save_as_previous();
+                    }
+                    None => BenchmarkCache::clear_previous(),
+                }
+                let _ = BenchmarkCache::new(entries.clone()).save();
+                BenchmarkFetchResult::Fresh(entries)
+            }
+            failed => match cache {
+                Some(cache) => BenchmarkFetchResult::Cached(cache.entries, cache.fetched_at),
+                None => failed,
+            },
+        }
     }
 
     /// Fetch benchmark data from CDN.
     pub async fn fetch(&self) -> BenchmarkFetchResult {
+        // Offline: `fetch_with_cache` falls back to the disk cache
+        if crate::offline::is_offline() {
+            return BenchmarkFetchResult::Error;
+        }
         let response = match self.client.get(CDN_URL).send().await {
             Ok(resp) => resp,
+            Err(e) if e.is_timeout() => return BenchmarkFetchResult::TimedOut,
+            Err(e) if crate::http::tls_hint(&e).is_some() => {
+                return BenchmarkFetchResult::CertificateRejected
+            }
             Err(_) => return BenchmarkFetchResult::Error,
         };
 
@@ -38,11 +86,74 @@ impl BenchmarkFetcher {
             return BenchmarkFetchResult::Error;
         }
 
-        let entries: Vec<BenchmarkEntry> = match response.json().await {
-            Ok(e) => e,
+        let body = match response.text().await {
+            Ok(body) => body,
+            Err(e) if e.is_timeout() => return BenchmarkFetchResult::TimedOut,
             Err(_) => return BenchmarkFetchResult::Error,
         };
 
-        BenchmarkFetchResult::Fresh(entries)
+        match parse_payload(&body) {
+            Some(entries) => BenchmarkFetchResult::Fresh(entries),
+            None => BenchmarkFetchResult::Error,
+        }
+    }
+}
+
+/// Deserialize a CDN payload, rejecting it when it parses but looks corrupt:
+/// empty, or too few entries with an identifying `slug` and `name`. A
+/// rejected payload never replaces the disk cache.
+fn parse_payload(body: &str) -> Option<Vec<BenchmarkEntry>> {
+    let entries: Vec<BenchmarkEntry> = serde_json::from_str(body).ok()?;
+    if entries.is_empty() {
+        return None;
+    }
+    let valid = entries
+        .iter()
+        .filter(|e| !e.slug.trim().is_empty() && !e.name.trim().is_empty())
+        .count();
+    if (valid as f64) < entries.len() as f64 * MIN_VALID_FRACTION {
+        return None;
+    }
+    Some(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(slug: &str, name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "slug": slug,
+            "name": name,
+            "intelligence_index": null,
+            "coding_index": null,
+            "math_index": null,
+            "mmlu_pro": null,
+            "gpqa": null,
+            "hle": null,
+            "livecodebench": null,
+            "scicode": null,
+        })
+    }
+
+    #[test]
+    fn parse_payload_accepts_well_formed_entries() {
+        let body = serde_json::json!([entry("gpt-4o", "GPT-4o"), entry("o3", "o3")]).to_string();
+        assert_eq!(parse_payload(&body).map(|e| e.len()), Some(2));
+    }
+
+    #[test]
+    fn parse_payload_rejects_corrupt_data() {
+        // Parses, but most entries lost their identifying fields
+        let body = serde_json::json!([
+            entry("gpt-4o", "GPT-4o"),
+            entry("", ""),
+            entry("", "Unnamed"),
+            entry(" ", ""),
+        ])
+        .to_string();
+        assert!(parse_payload(&body).is_none());
+        assert!(parse_payload("[]").is_none());
+        assert!(parse_payload("{\"error\": \"not found\"}").is_none());
     }
 }
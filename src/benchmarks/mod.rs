@@ -1,7 +1,14 @@
+mod cache;
+mod diff;
 mod fetch;
 mod store;
 mod traits;
 
+pub use cache::{BenchmarkCache, CACHE_VERSION as BENCHMARK_CACHE_VERSION};
+pub use diff::BenchmarkDiff;
 pub use fetch::{BenchmarkFetchResult, BenchmarkFetcher};
 pub use store::{BenchmarkEntry, BenchmarkStore, ReasoningFilter, ReasoningStatus};
-pub use traits::{apply_model_traits, build_open_weights_map};
+pub use traits::{
+    apply_model_traits, build_open_weights_map, find_entry_for_model, find_model_for_entry,
+    open_weights_matches, set_blocklist, MatchStage, MatchTier, OpenWeightsMatch,
+};
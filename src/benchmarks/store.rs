@@ -18,6 +18,9 @@ pub struct BenchmarkEntry {
     pub id: String,
     pub name: String,
     pub slug: String,
+    /// Earlier or alternate slugs for the same model, matched like `slug`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
     #[serde(default)]
     pub creator: String,
     #[serde(default)]
@@ -192,6 +195,15 @@ impl BenchmarkEntry {
             }
         }
     }
+
+    /// Blended $/M per Intelligence Index point (the "Value" sort); lower is
+    /// better. `None` without both numbers or with a zero index.
+    pub fn price_per_intelligence(&self) -> Option<f64> {
+        match (self.price_blended, self.intelligence_index) {
+            (Some(price), Some(index)) if index > 0.0 => Some(price / index),
+            _ => None,
+        }
+    }
 }
 
 pub struct BenchmarkStore {
@@ -271,6 +283,7 @@ mod tests {
             id: String::new(),
             name: "test".to_string(),
             slug: "test".to_string(),
+            aliases: Vec::new(),
             creator: "openai".to_string(),
             creator_id: String::new(),
             creator_name: "OpenAI".to_string(),
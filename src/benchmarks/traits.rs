@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+use serde::Serialize;
 
 use super::{BenchmarkEntry, ReasoningStatus};
 use crate::data::Provider;
@@ -9,6 +12,60 @@ use crate::data::Provider;
 /// (e.g. "gemma-3-27b" ≠ "gemini-3-pro").
 const MIN_SIMILARITY: f64 = 0.85;
 
+/// Normalized (model id, benchmark slug) pairs that must never match.
+type Blocklist = HashSet<(String, String)>;
+
+/// The blocklist from `benchmark_blocklist` in config.toml.
+static BLOCKLIST: OnceLock<Blocklist> = OnceLock::new();
+
+/// Install the configured blocklist. Called once at startup.
+pub fn set_blocklist(pairs: &[(String, String)]) {
+    let _ = BLOCKLIST.set(build_blocklist(pairs));
+}
+
+fn build_blocklist(pairs: &[(String, String)]) -> Blocklist {
+    pairs
+        .iter()
+        .map(|(model_id, slug)| (normalize(model_id), normalize(slug)))
+        .collect()
+}
+
+/// The installed blocklist, or an empty one before `set_blocklist()`.
+fn configured_blocklist() -> &'static Blocklist {
+    static EMPTY: OnceLock<Blocklist> = OnceLock::new();
+    BLOCKLIST
+        .get()
+        .unwrap_or_else(|| EMPTY.get_or_init(Blocklist::new))
+}
+
+/// Best similarity of a normalized model id to an entry's slug or any of its
+/// aliases (`norm_slugs` are normalized, primary slug first). An entry with
+/// the model id blocklisted against any of its slugs never matches.
+fn slug_similarity(
+    norm_slugs: &[String],
+    norm_model_id: &str,
+    blocklist: &Blocklist,
+) -> Option<f64> {
+    if norm_slugs
+        .iter()
+        .any(|slug| blocklist.contains(&(norm_model_id.to_string(), slug.clone())))
+    {
+        return None;
+    }
+    norm_slugs
+        .iter()
+        .map(|slug| strsim::jaro_winkler(slug, norm_model_id))
+        .max_by(f64::total_cmp)
+}
+
+/// The entry's slug followed by its aliases, normalized.
+fn normalized_slugs(entry: &BenchmarkEntry) -> Vec<String> {
+    std::iter::once(&entry.slug)
+        .chain(&entry.aliases)
+        .map(|slug| normalize(slug))
+        .collect()
+}
+
 /// Normalize a string for matching: lowercase, strip separators.
 fn normalize(s: &str) -> String {
     s.to_lowercase()
@@ -75,6 +132,44 @@ impl ModelTraits {
     }
 }
 
+/// Which stage of the trait matcher resolved a benchmark entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatchStage {
+    /// Slug matched a model from the creator's own provider(s)
+    Creator,
+    /// Slug matched a model from any provider
+    Global,
+    /// No model matched; openness comes from the known-creator table
+    KnownCreator,
+}
+
+impl MatchStage {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Creator => "creator",
+            Self::Global => "global",
+            Self::KnownCreator => "known creator",
+        }
+    }
+}
+
+/// How a benchmark entry's open-weights status was resolved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpenWeightsMatch {
+    pub open_weights: bool,
+    pub stage: MatchStage,
+    /// Jaro-Winkler similarity; `None` for known-creator overrides
+    pub score: Option<f64>,
+}
+
+/// A benchmark entry's matched traits and how they were found.
+struct EntryTraits {
+    traits: ModelTraits,
+    stage: MatchStage,
+    score: Option<f64>,
+}
+
 /// Build a map from AA benchmark entry slug → open_weights bool,
 /// and optionally augment entries with reasoning status from models.dev.
 ///
@@ -93,7 +188,26 @@ pub fn build_open_weights_map(
     let matched = match_entries(providers, entries);
     matched
         .into_iter()
-        .map(|(slug, traits)| (slug, traits.open_weights))
+        .map(|(slug, found)| (slug, found.traits.open_weights))
+        .collect()
+}
+
+/// The open-weights resolution behind [`build_open_weights_map`], with the
+/// matching stage and score per slug, for auditing the heuristic.
+pub fn open_weights_matches(
+    providers: &[(String, Provider)],
+    entries: &[BenchmarkEntry],
+) -> HashMap<String, OpenWeightsMatch> {
+    match_entries(providers, entries)
+        .into_iter()
+        .map(|(slug, found)| {
+            let resolved = OpenWeightsMatch {
+                open_weights: found.traits.open_weights,
+                stage: found.stage,
+                score: found.score,
+            };
+            (slug, resolved)
+        })
         .collect()
 }
 
@@ -102,7 +216,7 @@ pub fn build_open_weights_map(
 pub fn apply_model_traits(providers: &[(String, Provider)], entries: &mut [BenchmarkEntry]) {
     let matched = match_entries(providers, entries);
     for entry in entries {
-        if let Some(traits) = matched.get(&entry.slug) {
+        if let Some(traits) = matched.get(&entry.slug).map(|found| &found.traits) {
             if entry.reasoning_status == ReasoningStatus::None && traits.reasoning {
                 entry.reasoning_status = ReasoningStatus::Reasoning;
             }
@@ -119,10 +233,139 @@ pub fn apply_model_traits(providers: &[(String, Provider)], entries: &mut [Bench
     }
 }
 
+/// The reverse of the trait matching: the models.dev `(provider id, model id)`
+/// a benchmark entry resolves to, searching the creator's providers first and
+/// then all providers. Ties go to the alphabetically first pair, for a stable
+/// pick.
+pub fn find_model_for_entry<'a>(
+    providers: &'a [(String, Provider)],
+    entry: &BenchmarkEntry,
+) -> Option<(&'a str, &'a str)> {
+    model_for_entry(providers, entry, configured_blocklist())
+}
+
+fn model_for_entry<'a>(
+    providers: &'a [(String, Provider)],
+    entry: &BenchmarkEntry,
+    blocklist: &Blocklist,
+) -> Option<(&'a str, &'a str)> {
+    if entry.creator.is_empty() || entry.slug.is_empty() {
+        return None;
+    }
+    let norm_slugs = normalized_slugs(entry);
+    let mapped = creator_to_providers(&entry.creator);
+    let scoped: Vec<String> = if mapped.is_empty() {
+        vec![normalize(&entry.creator)]
+    } else {
+        mapped.iter().map(|id| normalize(id)).collect()
+    };
+
+    let best = |in_scope: &dyn Fn(&str) -> bool| {
+        providers
+            .iter()
+            .filter(|(id, _)| in_scope(id))
+            .flat_map(|(id, provider)| {
+                provider
+                    .models
+                    .keys()
+                    .map(move |model_id| (id.as_str(), model_id.as_str()))
+            })
+            .filter_map(|pair| {
+                let score = slug_similarity(&norm_slugs, &normalize(pair.1), blocklist)?;
+                Some((score, pair))
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0).then_with(|| b.1.cmp(&a.1)))
+            .filter(|(score, _)| *score >= MIN_SIMILARITY)
+            .map(|(_, pair)| pair)
+    };
+    best(&|id| scoped.contains(&normalize(id))).or_else(|| best(&|_| true))
+}
+
+/// How a benchmark entry was matched to a models.dev model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchTier {
+    /// Normalized slug equals the normalized model id
+    Exact,
+    /// Fuzzy match among entries whose creator maps to the provider
+    Creator,
+    /// Fuzzy match across all entries (resellers, unmapped creators)
+    Global,
+}
+
+impl MatchTier {
+    pub fn is_fuzzy(self) -> bool {
+        self != Self::Exact
+    }
+}
+
+/// A benchmark entry matched to a model, with the Jaro-Winkler score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntryMatch {
+    pub index: usize,
+    pub tier: MatchTier,
+    pub score: f64,
+}
+
+/// The benchmark entry a models.dev model matches: entries whose creator maps
+/// to `provider_id` first, then all entries.
+pub fn find_entry_for_model(
+    entries: &[BenchmarkEntry],
+    provider_id: &str,
+    model_id: &str,
+) -> Option<EntryMatch> {
+    entry_for_model(entries, provider_id, model_id, configured_blocklist())
+}
+
+fn entry_for_model(
+    entries: &[BenchmarkEntry],
+    provider_id: &str,
+    model_id: &str,
+    blocklist: &Blocklist,
+) -> Option<EntryMatch> {
+    let norm_model = normalize(model_id);
+    let norm_provider = normalize(provider_id);
+    let scoped = |entry: &BenchmarkEntry| {
+        let mapped = creator_to_providers(&entry.creator);
+        if mapped.is_empty() {
+            normalize(&entry.creator) == norm_provider
+        } else {
+            mapped.iter().any(|id| normalize(id) == norm_provider)
+        }
+    };
+
+    let best = |in_scope: &dyn Fn(&BenchmarkEntry) -> bool| {
+        entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !e.creator.is_empty() && !e.slug.is_empty() && in_scope(e))
+            .filter_map(|(i, e)| {
+                let score = slug_similarity(&normalized_slugs(e), &norm_model, blocklist)?;
+                Some((score, i))
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0).then_with(|| b.1.cmp(&a.1)))
+            .filter(|(score, _)| *score >= MIN_SIMILARITY)
+    };
+    let (score, index, fuzzy_tier) = match best(&scoped) {
+        Some((score, index)) => (score, index, MatchTier::Creator),
+        None => {
+            let (score, index) = best(&|_| true)?;
+            (score, index, MatchTier::Global)
+        }
+    };
+    let tier = if score >= 1.0 {
+        MatchTier::Exact
+    } else {
+        fuzzy_tier
+    };
+    Some(EntryMatch { index, tier, score })
+}
+
 fn match_entries(
     providers: &[(String, Provider)],
     entries: &[BenchmarkEntry],
-) -> HashMap<String, ModelTraits> {
+) -> HashMap<String, EntryTraits> {
+    let blocklist = configured_blocklist();
     // Build per-provider lookup: normalized provider ID → [(normalized model ID, traits)]
     let provider_set: HashMap<String, ()> = providers
         .iter()
@@ -159,7 +402,7 @@ fn match_entries(
         }
 
         let norm_creator = normalize(&entry.creator);
-        let norm_slug = normalize(&entry.slug);
+        let norm_slugs = normalized_slugs(entry);
 
         // Stage 1: Creator-scoped matching
         let mapped = creator_to_providers(&entry.creator);
@@ -171,6 +414,7 @@ fn match_entries(
 
         let mut best_score: f64 = 0.0;
         let mut best_traits: Option<&ModelTraits> = None;
+        let mut stage = MatchStage::Creator;
 
         for norm_provider_id in &provider_ids {
             if !provider_set.contains_key(norm_provider_id.as_str()) {
@@ -179,7 +423,9 @@ fn match_entries(
 
             if let Some(models) = model_lookup.get(norm_provider_id.as_str()) {
                 for (norm_model_id, traits) in models {
-                    let score = strsim::jaro_winkler(&norm_slug, norm_model_id);
+                    let Some(score) = slug_similarity(&norm_slugs, norm_model_id, blocklist) else {
+                        continue;
+                    };
                     if score > best_score {
                         best_score = score;
                         best_traits = Some(traits);
@@ -198,10 +444,13 @@ fn match_entries(
         // Stage 2: Global fallback — search all models if creator-scoped didn't match
         if best_score < MIN_SIMILARITY {
             for (norm_model_id, traits) in &all_models {
-                let score = strsim::jaro_winkler(&norm_slug, norm_model_id);
+                let Some(score) = slug_similarity(&norm_slugs, norm_model_id, blocklist) else {
+                    continue;
+                };
                 if score > best_score {
                     best_score = score;
                     best_traits = Some(traits);
+                    stage = MatchStage::Global;
                     if (score - 1.0).abs() < f64::EPSILON {
                         break;
                     }
@@ -213,12 +462,16 @@ fn match_entries(
             if let Some(traits) = best_traits {
                 result.insert(
                     entry.slug.clone(),
-                    ModelTraits {
-                        open_weights: traits.open_weights,
-                        reasoning: traits.reasoning,
-                        tool_call: traits.tool_call,
-                        context_window: traits.context_window,
-                        max_output: traits.max_output,
+                    EntryTraits {
+                        traits: ModelTraits {
+                            open_weights: traits.open_weights,
+                            reasoning: traits.reasoning,
+                            tool_call: traits.tool_call,
+                            context_window: traits.context_window,
+                            max_output: traits.max_output,
+                        },
+                        stage,
+                        score: Some(best_score),
                     },
                 );
                 continue;
@@ -229,12 +482,16 @@ fn match_entries(
         if let Some(ow) = known_creator_openness(&entry.creator) {
             result.insert(
                 entry.slug.clone(),
-                ModelTraits {
-                    open_weights: ow,
-                    reasoning: false,
-                    tool_call: false,
-                    context_window: None,
-                    max_output: None,
+                EntryTraits {
+                    traits: ModelTraits {
+                        open_weights: ow,
+                        reasoning: false,
+                        tool_call: false,
+                        context_window: None,
+                        max_output: None,
+                    },
+                    stage: MatchStage::KnownCreator,
+                    score: None,
                 },
             );
         }
@@ -301,6 +558,7 @@ mod tests {
             id: String::new(),
             name: slug.to_string(),
             slug: slug.to_string(),
+            aliases: Vec::new(),
             creator: creator.to_string(),
             creator_id: String::new(),
             creator_name: String::new(),
@@ -461,6 +719,98 @@ mod tests {
         assert!(map.is_empty(), "gemma should not match gemini");
     }
 
+    #[test]
+    fn test_find_model_for_entry() {
+        let providers = vec![
+            make_provider("openrouter", vec![("gemini-2.5-pro", false)]),
+            make_provider(
+                "google",
+                vec![
+                    ("gemini-2.5-pro", false),
+                    ("gemini-2.5-pro-preview-05-06", false),
+                ],
+            ),
+        ];
+        let entry = make_entry("google", "gemini-2-5-pro");
+        assert_eq!(
+            find_model_for_entry(&providers, &entry),
+            Some(("google", "gemini-2.5-pro"))
+        );
+
+        let unknown = make_entry("google", "gemma-3-27b");
+        assert_eq!(find_model_for_entry(&providers, &unknown), None);
+    }
+
+    #[test]
+    fn test_find_entry_for_model() {
+        let entries = vec![
+            make_entry("google", "gemini-2-5-flash"),
+            make_entry("google", "gemini-2-5-pro"),
+            make_entry("meta", "llama-3-1-instruct-405b"),
+        ];
+        let exact = find_entry_for_model(&entries, "google", "gemini-2.5-pro").unwrap();
+        assert_eq!((exact.index, exact.tier), (1, MatchTier::Exact));
+        let close = find_entry_for_model(&entries, "google", "gemini-2.5-pro-exp").unwrap();
+        assert_eq!((close.index, close.tier), (1, MatchTier::Creator));
+        assert!(close.score < 1.0);
+        // Resold models fall back to the global pass
+        let resold =
+            find_entry_for_model(&entries, "openrouter", "llama-3.1-405b-instruct").unwrap();
+        assert_eq!((resold.index, resold.tier), (2, MatchTier::Global));
+        assert_eq!(
+            find_entry_for_model(&entries, "google", "gemma-3-27b"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_aliases_match_like_slugs() {
+        let mut renamed = make_entry("acme", "acme-nova-pro");
+        renamed.aliases = vec!["acme-large-v3".to_string()];
+        let entries = vec![make_entry("acme", "acme-mini"), renamed];
+
+        let found = find_entry_for_model(&entries, "acme", "acme-large-v3").unwrap();
+        assert_eq!((found.index, found.tier), (1, MatchTier::Exact));
+
+        let providers = vec![make_provider("acme", vec![("acme-large-v3", true)])];
+        assert_eq!(
+            find_model_for_entry(&providers, &entries[1]),
+            Some(("acme", "acme-large-v3"))
+        );
+        assert_eq!(
+            build_open_weights_map(&providers, &entries).get("acme-nova-pro"),
+            Some(&true)
+        );
+    }
+
+    #[test]
+    fn test_blocklist_skips_to_next_candidate() {
+        let blocklist = build_blocklist(&[("acme-chat-2".to_string(), "acme_chat_2".to_string())]);
+        let entries = vec![
+            make_entry("acme", "acme-chat-2"),
+            make_entry("acme", "acme-chat-2-1"),
+        ];
+        let found = entry_for_model(&entries, "acme", "acme-chat-2", &blocklist).unwrap();
+        assert_eq!((found.index, found.tier), (1, MatchTier::Creator));
+
+        let providers = vec![make_provider("acme", vec![("acme-chat-2", false)])];
+        assert_eq!(model_for_entry(&providers, &entries[0], &blocklist), None);
+    }
+
+    #[test]
+    fn test_blocklist_matches_aliases() {
+        let mut entry = make_entry("acme", "acme-chat-2");
+        entry.aliases = vec!["acme-chat-two".to_string()];
+        let norm_slugs = normalized_slugs(&entry);
+        let blocklist =
+            build_blocklist(&[("acme-chat-two".to_string(), "acme-chat-two".to_string())]);
+        assert_eq!(
+            slug_similarity(&norm_slugs, "acmechattwo", &blocklist),
+            None
+        );
+        assert!(slug_similarity(&norm_slugs, "acmechattwo", &Blocklist::new()).is_some());
+    }
+
     /// Diagnostic test: runs matching against real benchmarks.json + live models.dev API.
     /// Run manually with: cargo test diagnostic_match_rate -- --ignored --nocapture
     #[test]
@@ -514,7 +864,7 @@ mod tests {
             }
         }
         let mut unmatched_creators: Vec<_> = unmatched_by_creator.iter().collect();
-        unmatched_creators.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+        unmatched_creators.sort_by_key(|c| std::cmp::Reverse(c.1.len()));
 
         // Build provider ID set for checking availability
         let provider_set: HashMap<String, Vec<String>> = providers
@@ -1,8 +1,11 @@
 use anyhow::Result;
 use clap::{CommandFactory, Parser};
+use serde::Serialize;
 use std::{collections::HashSet, sync::Arc};
 use tokio::sync::RwLock;
 
+use super::exit::CliError;
+
 #[derive(Parser, Debug)]
 #[command(name = "agents")]
 #[command(about = "Track AI coding agent releases and changelogs")]
@@ -19,10 +22,15 @@ use tokio::sync::RwLock;
   agents claude                 Browse Claude Code releases
   agents claude --latest        Latest Claude Code changelog
   agents cursor --list          All Cursor versions
-  agents cursor --version 1.0.0 Show a specific Cursor version")]
+  agents cursor --version 1.0.0 Show a specific Cursor version
+  agents --json                 Tracked agents and latest versions as JSON")]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct AgentsCli {
     #[command(subcommand)]
     pub command: Option<AgentsCommand>,
+    /// Output tracked agents and their cached latest versions as JSON
+    #[arg(long)]
+    pub json: bool,
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -51,7 +59,7 @@ pub struct ToolArgs {
 impl ToolArgs {
     pub fn parse_from(args: Vec<String>) -> Result<Self> {
         if args.is_empty() {
-            anyhow::bail!("No tool specified");
+            return Err(CliError::BadArgument("No tool specified".to_string()).into());
         }
         let tool = args[0].clone();
         let mut latest = false;
@@ -67,13 +75,13 @@ impl ToolArgs {
                 "--web" | "-w" => web = true,
                 "--version" => {
                     i += 1;
-                    version = Some(
-                        args.get(i)
-                            .cloned()
-                            .ok_or_else(|| anyhow::anyhow!("--version requires a value"))?,
-                    );
+                    version = Some(args.get(i).cloned().ok_or_else(|| {
+                        CliError::BadArgument("--version requires a value".to_string())
+                    })?);
+                }
+                other => {
+                    return Err(CliError::BadArgument(format!("Unknown flag: {}", other)).into())
                 }
-                other => anyhow::bail!("Unknown flag: {}", other),
             }
             i += 1;
         }
@@ -84,7 +92,10 @@ impl ToolArgs {
             .filter(|&&v| v)
             .count();
         if mode_count > 1 {
-            anyhow::bail!("--latest, --list, and --version are mutually exclusive");
+            return Err(CliError::BadArgument(
+                "--latest, --list, and --version are mutually exclusive".to_string(),
+            )
+            .into());
         }
 
         Ok(Self {
@@ -109,16 +120,43 @@ enum ResolveTool {
     Ambiguous(Vec<CatalogAgent>),
 }
 
+/// Machine-readable snapshot of a tracked agent, built from the disk cache only.
+#[derive(Debug, Serialize)]
+struct AgentSnapshot {
+    id: String,
+    name: String,
+    repo: String,
+    installed_version: Option<String>,
+    latest_version: Option<String>,
+    update_available: bool,
+}
+
+impl From<&crate::agents::data::AgentEntry> for AgentSnapshot {
+    fn from(entry: &crate::agents::data::AgentEntry) -> Self {
+        Self {
+            id: entry.id.clone(),
+            name: entry.agent.name.clone(),
+            repo: entry.agent.repo.clone(),
+            installed_version: entry.installed.version.clone(),
+            latest_version: entry.github.latest_version().map(str::to_string),
+            update_available: entry.update_available(),
+        }
+    }
+}
+
 pub fn run() -> Result<()> {
     let cli = AgentsCli::parse();
-    dispatch(cli.command)
+    dispatch(cli.command, cli.json)
 }
 
-pub fn run_with_command(command: Option<AgentsCommand>) -> Result<()> {
-    dispatch(command)
+pub fn run_with_command(command: Option<AgentsCommand>, json: bool) -> Result<()> {
+    dispatch(command, json)
 }
 
-fn dispatch(command: Option<AgentsCommand>) -> Result<()> {
+fn dispatch(command: Option<AgentsCommand>, json: bool) -> Result<()> {
+    if json {
+        return run_json_snapshot();
+    }
     match command {
         Some(AgentsCommand::Status) => run_status(),
         Some(AgentsCommand::Latest) => run_latest(),
@@ -332,7 +370,7 @@ fn get_github_data(
     disk_cache: &mut crate::agents::cache::GitHubCache,
     runtime: &tokio::runtime::Runtime,
 ) -> Option<crate::agents::data::GitHubData> {
-    eprint!("Fetching data for {}...", agent_name);
+    crate::notice::progress(&format!("Fetching data for {}...", agent_name));
     let cache_arc = Arc::new(RwLock::new(disk_cache.clone()));
     let token = crate::agents::github::detect_github_token();
     let client =
@@ -348,7 +386,7 @@ fn get_github_data(
         None
     };
     let (status, data) = apply_github_fetch_result(result, repo, disk_cache, fresh_shared_cache);
-    eprintln!("{status}");
+    crate::notice::info(status);
     data
 }
 
@@ -367,7 +405,7 @@ fn get_github_data_batch(
     let cache_arc = Arc::new(RwLock::new(disk_cache.clone()));
     let token = crate::agents::github::detect_github_token();
 
-    eprint!("Fetching {} agents...", agents.len());
+    crate::notice::progress(&format!("Fetching {} agents...", agents.len()));
 
     let results: Vec<_> = runtime.block_on(async {
         let mut handles = Vec::new();
@@ -425,7 +463,7 @@ fn get_github_data_batch(
         })
         .collect();
 
-    eprintln!(" done.");
+    crate::notice::info(" done.");
     output
 }
 
@@ -461,11 +499,7 @@ fn run_status() -> Result<()> {
             .map(|m| m.provider_slug)
             .collect();
         let seeds: Vec<_> = slugs.iter().map(|s| status_seed_for_provider(s)).collect();
-        let client = reqwest::Client::builder()
-            .user_agent("models-cli")
-            .connect_timeout(std::time::Duration::from_secs(5))
-            .build()
-            .expect("Failed to build HTTP client");
+        let client = crate::http::client("models-cli");
         let fetcher = crate::status::StatusFetcher::with_client(client);
         let crate::status::StatusFetchResult::Fresh(entries) =
             runtime.block_on(fetcher.fetch(&seeds));
@@ -681,6 +715,34 @@ fn run_latest() -> Result<()> {
     Ok(())
 }
 
+/// Print tracked agents as JSON using cached GitHub data only (no live fetch).
+fn run_json_snapshot() -> Result<()> {
+    let config = crate::config::Config::load()?;
+    let disk_cache = crate::agents::cache::GitHubCache::load();
+
+    let snapshots: Vec<AgentSnapshot> = load_catalog(&config)?
+        .into_iter()
+        .filter(|entry| entry.tracked)
+        .map(|entry| {
+            let github =
+                cached_github_data_for_repo(&disk_cache, &entry.agent.repo).unwrap_or_default();
+            let installed = crate::agents::detect::detect_installed(&entry.agent);
+            crate::agents::data::AgentEntry {
+                id: entry.id,
+                agent: entry.agent,
+                github,
+                installed,
+                tracked: entry.tracked,
+                fetch_status: crate::agents::data::FetchStatus::Loaded,
+            }
+        })
+        .map(|entry| AgentSnapshot::from(&entry))
+        .collect();
+
+    println!("{}", serde_json::to_string_pretty(&snapshots)?);
+    Ok(())
+}
+
 fn run_list_sources() -> Result<()> {
     use super::styles;
 
@@ -763,12 +825,13 @@ fn run_tool(args: ToolArgs) -> Result<()> {
                     .iter()
                     .map(|entry| styles::code_ref(&entry.id))
                     .collect();
-                anyhow::bail!(
+                return Err(CliError::BadArgument(format!(
                     "{} Ambiguous tool {}. Matches: {}",
                     styles::error_prefix(),
                     styles::input_badge(&args.tool),
                     names.join(", ")
-                );
+                ))
+                .into());
             }
         }
     };
@@ -863,10 +926,11 @@ fn resolve_tool(tool: &str, catalog: &[CatalogAgent]) -> Result<ResolveTool> {
     match matches.len() {
         1 => Ok(ResolveTool::Single(Box::new(matches[0].clone()))),
         n if n > 1 => Ok(ResolveTool::Ambiguous(matches)),
-        _ => anyhow::bail!(
+        _ => Err(CliError::NotFound(format!(
             "Unknown agent '{}'. Run agents list-sources to see available agents.",
             tool
-        ),
+        ))
+        .into()),
     }
 }
 
@@ -1184,6 +1248,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn agent_snapshot_reports_update_available_from_cached_release() {
+        let entry = crate::agents::data::AgentEntry {
+            id: "claude-code".to_string(),
+            agent: sample_agent("Claude Code", "anthropics/claude-code", Some("claude")),
+            github: sample_github_data("2.0.0"),
+            installed: crate::agents::data::InstalledInfo {
+                version: Some("1.9.0".to_string()),
+                path: None,
+            },
+            tracked: true,
+            fetch_status: crate::agents::data::FetchStatus::Loaded,
+        };
+
+        let snapshot = AgentSnapshot::from(&entry);
+        assert_eq!(snapshot.repo, "anthropics/claude-code");
+        assert_eq!(snapshot.installed_version.as_deref(), Some("1.9.0"));
+        assert_eq!(snapshot.latest_version.as_deref(), Some("2.0.0"));
+        assert!(snapshot.update_available);
+    }
+
+    #[test]
+    fn agent_snapshot_without_cache_has_no_latest_version() {
+        let entry = crate::agents::data::AgentEntry {
+            id: "codex".to_string(),
+            agent: sample_agent("Codex", "openai/codex", Some("codex")),
+            github: crate::agents::data::GitHubData::default(),
+            installed: crate::agents::data::InstalledInfo::default(),
+            tracked: true,
+            fetch_status: crate::agents::data::FetchStatus::Loaded,
+        };
+
+        let snapshot = AgentSnapshot::from(&entry);
+        assert!(snapshot.latest_version.is_none());
+        assert!(!snapshot.update_available);
+    }
+
     #[test]
     fn has_changelog_body_rejects_blank_strings() {
         assert!(!has_changelog_body(None));
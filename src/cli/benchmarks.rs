@@ -1,6 +1,7 @@
 use std::{collections::HashMap, time::Duration};
 
-use anyhow::{bail, Result};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
 use clap::{CommandFactory, Parser, ValueEnum};
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Table as ComfyTable};
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
@@ -16,11 +17,13 @@ use ratatui::{
 };
 use serde::Serialize;
 
+use super::exit::CliError;
 use super::picker::{self, PickerTerminal};
+use crate::offline::OfflineUnavailable;
 
 use crate::benchmarks::{
-    BenchmarkEntry, BenchmarkFetchResult, BenchmarkFetcher, BenchmarkStore, ReasoningFilter,
-    ReasoningStatus,
+    BenchmarkCache, BenchmarkDiff, BenchmarkEntry, BenchmarkFetchResult, BenchmarkFetcher,
+    BenchmarkStore, MatchStage, OpenWeightsMatch, ReasoningFilter, ReasoningStatus,
 };
 use crate::formatting::{cmp_opt_f64, parse_date_to_numeric, truncate};
 
@@ -35,7 +38,8 @@ use crate::formatting::{cmp_opt_f64, parse_date_to_numeric, truncate};
   benchmarks list --json
   benchmarks show gpt-4o              Show benchmark details by slug
   benchmarks show \"Claude Sonnet 4\"   Show by display name
-  benchmarks show gpt-4o --json       Output details as JSON")]
+  benchmarks show gpt-4o --json       Output details as JSON
+  benchmarks changes                  What the last refresh added or rescored")]
 pub struct BenchmarksCli {
     #[command(subcommand)]
     pub command: Option<BenchmarksCommand>,
@@ -93,6 +97,12 @@ pub enum BenchmarksCommand {
         #[arg(long)]
         json: bool,
     },
+    /// List models and scores that changed in the last benchmark refresh
+    Changes {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -122,6 +132,7 @@ pub enum BenchmarkSort {
     PriceOutput,
     #[value(name = "price-blended")]
     PriceBlended,
+    Value,
     Name,
     #[value(name = "release-date")]
     ReleaseDate,
@@ -148,6 +159,7 @@ impl BenchmarkSort {
             Self::PriceInput => "Input $/M",
             Self::PriceOutput => "Output $/M",
             Self::PriceBlended => "Blended $/M",
+            Self::Value => "$/Intel",
             Self::Name => "Name",
             Self::ReleaseDate => "Release",
         }
@@ -162,6 +174,7 @@ impl BenchmarkSort {
                 | Self::PriceInput
                 | Self::PriceOutput
                 | Self::PriceBlended
+                | Self::Value
         )
     }
 
@@ -185,6 +198,7 @@ impl BenchmarkSort {
             Self::PriceInput => entry.price_input,
             Self::PriceOutput => entry.price_output,
             Self::PriceBlended => entry.price_blended,
+            Self::Value => entry.price_per_intelligence(),
             Self::Name => Some(0.0),
             Self::ReleaseDate => entry
                 .release_date
@@ -265,6 +279,41 @@ struct BenchmarkDetail<'a> {
     price_blended: Option<f64>,
 }
 
+#[derive(Serialize)]
+struct LeaderboardItem<'a> {
+    rank: usize,
+    slug: &'a str,
+    name: &'a str,
+    creator: &'a str,
+    metric: &'static str,
+    score: f64,
+}
+
+#[derive(Serialize)]
+struct OpenWeightsItem<'a> {
+    slug: &'a str,
+    name: &'a str,
+    creator: &'a str,
+    open_weights: Option<bool>,
+    stage: Option<MatchStage>,
+    score: Option<f64>,
+}
+
+/// Entry counts per matching stage, mirroring `build_open_weights_map`.
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct OpenWeightsSummary {
+    creator: usize,
+    global: usize,
+    known_creator: usize,
+    unmatched: usize,
+}
+
+#[derive(Serialize)]
+struct OpenWeightsReport<'a> {
+    entries: Vec<OpenWeightsItem<'a>>,
+    summary: OpenWeightsSummary,
+}
+
 enum ResolveEntry<'a> {
     Single(&'a BenchmarkEntry),
     Ambiguous(Vec<&'a BenchmarkEntry>),
@@ -668,6 +717,7 @@ pub fn run_with_command(command: Option<BenchmarksCommand>) -> Result<()> {
             json,
         ),
         Some(BenchmarksCommand::Show { model, json }) => run_show(&model, json),
+        Some(BenchmarksCommand::Changes { json }) => run_changes(json),
         None => {
             BenchmarksCli::command().print_long_help()?;
             println!();
@@ -723,6 +773,197 @@ fn run_list(options: ListOptions, json: bool) -> Result<()> {
     Ok(())
 }
 
+/// `models leaderboard <metric>`: the top entries for one metric, best first
+/// (highest score, or lowest for latency and price).
+pub fn leaderboard(
+    metric: &str,
+    top: usize,
+    min: Option<f64>,
+    open_only: bool,
+    json: bool,
+) -> Result<()> {
+    let metric = parse_leaderboard_metric(metric)?;
+    let loaded = load_benchmarks()?;
+    let open_weights = open_only.then_some(&loaded.open_weights_map);
+    let ranked = leaderboard_entries(loaded.entries(), metric, top, min, open_weights);
+
+    if json {
+        let items: Vec<_> = ranked
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                Some(LeaderboardItem {
+                    rank: i + 1,
+                    slug: entry.slug.as_str(),
+                    name: entry.display_name.as_str(),
+                    creator: creator_label(entry),
+                    metric: metric.label(),
+                    score: metric.extract(entry)?,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&items)?);
+        return Ok(());
+    }
+
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["#", "Name", "Creator", metric.label()]);
+    for (i, entry) in ranked.iter().enumerate() {
+        table.add_row(vec![
+            (i + 1).to_string(),
+            entry.display_name.clone(),
+            creator_label(entry).to_string(),
+            format_metric(metric.extract(entry)),
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+/// `models open-weights`: every benchmark entry's resolved open/closed status
+/// and the matching stage that produced it, for auditing the heuristic.
+pub fn open_weights(json: bool) -> Result<()> {
+    let providers: Vec<_> = crate::api::fetch_providers()?.into_iter().collect();
+    let store = load_benchmark_store(&providers)?;
+    let matches = crate::benchmarks::open_weights_matches(&providers, store.entries());
+    let entries = open_weights_items(store.entries(), &matches);
+    let summary = summarize_open_weights(&entries);
+
+    if json {
+        let report = OpenWeightsReport { entries, summary };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["Name", "Creator", "Source", "Match", "Score"]);
+    for item in &entries {
+        table.add_row(vec![
+            item.name.to_string(),
+            item.creator.to_string(),
+            format_open_weights(item.open_weights),
+            item.stage
+                .map_or("unmatched", MatchStage::label)
+                .to_string(),
+            item.score
+                .map_or_else(|| "\u{2014}".to_string(), |s| format!("{:.3}", s)),
+        ]);
+    }
+    println!("{table}");
+    println!(
+        "{} entries: {} creator-scoped, {} global fallback, {} known creator, {} unmatched",
+        entries.len(),
+        summary.creator,
+        summary.global,
+        summary.known_creator,
+        summary.unmatched
+    );
+    Ok(())
+}
+
+/// Report rows ordered by creator (case-insensitive), then name.
+fn open_weights_items<'a>(
+    entries: &'a [BenchmarkEntry],
+    matches: &HashMap<String, OpenWeightsMatch>,
+) -> Vec<OpenWeightsItem<'a>> {
+    let mut items: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            let found = matches.get(&entry.slug);
+            OpenWeightsItem {
+                slug: &entry.slug,
+                name: &entry.display_name,
+                creator: creator_label(entry),
+                open_weights: found.map(|m| m.open_weights),
+                stage: found.map(|m| m.stage),
+                score: found.and_then(|m| m.score),
+            }
+        })
+        .collect();
+    items.sort_by(|a, b| {
+        a.creator
+            .to_lowercase()
+            .cmp(&b.creator.to_lowercase())
+            .then_with(|| a.name.cmp(b.name))
+    });
+    items
+}
+
+fn summarize_open_weights(items: &[OpenWeightsItem<'_>]) -> OpenWeightsSummary {
+    let mut summary = OpenWeightsSummary::default();
+    for item in items {
+        match item.stage {
+            Some(MatchStage::Creator) => summary.creator += 1,
+            Some(MatchStage::Global) => summary.global += 1,
+            Some(MatchStage::KnownCreator) => summary.known_creator += 1,
+            None => summary.unmatched += 1,
+        }
+    }
+    summary
+}
+
+/// Metric names accepted by `leaderboard`: the `--sort` columns that carry a score.
+fn leaderboard_metrics() -> impl Iterator<Item = BenchmarkSort> {
+    BenchmarkSort::value_variants()
+        .iter()
+        .copied()
+        .filter(|sort| !matches!(sort, BenchmarkSort::Name | BenchmarkSort::ReleaseDate))
+}
+
+fn parse_leaderboard_metric(metric: &str) -> Result<BenchmarkSort> {
+    leaderboard_metrics()
+        .find(|sort| {
+            sort.to_possible_value()
+                .is_some_and(|value| value.matches(metric, true))
+        })
+        .ok_or_else(|| {
+            let valid: Vec<String> = leaderboard_metrics()
+                .filter_map(|sort| sort.to_possible_value())
+                .map(|value| value.get_name().to_string())
+                .collect();
+            CliError::BadArgument(format!(
+                "Unknown metric '{}'. Valid metrics: {}",
+                metric,
+                valid.join(", ")
+            ))
+            .into()
+        })
+}
+
+/// Entries with a value for `metric` (at least `min`, if given), best first,
+/// truncated to `top`.
+/// With `open_only`, entries must be open weights in that map; unmatched
+/// entries are dropped, as with the Benchmarks tab's Open source filter.
+fn leaderboard_entries<'a>(
+    entries: &'a [BenchmarkEntry],
+    metric: BenchmarkSort,
+    top: usize,
+    min: Option<f64>,
+    open_only: Option<&HashMap<String, bool>>,
+) -> Vec<&'a BenchmarkEntry> {
+    let mut ranked: Vec<_> = entries
+        .iter()
+        .filter(|entry| {
+            metric
+                .extract(entry)
+                .is_some_and(|value| min.is_none_or(|min| value >= min))
+        })
+        .filter(|entry| open_only.is_none_or(|map| map.get(&entry.slug).copied().unwrap_or(false)))
+        .collect();
+    ranked.sort_by(|a, b| {
+        let ord = cmp_opt_f64(metric.extract(a), metric.extract(b));
+        if metric.default_descending() {
+            ord.reverse()
+        } else {
+            ord
+        }
+    });
+    ranked.truncate(top);
+    ranked
+}
+
 fn print_list_table(
     entries: &[&BenchmarkEntry],
     open_weights_map: &HashMap<String, bool>,
@@ -764,7 +1005,9 @@ fn run_show(model: &str, json: bool) -> Result<()> {
         ResolveEntry::Single(entry) => print_entry_detail(entry, &loaded.open_weights_map, json)?,
         ResolveEntry::Ambiguous(entries) => {
             if json || !super::styles::is_tty() {
-                bail!("{}", ambiguous_matches_message(model, &entries));
+                return Err(
+                    CliError::BadArgument(ambiguous_matches_message(model, &entries)).into(),
+                );
             }
 
             let title = format!(" Select Benchmark Match for \"{model}\" ");
@@ -782,6 +1025,65 @@ fn run_show(model: &str, json: bool) -> Result<()> {
     Ok(())
 }
 
+#[derive(Serialize)]
+struct ChangesReport {
+    previous_fetched_at: DateTime<Utc>,
+    current_fetched_at: DateTime<Utc>,
+    #[serde(flatten)]
+    diff: BenchmarkDiff,
+}
+
+/// Diff the cached payload against the snapshot it replaced. Reads only the
+/// disk cache; the snapshots are written by whichever command last fetched.
+fn run_changes(json: bool) -> Result<()> {
+    let (Some(previous), Some(current)) = (BenchmarkCache::load_previous(), BenchmarkCache::load())
+    else {
+        println!("No earlier benchmark snapshot yet; changes are recorded from the next refresh.");
+        return Ok(());
+    };
+    let report = ChangesReport {
+        previous_fetched_at: previous.fetched_at,
+        current_fetched_at: current.fetched_at,
+        diff: BenchmarkDiff::between(&previous.entries, &current.entries),
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!(
+        "Changes from {} to {}: {}",
+        report.previous_fetched_at.format("%Y-%m-%d %H:%M"),
+        report.current_fetched_at.format("%Y-%m-%d %H:%M"),
+        report.diff.summary().as_deref().unwrap_or("none")
+    );
+    if report.diff.is_empty() {
+        return Ok(());
+    }
+
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["Change", "Model", "Metric", "Old", "New"]);
+    for model in &report.diff.added {
+        table.add_row(vec!["new", model.name.as_str(), "", "", ""]);
+    }
+    for change in &report.diff.changed {
+        table.add_row(vec![
+            "score".to_string(),
+            change.name.clone(),
+            change.metric.to_string(),
+            format_metric(change.old),
+            format_metric(change.new),
+        ]);
+    }
+    for model in &report.diff.removed {
+        table.add_row(vec!["removed", model.name.as_str(), "", "", ""]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
 struct LoadedBenchmarks {
     store: BenchmarkStore,
     open_weights_map: HashMap<String, bool>,
@@ -796,25 +1098,55 @@ impl LoadedBenchmarks {
 fn load_benchmarks() -> Result<LoadedBenchmarks> {
     let providers = crate::api::fetch_providers()?;
     let provider_vec: Vec<_> = providers.into_iter().collect();
+    let store = load_benchmark_store(&provider_vec)?;
+    let open_weights_map =
+        crate::benchmarks::build_open_weights_map(&provider_vec, store.entries());
 
+    Ok(LoadedBenchmarks {
+        store,
+        open_weights_map,
+    })
+}
+
+/// Cached (or freshly fetched) benchmark entries with model traits applied.
+pub(super) fn load_benchmark_store(
+    provider_vec: &[(String, crate::data::Provider)],
+) -> Result<BenchmarkStore> {
     let runtime = tokio::runtime::Runtime::new()?;
     let fetcher = BenchmarkFetcher::new();
-    let entries = match runtime.block_on(fetcher.fetch()) {
-        BenchmarkFetchResult::Fresh(entries) => entries,
+    let ttl = crate::config::Config::load()
+        .unwrap_or_default()
+        .cache
+        .benchmarks_ttl();
+    let entries = match runtime.block_on(fetcher.fetch_with_cache(ttl, false)) {
+        BenchmarkFetchResult::Fresh(entries) | BenchmarkFetchResult::Cached(entries, _) => entries,
+        BenchmarkFetchResult::Error if crate::offline::is_offline() => {
+            return Err(OfflineUnavailable(
+                "No cached benchmark data; run once online to populate the cache".to_string(),
+            )
+            .into());
+        }
+        BenchmarkFetchResult::TimedOut => {
+            return Err(CliError::Network("Benchmark CDN request timed out".to_string()).into())
+        }
+        BenchmarkFetchResult::CertificateRejected => {
+            return Err(CliError::Network(format!(
+                "Failed to fetch benchmark data from the CDN ({})",
+                crate::http::NATIVE_TLS_HINT
+            ))
+            .into())
+        }
         BenchmarkFetchResult::Error => {
-            bail!("Failed to fetch benchmark data from the CDN")
+            return Err(CliError::Network(
+                "Failed to fetch benchmark data from the CDN".to_string(),
+            )
+            .into())
         }
     };
 
     let mut store = BenchmarkStore::from_entries(entries);
-    crate::benchmarks::apply_model_traits(&provider_vec, store.entries_mut());
-    let open_weights_map =
-        crate::benchmarks::build_open_weights_map(&provider_vec, store.entries());
-
-    Ok(LoadedBenchmarks {
-        store,
-        open_weights_map,
-    })
+    crate::benchmarks::apply_model_traits(provider_vec, store.entries_mut());
+    Ok(store)
 }
 
 fn filter_entries<'a>(
@@ -926,7 +1258,7 @@ fn resolve_entry<'a>(entries: &'a [BenchmarkEntry], query: &str) -> Result<Resol
     });
 
     match matches.as_slice() {
-        [] => bail!("No benchmark entry matched '{query}'"),
+        [] => Err(CliError::NotFound(format!("No benchmark entry matched '{query}'")).into()),
         [entry] => Ok(ResolveEntry::Single(entry)),
         many => Ok(ResolveEntry::Ambiguous(many.to_vec())),
     }
@@ -1257,6 +1589,7 @@ mod tests {
             id: slug.to_string(),
             name: display_name.to_string(),
             slug: slug.to_string(),
+            aliases: Vec::new(),
             creator: creator.to_string(),
             creator_id: String::new(),
             creator_name: creator_name.to_string(),
@@ -1534,4 +1867,94 @@ mod tests {
         assert_eq!(format_open_weights(Some(false)), "Closed");
         assert_eq!(format_metric(Some(74.0)), "74.00");
     }
+
+    #[test]
+    fn leaderboard_ranks_best_first_and_validates_metric() {
+        let entries = vec![
+            make_entry("beta", "Beta", "meta", "Meta", Some(80.0)),
+            make_entry("gamma", "Gamma", "openai", "OpenAI", None),
+            make_entry("alpha", "Alpha", "openai", "OpenAI", Some(90.0)),
+        ];
+        let metric = parse_leaderboard_metric("Intelligence").unwrap();
+        let ranked = leaderboard_entries(&entries, metric, 5, None, None);
+        let slugs: Vec<_> = ranked.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["alpha", "beta"]);
+        assert_eq!(
+            leaderboard_entries(&entries, metric, 1, None, None).len(),
+            1
+        );
+        assert_eq!(
+            leaderboard_entries(&entries, metric, 5, Some(85.0), None).len(),
+            1
+        );
+        // Open-only keeps entries the map marks open; unmatched ones drop out
+        let open = HashMap::from([("beta".to_string(), true), ("alpha".to_string(), false)]);
+        let ranked = leaderboard_entries(&entries, metric, 5, None, Some(&open));
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].slug, "beta");
+
+        // Price ranks cheapest first
+        let mut cheap = make_entry("cheap", "Cheap", "meta", "Meta", None);
+        cheap.price_blended = Some(0.5);
+        let entries = vec![entries[0].clone(), cheap];
+        let metric = parse_leaderboard_metric("price-blended").unwrap();
+        assert_eq!(
+            leaderboard_entries(&entries, metric, 5, None, None)[0].slug,
+            "cheap"
+        );
+
+        let err = parse_leaderboard_metric("name").unwrap_err().to_string();
+        assert!(err.contains("Valid metrics: intelligence, coding"));
+        assert!(!err.contains("release-date"));
+    }
+
+    #[test]
+    fn open_weights_items_sort_and_summarize_by_stage() {
+        let entries = vec![
+            make_entry("llama-4", "Llama 4", "meta", "Meta", None),
+            make_entry("gpt-5", "GPT-5", "openai", "OpenAI", None),
+            make_entry("mystery", "Mystery", "nobody", "", None),
+            make_entry("aria", "Aria", "ai2", "Ai2", None),
+        ];
+        let matches = HashMap::from([
+            (
+                "llama-4".to_string(),
+                OpenWeightsMatch {
+                    open_weights: true,
+                    stage: MatchStage::Creator,
+                    score: Some(1.0),
+                },
+            ),
+            (
+                "gpt-5".to_string(),
+                OpenWeightsMatch {
+                    open_weights: false,
+                    stage: MatchStage::Global,
+                    score: Some(0.92),
+                },
+            ),
+            (
+                "aria".to_string(),
+                OpenWeightsMatch {
+                    open_weights: true,
+                    stage: MatchStage::KnownCreator,
+                    score: None,
+                },
+            ),
+        ]);
+
+        let items = open_weights_items(&entries, &matches);
+        let slugs: Vec<_> = items.iter().map(|i| i.slug).collect();
+        assert_eq!(slugs, vec!["aria", "llama-4", "mystery", "gpt-5"]);
+        assert_eq!(items[2].open_weights, None);
+        assert_eq!(
+            summarize_open_weights(&items),
+            OpenWeightsSummary {
+                creator: 1,
+                global: 1,
+                known_creator: 1,
+                unmatched: 1,
+            }
+        );
+    }
 }
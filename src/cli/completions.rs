@@ -0,0 +1,82 @@
+//! Shell completion scripts. `--with-ids` bakes the model and provider ids
+//! from the cached data (fetched only when there is no cache) into the
+//! script, so `models show <TAB>` completes real ids; regenerate the script
+//! to pick up new models.
+
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use clap::builder::PossibleValuesParser;
+use clap::Command;
+use clap_complete::Shell;
+
+use crate::api;
+
+/// `(subcommand, argument)` pairs that take a model id.
+const MODEL_ARGS: [(&str, &str); 3] =
+    [("show", "model_id"), ("cost", "model"), ("budget", "model")];
+
+/// `(subcommand, argument)` pairs that take a provider id.
+const PROVIDER_ARGS: [(&str, &str); 2] = [("list", "provider"), ("env", "provider_id")];
+
+pub fn run(cmd: Command, shell: Shell, with_ids: bool) -> Result<()> {
+    let mut cmd = cmd;
+    if with_ids {
+        let providers = api::cached_providers()?;
+        let provider_ids: BTreeSet<String> = providers.keys().cloned().collect();
+        let model_ids: BTreeSet<String> = providers
+            .values()
+            .flat_map(|provider| provider.models.keys().cloned())
+            .collect();
+        cmd = bake_ids(cmd, &model_ids, &provider_ids);
+    }
+    clap_complete::generate(shell, &mut cmd, "models", &mut std::io::stdout());
+    Ok(())
+}
+
+/// Give each id argument its ids as possible values. Parsing never sees the
+/// result: it only shapes the generated script.
+fn bake_ids(
+    mut cmd: Command,
+    model_ids: &BTreeSet<String>,
+    provider_ids: &BTreeSet<String>,
+) -> Command {
+    let targets = MODEL_ARGS
+        .iter()
+        .map(|target| (target, model_ids))
+        .chain(PROVIDER_ARGS.iter().map(|target| (target, provider_ids)));
+    for ((subcommand, arg), ids) in targets {
+        if cmd.find_subcommand(subcommand).is_none() {
+            continue;
+        }
+        // Without clap's `string` feature values must be `'static`; the
+        // process exits right after printing the script.
+        let ids: Vec<&'static str> = ids.iter().map(|id| &*id.clone().leak()).collect();
+        cmd = cmd.mut_subcommand(subcommand, |sub| {
+            sub.mut_arg(*arg, |a| a.value_parser(PossibleValuesParser::new(ids)))
+        });
+    }
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Arg;
+
+    #[test]
+    fn baked_ids_appear_in_the_script() {
+        let cmd = Command::new("models")
+            .subcommand(Command::new("show").arg(Arg::new("model_id")))
+            .subcommand(Command::new("env").arg(Arg::new("provider_id")));
+        let models = BTreeSet::from(["gpt-4o".to_string(), "claude-sonnet-4".to_string()]);
+        let providers = BTreeSet::from(["anthropic".to_string()]);
+        let mut cmd = bake_ids(cmd, &models, &providers);
+
+        let mut script = Vec::new();
+        clap_complete::generate(Shell::Bash, &mut cmd, "models", &mut script);
+        let script = String::from_utf8(script).unwrap();
+        assert!(script.contains("claude-sonnet-4 gpt-4o"));
+        assert!(script.contains("anthropic"));
+    }
+}
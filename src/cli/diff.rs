@@ -0,0 +1,209 @@
+//! `models diff`: what changed between two saved `api.json` snapshots.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Result;
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Table as ComfyTable};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api;
+use crate::data::{Model, ProvidersMap};
+use crate::formatting::EM_DASH;
+
+type FieldExtract = fn(&Model) -> Value;
+
+/// Fields compared between snapshots: (name, value; `null` when missing).
+const DIFF_FIELDS: &[(&str, FieldExtract)] = &[
+    ("input_cost", |m| {
+        m.cost.as_ref().and_then(|c| c.input).into()
+    }),
+    ("output_cost", |m| {
+        m.cost.as_ref().and_then(|c| c.output).into()
+    }),
+    ("context", |m| {
+        m.limit.as_ref().and_then(|l| l.context).into()
+    }),
+    ("output_limit", |m| {
+        m.limit.as_ref().and_then(|l| l.output).into()
+    }),
+    ("status", |m| m.status.clone().into()),
+];
+
+/// One field that differs for a model in both snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    /// `provider/model`
+    pub model: String,
+    pub field: &'static str,
+    pub old: Value,
+    pub new: Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SnapshotDiff {
+    /// `provider/model` keys only in the new snapshot
+    pub added: Vec<String>,
+    /// `provider/model` keys only in the old snapshot
+    pub removed: Vec<String>,
+    pub changed: Vec<FieldChange>,
+}
+
+impl SnapshotDiff {
+    /// Compare two snapshots by `provider/model`, in key order.
+    pub fn between(old: &ProvidersMap, new: &ProvidersMap) -> Self {
+        let (old, new) = (models_by_key(old), models_by_key(new));
+        let mut diff = Self::default();
+        for (key, model) in &new {
+            let Some(previous) = old.get(key) else {
+                diff.added.push(key.clone());
+                continue;
+            };
+            for (field, extract) in DIFF_FIELDS {
+                let (before, after) = (extract(previous), extract(model));
+                if before != after {
+                    diff.changed.push(FieldChange {
+                        model: key.clone(),
+                        field,
+                        old: before,
+                        new: after,
+                    });
+                }
+            }
+        }
+        diff.removed = old
+            .keys()
+            .filter(|key| !new.contains_key(*key))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// One-line summary such as "3 added, 1 removed, 5 field changes".
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "no changes".to_string();
+        }
+        let changes = if self.changed.len() == 1 {
+            "field change"
+        } else {
+            "field changes"
+        };
+        format!(
+            "{} added, {} removed, {} {changes}",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+}
+
+fn models_by_key(providers: &ProvidersMap) -> BTreeMap<String, &Model> {
+    providers
+        .iter()
+        .flat_map(|(provider_id, provider)| {
+            provider
+                .models
+                .iter()
+                .map(move |(model_id, model)| (format!("{provider_id}/{model_id}"), model))
+        })
+        .collect()
+}
+
+/// A changed value for the table: numbers and strings as-is, `—` for missing.
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => EM_DASH.to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub fn run(old: &Path, new: &Path, json: bool) -> Result<()> {
+    let diff = SnapshotDiff::between(&api::load_data_file(old)?, &api::load_data_file(new)?);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} \u{2192} {}: {}",
+        old.display(),
+        new.display(),
+        diff.summary()
+    );
+    if diff.is_empty() {
+        return Ok(());
+    }
+
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["Change", "Model", "Field", "Old", "New"]);
+    for key in &diff.added {
+        table.add_row(vec!["added", key.as_str(), "", "", ""]);
+    }
+    for change in &diff.changed {
+        table.add_row(vec![
+            "changed".to_string(),
+            change.model.clone(),
+            change.field.to_string(),
+            display_value(&change.old),
+            display_value(&change.new),
+        ]);
+    }
+    for key in &diff.removed {
+        table.add_row(vec!["removed", key.as_str(), "", "", ""]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(models: Value) -> ProvidersMap {
+        serde_json::from_value(serde_json::json!({
+            "openai": { "id": "openai", "name": "OpenAI", "models": models }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn between_reports_added_removed_and_changed_fields() {
+        let old = snapshot(serde_json::json!({
+            "gpt-4o": { "id": "gpt-4o", "name": "GPT-4o", "cost": { "input": 5.0, "output": 15.0 } },
+            "retired": { "id": "retired", "name": "Retired" }
+        }));
+        let new = snapshot(serde_json::json!({
+            "gpt-4o": {
+                "id": "gpt-4o",
+                "name": "GPT-4o",
+                "cost": { "input": 2.5, "output": 15.0 },
+                "status": "deprecated"
+            },
+            "o3": { "id": "o3", "name": "o3" }
+        }));
+
+        let diff = SnapshotDiff::between(&old, &new);
+        assert_eq!(diff.added, ["openai/o3"]);
+        assert_eq!(diff.removed, ["openai/retired"]);
+        assert_eq!(
+            diff.changed
+                .iter()
+                .map(|c| (c.field, display_value(&c.old), display_value(&c.new)))
+                .collect::<Vec<_>>(),
+            [
+                ("input_cost", "5.0".to_string(), "2.5".to_string()),
+                ("status", EM_DASH.to_string(), "deprecated".to_string()),
+            ]
+        );
+        assert_eq!(diff.summary(), "1 added, 1 removed, 2 field changes");
+        assert_eq!(SnapshotDiff::between(&old, &old).summary(), "no changes");
+    }
+}
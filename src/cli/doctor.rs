@@ -0,0 +1,331 @@
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Table as ComfyTable};
+
+use crate::agents::GitHubCache;
+use crate::benchmarks::BenchmarkCache;
+use crate::config::Config;
+use crate::formatting::format_relative_time;
+
+use super::styles;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Ok,
+    /// Degraded but usable; never fails the command
+    Warn,
+    /// Critical; makes `doctor` exit non-zero
+    Fail,
+}
+
+struct Check {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Print a health report of config, caches, network reachability, and the
+/// clipboard. Fails when a critical check (config, models.dev) fails.
+pub fn run() -> Result<()> {
+    let (config_check, config) = check_config();
+    let mut checks = vec![config_check];
+    checks.push(check_file_cache("Model cache", crate::api::cache_path()));
+    checks.push(check_benchmark_cache(&config));
+    checks.push(check_file_cache("GitHub cache", GitHubCache::cache_path()));
+    checks.push(check_proxy());
+    checks.push(check_models_dev());
+    checks.push(check_github());
+    checks.push(check_clipboard(&config));
+
+    print_report(&checks);
+
+    let failed = critical_failures(&checks);
+    if failed > 0 {
+        anyhow::bail!("{} critical check(s) failed", failed);
+    }
+    Ok(())
+}
+
+fn critical_failures(checks: &[Check]) -> usize {
+    checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count()
+}
+
+fn print_report(checks: &[Check]) {
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec![
+        styles::header_cell("Check"),
+        styles::header_cell("Status"),
+        styles::header_cell("Detail"),
+    ]);
+    for check in checks {
+        let status = match check.status {
+            CheckStatus::Ok => styles::green_cell("ok"),
+            CheckStatus::Warn => styles::yellow_cell("warn"),
+            CheckStatus::Fail => styles::red_cell("FAIL"),
+        };
+        table.add_row(vec![
+            styles::bold_cell(check.name),
+            status,
+            comfy_table::Cell::new(&check.detail),
+        ]);
+    }
+    println!("{table}");
+}
+
+fn check_config() -> (Check, Config) {
+    let Some(path) = Config::config_path() else {
+        return (
+            Check::new("Config", CheckStatus::Warn, "no config directory"),
+            Config::default(),
+        );
+    };
+    if !path.exists() {
+        let detail = format!("{} (not created; using defaults)", path.display());
+        return (
+            Check::new("Config", CheckStatus::Ok, detail),
+            Config::default(),
+        );
+    }
+    match Config::load() {
+        Ok(config) => {
+            let (_, bad) = crate::provider_category::parse_overrides(&config.provider_categories);
+            let check = if bad.is_empty() {
+                Check::new(
+                    "Config",
+                    CheckStatus::Ok,
+                    format!("{} (valid)", path.display()),
+                )
+            } else {
+                Check::new(
+                    "Config",
+                    CheckStatus::Warn,
+                    format!(
+                        "{}: provider_categories ignores unknown category in {}",
+                        path.display(),
+                        bad.join(", ")
+                    ),
+                )
+            };
+            (check, config)
+        }
+        Err(err) => (
+            Check::new(
+                "Config",
+                CheckStatus::Fail,
+                format!("{}: {:#}", path.display(), err),
+            ),
+            Config::default(),
+        ),
+    }
+}
+
+/// Age of a cache file from its mtime; a missing cache is only a warning.
+fn check_file_cache(name: &'static str, path: Option<PathBuf>) -> Check {
+    let Some(path) = path else {
+        return Check::new(name, CheckStatus::Warn, "no config directory");
+    };
+    match modified_at(&path) {
+        Some(modified) => Check::new(
+            name,
+            CheckStatus::Ok,
+            format!(
+                "{} (updated {})",
+                path.display(),
+                format_relative_time(&modified)
+            ),
+        ),
+        None => Check::new(
+            name,
+            CheckStatus::Warn,
+            format!("{} (missing)", path.display()),
+        ),
+    }
+}
+
+fn check_benchmark_cache(config: &Config) -> Check {
+    let name = "Benchmark cache";
+    let Some(path) = BenchmarkCache::cache_path() else {
+        return Check::new(name, CheckStatus::Warn, "no config directory");
+    };
+    match BenchmarkCache::load() {
+        Some(cache) => {
+            let freshness = if cache.is_fresh(config.cache.benchmarks_ttl()) {
+                "fresh"
+            } else {
+                "stale"
+            };
+            Check::new(
+                name,
+                CheckStatus::Ok,
+                format!(
+                    "{} ({} entries, updated {}, {})",
+                    path.display(),
+                    cache.entries.len(),
+                    format_relative_time(&cache.fetched_at),
+                    freshness
+                ),
+            )
+        }
+        None => Check::new(
+            name,
+            CheckStatus::Warn,
+            format!("{} (missing or unreadable)", path.display()),
+        ),
+    }
+}
+
+fn modified_at(path: &Path) -> Option<DateTime<Utc>> {
+    let modified: SystemTime = std::fs::metadata(path).ok()?.modified().ok()?;
+    Some(modified.into())
+}
+
+/// Informational only: a wrong proxy shows up as a reachability failure.
+fn check_proxy() -> Check {
+    let proxies = crate::http::proxies();
+    if proxies.is_empty() {
+        return Check::new("Proxy", CheckStatus::Ok, "none (direct connection)");
+    }
+    let mut detail = proxies
+        .iter()
+        .map(|rule| format!("{} ({})", rule.display_url(), rule.source))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if let Some(no_proxy) = crate::http::no_proxy() {
+        detail.push_str(&format!("; NO_PROXY={}", no_proxy));
+    }
+    Check::new("Proxy", CheckStatus::Ok, detail)
+}
+
+fn check_models_dev() -> Check {
+    let name = "models.dev";
+    if let Some(path) = crate::api::data_file() {
+        return Check::new(
+            name,
+            CheckStatus::Ok,
+            format!("skipped (data file {})", path.display()),
+        );
+    }
+    if crate::offline::is_offline() {
+        return Check::new(name, CheckStatus::Warn, "skipped (offline)");
+    }
+    let url = crate::api::api_url();
+    let client = crate::http::blocking_client("models");
+    let started = Instant::now();
+    match client.head(&url).send() {
+        Ok(resp) if resp.status().is_success() => {
+            let mut detail = format!("reachable ({} ms)", started.elapsed().as_millis());
+            if url != crate::api::DEFAULT_API_URL {
+                detail.push_str(&format!(" via {}", url));
+            }
+            let (etag, last_modified) = crate::api::version_headers(resp.headers());
+            let version = crate::api::ResponseInfo {
+                etag,
+                last_modified,
+                ..Default::default()
+            };
+            if let Some(label) = version.version_label() {
+                detail.push_str(&format!("; {}", label));
+            }
+            Check::new(name, CheckStatus::Ok, detail)
+        }
+        Ok(resp) => Check::new(name, CheckStatus::Fail, format!("HTTP {}", resp.status())),
+        Err(err) => Check::new(name, CheckStatus::Fail, crate::http::describe_error(&err)),
+    }
+}
+
+/// GitHub is only needed for agent release data, so problems here are warnings.
+fn check_github() -> Check {
+    let name = "GitHub API";
+    if crate::offline::is_offline() {
+        return Check::new(name, CheckStatus::Warn, "skipped (offline)");
+    }
+    let token = crate::agents::github::detect_github_token();
+    let auth = if token.is_some() { "token" } else { "no token" };
+    let client = crate::http::blocking_client("models");
+    let mut request = client.get(format!(
+        "{}/rate_limit",
+        crate::agents::github::GITHUB_API_BASE
+    ));
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Bearer {}", token));
+    }
+    let started = Instant::now();
+    let resp = match request.send() {
+        Ok(resp) => resp,
+        Err(err) => return Check::new(name, CheckStatus::Warn, crate::http::describe_error(&err)),
+    };
+    let latency = started.elapsed().as_millis();
+    if !resp.status().is_success() {
+        return Check::new(name, CheckStatus::Warn, format!("HTTP {}", resp.status()));
+    }
+    let body: serde_json::Value = resp.json().unwrap_or_default();
+    let core = &body["resources"]["core"];
+    match (core["remaining"].as_u64(), core["limit"].as_u64()) {
+        (Some(remaining), Some(limit)) => {
+            let status = if remaining == 0 {
+                CheckStatus::Warn
+            } else {
+                CheckStatus::Ok
+            };
+            Check::new(
+                name,
+                status,
+                format!(
+                    "reachable ({} ms), {}/{} requests left, {}",
+                    latency, remaining, limit, auth
+                ),
+            )
+        }
+        _ => Check::new(
+            name,
+            CheckStatus::Ok,
+            format!("reachable ({} ms), {}", latency, auth),
+        ),
+    }
+}
+
+/// Checks the backend from `display.clipboard_backend`, the one the TUI uses.
+fn check_clipboard(config: &Config) -> Check {
+    match crate::tui::clipboard::diagnose(config.display.clipboard_backend) {
+        Ok(detail) => Check::new("Clipboard", CheckStatus::Ok, detail),
+        Err(detail) => Check::new("Clipboard", CheckStatus::Warn, detail),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_failed_checks_are_critical() {
+        let checks = vec![
+            Check::new("a", CheckStatus::Ok, ""),
+            Check::new("b", CheckStatus::Warn, ""),
+        ];
+        assert_eq!(critical_failures(&checks), 0);
+
+        let checks = vec![
+            Check::new("a", CheckStatus::Fail, ""),
+            Check::new("b", CheckStatus::Warn, ""),
+            Check::new("c", CheckStatus::Fail, ""),
+        ];
+        assert_eq!(critical_failures(&checks), 2);
+    }
+}
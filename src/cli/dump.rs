@@ -0,0 +1,134 @@
+//! `models dump`: every text model joined with its benchmark entry, for
+//! scripts and custom dashboards.
+
+use std::io::{BufWriter, Write};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use super::models::{flatten_model_row, ModelRow};
+use crate::api;
+use crate::benchmarks::{find_entry_for_model, BenchmarkEntry, MatchTier};
+use crate::data::Provider;
+
+#[derive(Serialize)]
+struct DumpRecord<'a> {
+    #[serde(flatten)]
+    model: ModelRow,
+    /// Whether a benchmark entry was found for the model
+    matched: bool,
+    /// `exact`, or `creator`/`global` for fuzzy matches
+    match_tier: Option<MatchTier>,
+    /// Jaro-Winkler similarity of the benchmark slug and model id
+    match_score: Option<f64>,
+    benchmark: Option<&'a BenchmarkEntry>,
+}
+
+/// Print one record per text model, ordered by provider then model id:
+/// JSON Lines by default, or a single JSON array with `json`. Records are
+/// written as they're resolved rather than collected first.
+pub fn run(json: bool) -> Result<()> {
+    let mut providers: Vec<(String, Provider)> = api::fetch_providers()?.into_iter().collect();
+    providers.sort_by(|a, b| a.0.cmp(&b.0));
+    let store = super::benchmarks::load_benchmark_store(&providers)?;
+
+    let mut out = BufWriter::new(std::io::stdout().lock());
+    write_records(&mut out, &providers, store.entries(), json)?;
+    out.flush()?;
+    Ok(())
+}
+
+fn write_records(
+    out: &mut impl Write,
+    providers: &[(String, Provider)],
+    entries: &[BenchmarkEntry],
+    json: bool,
+) -> Result<()> {
+    if json {
+        out.write_all(b"[")?;
+    }
+    let mut first = true;
+    for (provider_id, provider) in providers {
+        let mut models: Vec<_> = provider
+            .models
+            .values()
+            .filter(|model| model.is_text_model())
+            .collect();
+        models.sort_by(|a, b| a.id.cmp(&b.id));
+
+        for model in models {
+            let found = find_entry_for_model(entries, provider_id, &model.id);
+            let record = DumpRecord {
+                model: flatten_model_row(provider_id, &provider.name, model),
+                matched: found.is_some(),
+                match_tier: found.map(|m| m.tier),
+                match_score: found.map(|m| m.score),
+                benchmark: found.map(|m| &entries[m.index]),
+            };
+            if json && !first {
+                out.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut *out, &record)?;
+            if !json {
+                out.write_all(b"\n")?;
+            }
+            first = false;
+        }
+    }
+    if json {
+        out.write_all(b"]\n")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ProvidersMap;
+
+    #[test]
+    fn dump_joins_text_models_with_benchmarks() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "openai": {
+                "id": "openai",
+                "name": "OpenAI",
+                "models": {
+                    "gpt-4o": { "id": "gpt-4o", "name": "GPT-4o" },
+                    "o9-unreleased": { "id": "o9-unreleased", "name": "o9" },
+                    "dall-e-3": {
+                        "id": "dall-e-3",
+                        "name": "DALL-E 3",
+                        "modalities": { "input": ["text"], "output": ["image"] }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+        let providers: Vec<(String, Provider)> = providers.into_iter().collect();
+        let mut entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "GPT-4o", "slug": "gpt-4o", "intelligence_index": 30.0 }
+        ]))
+        .unwrap();
+        entries[0].creator = "openai".to_string();
+
+        let mut lines = Vec::new();
+        write_records(&mut lines, &providers, &entries, false).unwrap();
+        let records: Vec<serde_json::Value> = String::from_utf8(lines)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["id"], "gpt-4o");
+        assert_eq!(records[0]["matched"], true);
+        assert_eq!(records[0]["match_tier"], "exact");
+        assert_eq!(records[0]["benchmark"]["intelligence_index"], 30.0);
+        assert_eq!(records[1]["matched"], false);
+        assert!(records[1]["benchmark"].is_null());
+
+        let mut array = Vec::new();
+        write_records(&mut array, &providers, &entries, true).unwrap();
+        let parsed: Vec<serde_json::Value> = serde_json::from_slice(&array).unwrap();
+        assert_eq!(parsed, records);
+    }
+}
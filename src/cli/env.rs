@@ -0,0 +1,84 @@
+use anyhow::{bail, Result};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Table as ComfyTable};
+
+use crate::api;
+use crate::data::Provider;
+
+use super::exit::CliError;
+use super::styles;
+
+/// Print a provider's environment variables as `export` lines, or with
+/// `check` report which of them are set in the current environment.
+pub fn run(provider_id: &str, check: bool) -> Result<()> {
+    let providers = api::fetch_providers()?;
+    let filter = provider_id.to_lowercase();
+    let Some(provider) = providers
+        .values()
+        .find(|p| p.id.to_lowercase() == filter || p.name.to_lowercase() == filter)
+    else {
+        return Err(CliError::NotFound(format!("Provider '{}' not found", provider_id)).into());
+    };
+    if provider.env.is_empty() {
+        bail!(
+            "Provider '{}' does not list any environment variables",
+            provider.id
+        );
+    }
+
+    if check {
+        print_check_table(provider);
+    } else {
+        for line in export_lines(&provider.env) {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+fn export_lines(vars: &[String]) -> Vec<String> {
+    vars.iter().map(|var| format!("export {}=", var)).collect()
+}
+
+/// An empty value counts as unset, matching how SDKs treat blank API keys.
+pub(crate) fn is_set(var: &str) -> bool {
+    std::env::var_os(var).is_some_and(|v| !v.is_empty())
+}
+
+fn print_check_table(provider: &Provider) {
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec![
+        styles::header_cell("Variable"),
+        styles::header_cell("Status"),
+    ]);
+    for var in &provider.env {
+        let status = if is_set(var) {
+            styles::green_cell("set")
+        } else {
+            styles::yellow_cell("unset")
+        };
+        table.add_row(vec![comfy_table::Cell::new(var), status]);
+    }
+    println!("{} ({})", provider.name, provider.id);
+    println!("{table}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_lines_leave_values_blank() {
+        let vars = vec!["AWS_ACCESS_KEY_ID".to_string(), "AWS_REGION".to_string()];
+        assert_eq!(
+            export_lines(&vars),
+            vec!["export AWS_ACCESS_KEY_ID=", "export AWS_REGION="]
+        );
+    }
+
+    #[test]
+    fn is_set_ignores_missing_vars() {
+        assert!(!is_set("MODELS_TEST_SURELY_UNSET_VAR"));
+        assert!(is_set("PATH"));
+    }
+}
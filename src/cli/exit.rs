@@ -0,0 +1,95 @@
+//! Process exit codes, so scripts can branch on why a command failed.
+//!
+//! | Code | Meaning                                      |
+//! |------|----------------------------------------------|
+//! | 0    | Success                                      |
+//! | 1    | Any other failure                            |
+//! | 2    | Model, provider, or other lookup not found   |
+//! | 3    | Network error (models.dev, CDN, GitHub), or data unavailable with `--offline` |
+//! | 4    | Bad argument (invalid flag value, ambiguous query, usage error) |
+//! | 5    | `budget`: the estimated cost is over `--max`  |
+
+use std::fmt;
+
+pub const FAILURE: u8 = 1;
+pub const NOT_FOUND: u8 = 2;
+pub const NETWORK: u8 = 3;
+pub const BAD_ARGUMENT: u8 = 4;
+pub const OVER_BUDGET: u8 = 5;
+
+/// Typed CLI failures that map to a specific exit code. Everything else in an
+/// `anyhow::Error` chain exits with `FAILURE`, except `reqwest` and
+/// `OfflineUnavailable` errors (`NETWORK`).
+#[derive(Debug)]
+pub enum CliError {
+    NotFound(String),
+    Network(String),
+    BadArgument(String),
+    OverBudget(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(msg)
+            | Self::Network(msg)
+            | Self::BadArgument(msg)
+            | Self::OverBudget(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// Exit code for an error returned from a command.
+pub fn code_for(err: &anyhow::Error) -> u8 {
+    for cause in err.chain() {
+        if let Some(cli_err) = cause.downcast_ref::<CliError>() {
+            return match cli_err {
+                CliError::NotFound(_) => NOT_FOUND,
+                CliError::Network(_) => NETWORK,
+                CliError::BadArgument(_) => BAD_ARGUMENT,
+                CliError::OverBudget(_) => OVER_BUDGET,
+            };
+        }
+        if cause.is::<reqwest::Error>() || cause.is::<crate::offline::OfflineUnavailable>() {
+            return NETWORK;
+        }
+    }
+    FAILURE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn code_for_maps_typed_errors() {
+        let err = anyhow::Error::from(CliError::NotFound("Model 'x' not found".into()));
+        assert_eq!(code_for(&err), NOT_FOUND);
+        assert_eq!(err.to_string(), "Model 'x' not found");
+
+        let err = anyhow::Error::from(CliError::BadArgument("bad date".into()));
+        assert_eq!(code_for(&err), BAD_ARGUMENT);
+
+        let err = anyhow::Error::from(CliError::OverBudget("over".into()));
+        assert_eq!(code_for(&err), OVER_BUDGET);
+
+        assert_eq!(code_for(&anyhow::anyhow!("boom")), FAILURE);
+    }
+
+    #[test]
+    fn code_for_sees_through_context() {
+        let result: Result<(), CliError> = Err(CliError::Network("offline".into()));
+        let err = result.context("Failed to load benchmarks").unwrap_err();
+        assert_eq!(code_for(&err), NETWORK);
+    }
+
+    #[test]
+    fn offline_unavailable_is_a_network_failure() {
+        let err = anyhow::Error::from(crate::offline::OfflineUnavailable("no cache".into()));
+        assert_eq!(code_for(&err), NETWORK);
+        assert_eq!(err.to_string(), "no cache (offline mode)");
+    }
+}
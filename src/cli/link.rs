@@ -1,16 +1,22 @@
 use std::path::{Path, PathBuf};
 
-use anyhow::{bail, Context, Result};
+use anyhow::{Context, Result};
 
 use crate::config::Config;
 
+use super::exit::CliError;
+
 pub fn run(dir: Option<PathBuf>, remove: bool, status: bool, config: &Config) -> Result<()> {
     let binary = std::env::current_exe().context("could not determine binary path")?;
 
     let target_dir = match dir {
         Some(d) => {
             if !d.is_dir() {
-                bail!("directory does not exist: {}", d.display());
+                return Err(CliError::BadArgument(format!(
+                    "directory does not exist: {}",
+                    d.display()
+                ))
+                .into());
             }
             d
         }
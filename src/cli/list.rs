@@ -1,9 +1,133 @@
 use anyhow::Result;
+use clap::ValueEnum;
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Table as ComfyTable};
 
-pub fn providers(json: bool) -> Result<()> {
-    super::models::providers(json)
+use super::models::{format_optional_price, yes_no, ModelRow, ModelSort, ReleaseRange};
+use super::output::OutputFormat;
+
+/// A column `models list --columns` can show, in the order given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ModelColumn {
+    Id,
+    Name,
+    Provider,
+    Family,
+    /// Input price
+    #[value(name = "input", alias = "input-price")]
+    InputPrice,
+    /// Output price
+    OutputPrice,
+    CacheRead,
+    CacheWrite,
+    /// Input/output price pair
+    Cost,
+    Context,
+    /// Max output tokens
+    #[value(name = "output")]
+    OutputLimit,
+    Reasoning,
+    Tools,
+    Files,
+    OpenWeights,
+    Capabilities,
+    Modalities,
+    #[value(name = "released")]
+    ReleaseDate,
+    Knowledge,
+    Status,
+}
+
+impl ModelColumn {
+    /// Numbers and prices, right-aligned in the table
+    fn numeric(self) -> bool {
+        matches!(
+            self,
+            Self::InputPrice
+                | Self::OutputPrice
+                | Self::CacheRead
+                | Self::CacheWrite
+                | Self::Cost
+                | Self::Context
+                | Self::OutputLimit
+        )
+    }
+
+    fn header(self) -> String {
+        match self {
+            Self::Id => "ID".to_string(),
+            Self::Name => "Name".to_string(),
+            Self::Provider => "Provider".to_string(),
+            Self::Family => "Family".to_string(),
+            Self::InputPrice => ModelSort::InputPrice.label(),
+            Self::OutputPrice => ModelSort::OutputPrice.label(),
+            Self::CacheRead => "Cache Read".to_string(),
+            Self::CacheWrite => "Cache Write".to_string(),
+            Self::Cost => "Cost".to_string(),
+            Self::Context => "Context".to_string(),
+            Self::OutputLimit => "Max Output".to_string(),
+            Self::Reasoning => "Reasoning".to_string(),
+            Self::Tools => "Tools".to_string(),
+            Self::Files => "Files".to_string(),
+            Self::OpenWeights => "Open Weights".to_string(),
+            Self::Capabilities => "Capabilities".to_string(),
+            Self::Modalities => "Modalities".to_string(),
+            Self::ReleaseDate => "Release".to_string(),
+            Self::Knowledge => "Knowledge".to_string(),
+            Self::Status => "Status".to_string(),
+        }
+    }
+
+    pub(super) fn cell(self, row: &ModelRow) -> String {
+        let text = |value: &Option<String>| value.clone().unwrap_or_else(|| "\u{2014}".to_string());
+        match self {
+            Self::Id => row.display_id.clone(),
+            Self::Name => row.name.clone(),
+            Self::Provider => row.provider_name.clone(),
+            Self::Family => text(&row.family),
+            Self::InputPrice => format_optional_price(row.input_cost),
+            Self::OutputPrice => format_optional_price(row.output_cost),
+            Self::CacheRead => format_optional_price(row.cache_read_cost),
+            Self::CacheWrite => format_optional_price(row.cache_write_cost),
+            Self::Cost => row.cost.clone(),
+            Self::Context => row.context.clone(),
+            Self::OutputLimit => row.output.clone(),
+            Self::Reasoning => yes_no(row.reasoning).to_string(),
+            Self::Tools => yes_no(row.tool_call).to_string(),
+            Self::Files => yes_no(row.attachment).to_string(),
+            Self::OpenWeights => yes_no(row.open_weights).to_string(),
+            Self::Capabilities => row.capabilities.clone(),
+            Self::Modalities => row.modalities.clone(),
+            Self::ReleaseDate => text(&row.release_date),
+            Self::Knowledge => text(&row.knowledge_cutoff),
+            Self::Status => text(&row.status),
+        }
+    }
+}
+
+/// The model table with exactly `columns`, in that order.
+pub fn print_column_table(rows: &[ModelRow], columns: &[ModelColumn]) {
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(columns.iter().map(|c| c.header()).collect::<Vec<_>>());
+    for row in rows {
+        table.add_row(columns.iter().map(|c| c.cell(row)).collect::<Vec<_>>());
+    }
+    let numeric: Vec<bool> = columns.iter().map(|c| c.numeric()).collect();
+    let id = columns.iter().position(|&c| c == ModelColumn::Id);
+    super::styles::fit_table(&mut table, &numeric, id);
+    println!("{table}");
+}
+
+pub fn providers(json: bool, summary: bool) -> Result<()> {
+    super::models::providers(json, summary)
 }
 
-pub fn models(provider: Option<String>, json: bool) -> Result<()> {
-    super::models::list(provider.as_deref(), json)
+pub fn models(
+    provider: Option<String>,
+    format: Option<OutputFormat>,
+    range: &ReleaseRange,
+    sort: Option<ModelSort>,
+    columns: &[ModelColumn],
+) -> Result<()> {
+    super::models::list(provider.as_deref(), format, range, sort, columns)
 }
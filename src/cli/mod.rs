@@ -1,11 +1,19 @@
 pub mod agents;
 pub mod agents_ui;
 pub mod benchmarks;
+pub mod completions;
+pub mod diff;
+pub mod doctor;
+pub mod dump;
+pub mod env;
+pub mod exit;
 pub mod link;
 pub mod list;
 pub mod models;
+pub mod output;
 pub(crate) mod picker;
 pub mod search;
 pub mod show;
 pub mod status;
 pub mod styles;
+pub mod version;
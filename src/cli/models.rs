@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
 use std::time::Duration;
 
-use anyhow::{bail, Result};
+use anyhow::Result;
+use clap::ValueEnum;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Table as ComfyTable};
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use ratatui::{
@@ -15,15 +17,23 @@ use ratatui::{
 };
 use serde::Serialize;
 
-use crate::formatting::{cmp_opt_f64, parse_date_to_numeric, truncate};
+use crate::data::CAPABILITY_KEYWORDS;
+use crate::formatting::{
+    cmp_opt_f64, currency_rate, currency_symbol, format_tokens, knowledge_month,
+    knowledge_on_or_after, parse_date_to_numeric, parse_ymd, release_date_in_range, truncate,
+};
 use crate::{api, data::Model as ApiModel};
 
+use super::exit::CliError;
+use super::list::{print_column_table, ModelColumn};
+use super::output::{print_list, render_list, render_value, OutputFormat};
 use super::picker::{self, PickerTerminal};
-const PICKER_SORTS: [ModelSort; 6] = [
+const PICKER_SORTS: [ModelSort; 7] = [
     ModelSort::ReleaseDate,
     ModelSort::Name,
     ModelSort::Provider,
     ModelSort::Context,
+    ModelSort::OutputLimit,
     ModelSort::InputPrice,
     ModelSort::OutputPrice,
 ];
@@ -42,8 +52,15 @@ pub struct ModelRow {
     pub provider: String,
     pub provider_name: String,
     pub display_id: String,
+    /// Abbreviated for tables; JSON/TOML/YAML get `context_tokens` instead
+    #[serde(skip)]
     pub context: String,
+    #[serde(skip)]
     pub output: String,
+    #[serde(rename = "context")]
+    pub context_tokens: Option<u64>,
+    #[serde(rename = "output")]
+    pub output_tokens: Option<u64>,
     pub cost: String,
     pub capabilities: String,
     pub modalities: String,
@@ -69,8 +86,8 @@ pub struct ModelDetail {
     pub provider_id: String,
     pub provider_name: String,
     pub family: Option<String>,
-    pub context: String,
-    pub output: String,
+    pub context: Option<u64>,
+    pub output: Option<u64>,
     pub input_cost: Option<f64>,
     pub output_cost: Option<f64>,
     pub cache_read_cost: Option<f64>,
@@ -86,36 +103,133 @@ pub struct ModelDetail {
     pub status: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Lifecycle filter for `--status`, matched against models.dev's `status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ModelStatus {
+    /// No status set
+    Active,
+    Beta,
+    Deprecated,
+}
+
+impl ModelStatus {
+    fn matches(self, status: Option<&str>) -> bool {
+        match self {
+            Self::Active => status.is_none(),
+            Self::Beta => status == Some("beta"),
+            Self::Deprecated => status == Some("deprecated"),
+        }
+    }
+}
+
+/// Inclusive release-date bounds from `--released-after` / `--released-before`,
+/// plus the `--min-knowledge` and `--status` row filters.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseRange {
+    pub after: Option<String>,
+    pub before: Option<String>,
+    /// Minimum knowledge cutoff, normalized to YYYY-MM
+    pub min_knowledge: Option<String>,
+    /// Lifecycle status from `--status`
+    pub status: Option<ModelStatus>,
+}
+
+impl ReleaseRange {
+    pub fn new(after: Option<String>, before: Option<String>) -> Result<Self> {
+        for (flag, value) in [("--released-after", &after), ("--released-before", &before)] {
+            if let Some(value) = value {
+                if parse_ymd(value).is_none() {
+                    return Err(CliError::BadArgument(format!(
+                        "Invalid {} date '{}': expected YYYY-MM-DD",
+                        flag, value
+                    ))
+                    .into());
+                }
+            }
+        }
+        Ok(Self {
+            after,
+            before,
+            ..Self::default()
+        })
+    }
+
+    /// Also require a knowledge cutoff on or after `min` (YYYY, YYYY-MM, or
+    /// YYYY-MM-DD). Rows without a cutoff are dropped.
+    pub fn with_min_knowledge(mut self, min: Option<String>) -> Result<Self> {
+        if let Some(value) = min {
+            let Some(month) = knowledge_month(&value) else {
+                return Err(CliError::BadArgument(format!(
+                    "Invalid --min-knowledge date '{}': expected YYYY, YYYY-MM, or YYYY-MM-DD",
+                    value
+                ))
+                .into());
+            };
+            self.min_knowledge = Some(month);
+        }
+        Ok(self)
+    }
+
+    /// Also require a lifecycle status (`--status`).
+    pub fn with_status(mut self, status: Option<ModelStatus>) -> Self {
+        self.status = status;
+        self
+    }
+
+    fn contains(&self, row: &ModelRow) -> bool {
+        release_date_in_range(
+            row.release_date.as_deref(),
+            self.after.as_deref(),
+            self.before.as_deref(),
+        ) && knowledge_on_or_after(
+            row.knowledge_cutoff.as_deref(),
+            self.min_knowledge.as_deref(),
+        ) && self
+            .status
+            .is_none_or(|status| status.matches(row.status.as_deref()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum ModelSort {
     Name,
     Provider,
     Context,
+    /// Max output tokens
+    #[value(name = "output")]
+    OutputLimit,
     InputPrice,
     OutputPrice,
+    #[value(name = "date")]
     ReleaseDate,
 }
 
 impl ModelSort {
-    pub fn label(self) -> &'static str {
+    pub fn label(self) -> String {
+        let price = |side: &str| {
+            let sym = crate::formatting::currency_symbol().trim_end();
+            format!("{side} {sym}{}", crate::formatting::cost_unit().suffix())
+        };
         match self {
-            Self::Name => "Name",
-            Self::Provider => "Provider",
-            Self::Context => "Context",
-            Self::InputPrice => "Input $/M",
-            Self::OutputPrice => "Output $/M",
-            Self::ReleaseDate => "Release",
+            Self::Name => "Name".to_string(),
+            Self::Provider => "Provider".to_string(),
+            Self::Context => "Context".to_string(),
+            Self::OutputLimit => "Max Output".to_string(),
+            Self::InputPrice => price("Input"),
+            Self::OutputPrice => price("Output"),
+            Self::ReleaseDate => "Release".to_string(),
         }
     }
 
     pub fn default_descending(self) -> bool {
-        matches!(self, Self::Context | Self::ReleaseDate)
+        matches!(self, Self::Context | Self::OutputLimit | Self::ReleaseDate)
     }
 
     fn extract(self, row: &ModelRow) -> Option<f64> {
         match self {
             Self::Name | Self::Provider => Some(0.0),
             Self::Context => parse_token_count(&row.context),
+            Self::OutputLimit => row.output_tokens.map(|v| v as f64),
             Self::InputPrice => row.input_cost,
             Self::OutputPrice => row.output_cost,
             Self::ReleaseDate => row.release_date.as_deref().and_then(parse_date_to_numeric),
@@ -280,12 +394,12 @@ impl ModelPicker {
         )
         .header(
             TuiRow::new(vec![
-                "Name",
-                "Provider",
+                "Name".to_string(),
+                "Provider".to_string(),
                 picker_sort_label(self.sort),
-                "Cost",
-                "Capabilities",
-                "Release",
+                "Cost".to_string(),
+                "Capabilities".to_string(),
+                "Release".to_string(),
             ])
             .style(picker::HEADER_STYLE),
         )
@@ -318,7 +432,7 @@ impl ModelPicker {
             &self.title,
             self.visible_entries.len(),
             self.entries.len(),
-            picker_sort_label(self.sort),
+            &picker_sort_label(self.sort),
             self.descending,
             &self.query,
         )
@@ -381,7 +495,26 @@ impl ModelPicker {
     }
 }
 
-pub fn providers(json: bool) -> Result<()> {
+/// Width in cells of the longest `providers --summary` bar
+const SUMMARY_BAR_WIDTH: usize = 30;
+
+/// Block bar for `count` scaled against `max`, in eighth-cell steps.
+/// Any non-zero count gets at least a sliver.
+fn count_bar(count: usize, max: usize, width: usize) -> String {
+    const PARTIALS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    if count == 0 || max == 0 {
+        return String::new();
+    }
+    let eighths = (count * width * 8 / max).max(1);
+    let mut bar = "█".repeat(eighths / 8);
+    let partial = PARTIALS[eighths % 8];
+    if partial != ' ' {
+        bar.push(partial);
+    }
+    bar
+}
+
+pub fn providers(json: bool, summary: bool) -> Result<()> {
     let providers = api::fetch_providers()?;
     let mut infos: Vec<ProviderInfo> = providers
         .values()
@@ -392,6 +525,9 @@ pub fn providers(json: bool) -> Result<()> {
         })
         .collect();
     infos.sort_by(|a, b| a.id.cmp(&b.id));
+    if summary {
+        infos.sort_by_key(|info| std::cmp::Reverse(info.models_count));
+    }
 
     if json {
         println!("{}", serde_json::to_string_pretty(&infos)?);
@@ -400,80 +536,364 @@ pub fn providers(json: bool) -> Result<()> {
 
     let mut table = ComfyTable::new();
     table.load_preset(UTF8_FULL_CONDENSED);
-    table.set_header(vec!["ID", "Name", "Models"]);
-    for info in infos {
-        table.add_row(vec![info.id, info.name, info.models_count.to_string()]);
+    if summary {
+        let max = infos.first().map_or(0, |info| info.models_count);
+        table.set_header(vec!["ID", "Name", "Models", ""]);
+        for info in infos {
+            let bar = count_bar(info.models_count, max, SUMMARY_BAR_WIDTH);
+            table.add_row(vec![info.id, info.name, info.models_count.to_string(), bar]);
+        }
+    } else {
+        table.set_header(vec!["ID", "Name", "Models"]);
+        for info in infos {
+            table.add_row(vec![info.id, info.name, info.models_count.to_string()]);
+        }
     }
     println!("{table}");
     Ok(())
 }
 
-pub fn list(provider: Option<&str>, json: bool) -> Result<()> {
-    let rows = load_model_rows(provider)?;
+pub fn list(
+    provider: Option<&str>,
+    format: Option<OutputFormat>,
+    range: &ReleaseRange,
+    sort: Option<ModelSort>,
+    columns: &[ModelColumn],
+) -> Result<()> {
+    let mut rows = load_model_rows(provider)?;
+    rows.retain(|row| range.contains(row));
+    // An explicit sort also orders structured output, and like the picker
+    // drops rows without a value for it
+    if let Some(sort) = sort {
+        rows = filter_picker_entries(&rows, "", sort, sort.default_descending());
+    }
+    let sort = sort.unwrap_or(ModelSort::ReleaseDate);
     if rows.is_empty() {
-        bail!("No models found");
+        return Err(CliError::NotFound("No models found".to_string()).into());
     }
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&rows)?);
+    if let Some(format) = format {
+        return print_list(&rows, "models", format);
+    }
+
+    // Asking for columns means asking for the table, even on a terminal
+    if !columns.is_empty() {
+        print_column_table(&rows, columns);
         return Ok(());
     }
 
     if super::styles::is_tty() {
         let title = " Model Picker ".to_string();
-        if let Some(row) = pick_model(rows, ModelSort::ReleaseDate, true, &title)? {
-            print_model_detail(&row, false)?;
+        if let Some(row) = pick_model(rows, sort, sort.default_descending(), &title)? {
+            print_model_detail(&row, None)?;
         }
         return Ok(());
     }
 
-    print_model_table(&rows, ModelSort::ReleaseDate);
+    print_model_table(&rows, sort);
     Ok(())
 }
 
-pub fn show(query: &str, json: bool) -> Result<()> {
+pub fn show(query: &str, format: Option<OutputFormat>) -> Result<()> {
     match resolve_model(query)? {
-        ResolveModel::Single(row) => print_model_detail(&row, json),
+        ResolveModel::Single(row) => print_model_detail(&row, format),
         ResolveModel::Ambiguous(rows) => {
-            if json || !super::styles::is_tty() {
-                bail!("{}", ambiguous_model_matches_message(query, &rows));
+            if format.is_some() || !super::styles::is_tty() {
+                return Err(
+                    CliError::BadArgument(ambiguous_model_matches_message(query, &rows)).into(),
+                );
             }
             let title = format!(" Select Model Match for \"{query}\" ");
             if let Some(row) = pick_model(rows, ModelSort::ReleaseDate, true, &title)? {
-                print_model_detail(&row, false)?;
+                print_model_detail(&row, None)?;
             }
             Ok(())
         }
     }
 }
 
-pub fn search(query: &str, json: bool) -> Result<()> {
-    let rows = load_model_rows(None)?;
-    let filtered = filter_picker_entries(&rows, query, ModelSort::ReleaseDate, true);
+pub fn search(
+    query: &str,
+    format: Option<OutputFormat>,
+    range: &ReleaseRange,
+    sort: Option<ModelSort>,
+    group_by_provider: bool,
+) -> Result<()> {
+    let sort = sort.unwrap_or(ModelSort::ReleaseDate);
+    let mut rows = load_model_rows(None)?;
+    rows.retain(|row| range.contains(row));
+    let filtered = filter_picker_entries(&rows, query, sort, sort.default_descending());
+    // Structured output stays parseable: no matches is an empty list
+    if let Some(format) = format {
+        return print_list(&filtered, "models", format);
+    }
     if filtered.is_empty() {
-        println!("No models found matching '{}'", query);
-        return Ok(());
+        return Err(CliError::NotFound(format!("No models found matching '{query}'")).into());
     }
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&filtered)?);
+    if group_by_provider {
+        print_grouped_model_tables(&filtered, sort);
         return Ok(());
     }
 
     if super::styles::is_tty() {
         let title = " Model Search ".to_string();
         if let Some(row) =
-            pick_model_with_query(filtered, ModelSort::ReleaseDate, true, &title, query)?
+            pick_model_with_query(filtered, sort, sort.default_descending(), &title, query)?
         {
-            print_model_detail(&row, false)?;
+            print_model_detail(&row, None)?;
+        }
+        return Ok(());
+    }
+
+    print_model_table(&filtered, sort);
+    Ok(())
+}
+
+/// Models shown by `models cheapest`
+const CHEAPEST_LIMIT: usize = 10;
+
+/// The cheapest models by input price across all providers, optionally
+/// limited to those with a capability keyword (reasoning, tools, vision, ...).
+pub fn cheapest(capability: Option<&str>, json: bool) -> Result<()> {
+    let capability = capability.map(str::to_lowercase);
+    if let Some(cap) = &capability {
+        if !CAPABILITY_KEYWORDS.contains(&cap.as_str()) {
+            return Err(CliError::BadArgument(format!(
+                "Unknown capability '{}'; expected one of: {}",
+                cap,
+                CAPABILITY_KEYWORDS.join(", ")
+            ))
+            .into());
+        }
+    }
+
+    let providers = api::fetch_providers()?;
+    let rows: Vec<ModelRow> = providers
+        .values()
+        .flat_map(|provider| {
+            provider
+                .models
+                .values()
+                .filter(|model| {
+                    capability
+                        .as_deref()
+                        .is_none_or(|cap| model.has_capability(cap))
+                })
+                .map(|model| flatten_model_row(&provider.id, &provider.name, model))
+        })
+        .collect();
+    let cheapest = cheapest_rows(&rows);
+    if cheapest.is_empty() {
+        return Err(CliError::NotFound("No priced models found".to_string()).into());
+    }
+
+    if json {
+        println!(
+            "{}",
+            render_list(&cheapest, "models", OutputFormat::Json)?.trim_end()
+        );
+        return Ok(());
+    }
+    print_model_table(&cheapest, ModelSort::InputPrice);
+    Ok(())
+}
+
+/// `cost --json` output: token counts and USD costs at full precision.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct CostEstimate {
+    pub model: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub input_cost: f64,
+    pub output_cost: f64,
+    /// Cache reads and writes together
+    pub cache_cost: f64,
+    pub total: f64,
+}
+
+/// Token counts for `cost`; cache tokens default to zero.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenUsage {
+    pub input: u64,
+    pub output: u64,
+    pub cache_read: u64,
+    pub cache_write: u64,
+}
+
+/// A token count like `12000`, `200k`, or `1.5M`.
+pub fn parse_token_arg(text: &str) -> std::result::Result<u64, String> {
+    parse_token_count(text.trim())
+        .filter(|v| v.is_finite() && *v >= 0.0)
+        .map(|v| v.round() as u64)
+        .ok_or_else(|| format!("invalid token count '{text}': expected e.g. 12000, 200k, 1.5M"))
+}
+
+/// Price `usage` against a model's per-1M USD rates. Cache tokens fall back
+/// to the input price when the model lists no cache pricing.
+fn estimate_cost(row: &ModelRow, usage: TokenUsage) -> Result<CostEstimate> {
+    let (Some(input_price), Some(output_price)) = (row.input_cost, row.output_cost) else {
+        return Err(
+            CliError::NotFound(format!("{} has no input/output pricing", row.display_id)).into(),
+        );
+    };
+    let cost = |tokens: u64, per_million: f64| tokens as f64 * per_million / 1_000_000.0;
+    let input_cost = cost(usage.input, input_price);
+    let output_cost = cost(usage.output, output_price);
+    let cache_cost = cost(usage.cache_read, row.cache_read_cost.unwrap_or(input_price))
+        + cost(
+            usage.cache_write,
+            row.cache_write_cost.unwrap_or(input_price),
+        );
+    Ok(CostEstimate {
+        model: row.display_id.clone(),
+        input_tokens: usage.input,
+        output_tokens: usage.output,
+        input_cost,
+        output_cost,
+        cache_cost,
+        total: input_cost + output_cost + cache_cost,
+    })
+}
+
+/// The one model `query` names; scripts get an error rather than a picker.
+fn resolve_single_model(query: &str) -> Result<Box<ModelRow>> {
+    match resolve_model(query)? {
+        ResolveModel::Single(row) => Ok(row),
+        ResolveModel::Ambiguous(rows) => {
+            Err(CliError::BadArgument(ambiguous_model_matches_message(query, &rows)).into())
         }
+    }
+}
+
+pub fn cost(query: &str, usage: TokenUsage, json: bool) -> Result<()> {
+    let row = resolve_single_model(query)?;
+    let estimate = estimate_cost(&row, usage)?;
+    if json {
+        println!(
+            "{}",
+            render_value(&estimate, OutputFormat::Json)?.trim_end()
+        );
         return Ok(());
     }
 
-    print_model_table(&filtered, ModelSort::ReleaseDate);
+    let rate = currency_rate();
+    let amount = |usd: f64| format!("{}{:.4}", currency_symbol(), usd * rate);
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["", "Tokens", "Cost"]);
+    table.add_row(vec![
+        "Input".to_string(),
+        format_tokens(estimate.input_tokens),
+        amount(estimate.input_cost),
+    ]);
+    table.add_row(vec![
+        "Output".to_string(),
+        format_tokens(estimate.output_tokens),
+        amount(estimate.output_cost),
+    ]);
+    if usage.cache_read + usage.cache_write > 0 {
+        table.add_row(vec![
+            "Cache".to_string(),
+            format_tokens(usage.cache_read + usage.cache_write),
+            amount(estimate.cache_cost),
+        ]);
+    }
+    table.add_row(vec![
+        "Total".to_string(),
+        String::new(),
+        amount(estimate.total),
+    ]);
+    println!("{}", estimate.model);
+    println!("{table}");
+    Ok(())
+}
+
+/// Check the estimated cost against `max` (USD): prints both and succeeds
+/// when within budget, fails with `OverBudget` (exit 5) when over.
+pub fn budget(query: &str, usage: TokenUsage, max: f64) -> Result<()> {
+    if !max.is_finite() || max < 0.0 {
+        return Err(CliError::BadArgument(format!(
+            "Invalid --max '{max}': expected a non-negative amount in USD"
+        ))
+        .into());
+    }
+    let row = resolve_single_model(query)?;
+    let estimate = estimate_cost(&row, usage)?;
+    let summary = format!(
+        "{}: ${:.4} (limit ${:.4})",
+        estimate.model, estimate.total, max
+    );
+    if estimate.total > max {
+        return Err(CliError::OverBudget(format!("{summary}: over budget")).into());
+    }
+    println!("{summary}: within budget");
     Ok(())
 }
 
+/// Models whose `last_updated` (or `release_date`) is on or after `since`,
+/// newest first.
+pub fn updated(since: &str, json: bool) -> Result<()> {
+    if parse_ymd(since).is_none() {
+        return Err(CliError::BadArgument(format!(
+            "Invalid date '{}': expected YYYY-MM-DD",
+            since
+        ))
+        .into());
+    }
+
+    let rows = updated_rows(load_model_rows(None)?, since.trim());
+    if json {
+        println!(
+            "{}",
+            render_list(&rows, "models", OutputFormat::Json)?.trim_end()
+        );
+        return Ok(());
+    }
+    if rows.is_empty() {
+        println!("No models updated since {}", since);
+        return Ok(());
+    }
+
+    let mut table = ComfyTable::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["ID", "Name", "Provider", "Updated", "Released"]);
+    for row in &rows {
+        table.add_row(vec![
+            row.display_id.clone(),
+            row.name.clone(),
+            row.provider_name.clone(),
+            updated_date(row).unwrap_or_default().to_string(),
+            row.release_date.clone().unwrap_or_default(),
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+/// `last_updated`, falling back to `release_date`.
+fn updated_date(row: &ModelRow) -> Option<&str> {
+    row.last_updated.as_deref().or(row.release_date.as_deref())
+}
+
+/// YYYY-MM-DD strings compare correctly as text, so no date parsing here.
+fn updated_rows(mut rows: Vec<ModelRow>, since: &str) -> Vec<ModelRow> {
+    rows.retain(|row| updated_date(row).is_some_and(|date| date >= since));
+    rows.sort_by(|a, b| {
+        updated_date(b)
+            .cmp(&updated_date(a))
+            .then_with(|| a.display_id.cmp(&b.display_id))
+    });
+    rows
+}
+
+/// Rows with a known input price, cheapest first, capped at `CHEAPEST_LIMIT`.
+fn cheapest_rows(rows: &[ModelRow]) -> Vec<ModelRow> {
+    let mut sorted = filter_picker_entries(rows, "", ModelSort::InputPrice, false);
+    sorted.truncate(CHEAPEST_LIMIT);
+    sorted
+}
+
 pub fn load_model_rows(provider: Option<&str>) -> Result<Vec<ModelRow>> {
     let providers = api::fetch_providers()?;
     let provider = provider.map(str::to_lowercase);
@@ -506,14 +926,18 @@ pub fn load_model_rows(provider: Option<&str>) -> Result<Vec<ModelRow>> {
 
     if let Some(filter) = provider {
         if rows.is_empty() {
-            bail!("Provider '{}' not found", filter);
+            return Err(CliError::NotFound(format!("Provider '{}' not found", filter)).into());
         }
     }
 
     Ok(rows)
 }
 
-fn flatten_model_row(provider_id: &str, provider_name: &str, model: &ApiModel) -> ModelRow {
+pub(super) fn flatten_model_row(
+    provider_id: &str,
+    provider_name: &str,
+    model: &ApiModel,
+) -> ModelRow {
     ModelRow {
         id: model.id.clone(),
         name: model.name.clone(),
@@ -522,6 +946,8 @@ fn flatten_model_row(provider_id: &str, provider_name: &str, model: &ApiModel) -
         display_id: format!("{provider_id}/{}", model.id),
         context: model.context_str(),
         output: model.output_str(),
+        context_tokens: model.limit.as_ref().and_then(|l| l.context),
+        output_tokens: model.limit.as_ref().and_then(|l| l.output),
         cost: model.cost_str(),
         capabilities: model.capabilities_str(),
         modalities: model.modalities_str(),
@@ -584,7 +1010,7 @@ fn resolve_model(query: &str) -> Result<ResolveModel> {
     });
 
     match partial_matches.as_slice() {
-        [] => bail!("Model '{}' not found", query),
+        [] => Err(CliError::NotFound(format!("Model '{}' not found", query)).into()),
         [row] => Ok(ResolveModel::Single(Box::new((*row).clone()))),
         many => Ok(ResolveModel::Ambiguous(
             many.iter().map(|row| (*row).clone()).collect(),
@@ -734,10 +1160,7 @@ fn print_model_table(rows: &[ModelRow], sort: ModelSort) {
         "ID",
         "Name",
         "Provider",
-        match sort {
-            ModelSort::Name => "Context",
-            _ => sort.label(),
-        },
+        &picker_sort_label(sort),
         "Cost",
         "Capabilities",
     ]);
@@ -753,18 +1176,53 @@ fn print_model_table(rows: &[ModelRow], sort: ModelSort) {
         ]);
     }
 
+    // Every sort column but provider and date holds a number
+    let sort_numeric = !matches!(sort, ModelSort::Provider | ModelSort::ReleaseDate);
+    super::styles::fit_table(
+        &mut table,
+        &[false, false, false, sort_numeric, true, false],
+        Some(0),
+    );
     println!("{table}");
 }
 
-pub fn print_model_detail(row: &ModelRow, json: bool) -> Result<()> {
+/// Rows grouped by provider id (sorted), keeping each group's row order.
+fn group_rows_by_provider(rows: &[ModelRow]) -> Vec<(&str, Vec<ModelRow>)> {
+    let mut groups: BTreeMap<&str, Vec<ModelRow>> = BTreeMap::new();
+    for row in rows {
+        groups.entry(&row.provider).or_default().push(row.clone());
+    }
+    groups.into_iter().collect()
+}
+
+/// One table per provider under a "Name (id) · N models" header.
+fn print_grouped_model_tables(rows: &[ModelRow], sort: ModelSort) {
+    for (i, (provider_id, group)) in group_rows_by_provider(rows).iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let count = group.len();
+        println!(
+            "{} {}",
+            super::styles::key_value(&group[0].provider_name),
+            super::styles::dim(&format!(
+                "({provider_id}) \u{b7} {count} model{}",
+                if count == 1 { "" } else { "s" }
+            ))
+        );
+        print_model_table(group, sort);
+    }
+}
+
+pub fn print_model_detail(row: &ModelRow, format: Option<OutputFormat>) -> Result<()> {
     let detail = ModelDetail {
         id: row.id.clone(),
         name: row.name.clone(),
         provider_id: row.provider.clone(),
         provider_name: row.provider_name.clone(),
         family: row.family.clone(),
-        context: row.context.clone(),
-        output: row.output.clone(),
+        context: row.context_tokens,
+        output: row.output_tokens,
         input_cost: row.input_cost,
         output_cost: row.output_cost,
         cache_read_cost: row.cache_read_cost,
@@ -780,10 +1238,9 @@ pub fn print_model_detail(row: &ModelRow, json: bool) -> Result<()> {
         status: row.status.clone(),
     };
 
-    if json {
-        println!("{}", serde_json::to_string_pretty(&detail)?);
-    } else {
-        print_detail(&detail);
+    match format {
+        Some(format) => println!("{}", render_value(&detail, format)?.trim_end()),
+        None => print_detail(&detail),
     }
     Ok(())
 }
@@ -801,23 +1258,24 @@ fn print_detail(d: &ModelDetail) {
 
     println!("Limits");
     println!("------");
-    println!("Context:     {} tokens", d.context);
-    println!("Max Output:  {} tokens", d.output);
+    println!("Context:     {} tokens", token_limit(d.context));
+    println!("Max Output:  {} tokens", token_limit(d.output));
     println!();
 
-    println!("Pricing (per million tokens)");
-    println!("----------------------------");
+    let heading = format!("Pricing ({})", crate::formatting::cost_unit().per_tokens());
+    println!("{heading}");
+    println!("{}", "-".repeat(heading.len()));
     if let Some(input) = d.input_cost {
-        println!("Input:       ${:.2}", input);
+        println!("Input:       {}", detail_price(input));
     }
     if let Some(output) = d.output_cost {
-        println!("Output:      ${:.2}", output);
+        println!("Output:      {}", detail_price(output));
     }
     if let Some(cache_read) = d.cache_read_cost {
-        println!("Cache Read:  ${:.2}", cache_read);
+        println!("Cache Read:  {}", detail_price(cache_read));
     }
     if let Some(cache_write) = d.cache_write_cost {
-        println!("Cache Write: ${:.2}", cache_write);
+        println!("Cache Write: {}", detail_price(cache_write));
     }
     println!();
 
@@ -861,6 +1319,7 @@ fn format_picker_sort_value(sort: ModelSort, row: &ModelRow) -> String {
         ModelSort::Name => row.context.clone(),
         ModelSort::Provider => row.provider_name.clone(),
         ModelSort::Context => row.context.clone(),
+        ModelSort::OutputLimit => row.output.clone(),
         ModelSort::InputPrice => format_optional_price(row.input_cost),
         ModelSort::OutputPrice => format_optional_price(row.output_cost),
         ModelSort::ReleaseDate => row
@@ -870,20 +1329,43 @@ fn format_picker_sort_value(sort: ModelSort, row: &ModelRow) -> String {
     }
 }
 
-fn picker_sort_label(sort: ModelSort) -> &'static str {
+fn picker_sort_label(sort: ModelSort) -> String {
     match sort {
-        ModelSort::Name => "Context",
+        ModelSort::Name => "Context".to_string(),
         _ => sort.label(),
     }
 }
 
-fn format_optional_price(value: Option<f64>) -> String {
+pub(super) fn format_optional_price(value: Option<f64>) -> String {
     value
         .map(|v| ApiModel::cost_short(Some(v)))
         .unwrap_or_else(|| "\u{2014}".to_string())
 }
 
-fn yes_no(value: bool) -> &'static str {
+fn token_limit(value: Option<u64>) -> String {
+    value
+        .map(crate::formatting::format_tokens)
+        .unwrap_or_else(|| "\u{2014}".to_string())
+}
+
+/// Two decimals for reading (more for small per-1K prices), or the exact
+/// price with `--raw-numbers`, in the configured cost unit.
+fn detail_price(value: f64) -> String {
+    let unit = crate::formatting::cost_unit();
+    let value = crate::formatting::scale_price(value, unit);
+    let sym = crate::formatting::currency_symbol();
+    if crate::formatting::raw_numbers() {
+        format!("{sym}{}", value)
+    } else {
+        format!(
+            "{sym}{:.*}",
+            crate::formatting::price_decimals(value, unit, 2),
+            value
+        )
+    }
+}
+
+pub(super) fn yes_no(value: bool) -> &'static str {
     if value {
         "Yes"
     } else {
@@ -924,6 +1406,8 @@ mod tests {
             display_id: format!("{provider}/{id}"),
             context: context.to_string(),
             output: "8k".to_string(),
+            context_tokens: parse_token_count(context).map(|v| v as u64),
+            output_tokens: Some(8_000),
             cost: "-/-".to_string(),
             capabilities: "reasoning, tools".to_string(),
             modalities: "text -> text".to_string(),
@@ -943,6 +1427,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn budget_rejects_non_finite_or_negative_max() {
+        for max in [f64::NAN, f64::INFINITY, -0.5] {
+            let err = budget("gpt-4o", TokenUsage::default(), max).unwrap_err();
+            assert_eq!(
+                crate::cli::exit::code_for(&err),
+                crate::cli::exit::BAD_ARGUMENT
+            );
+        }
+    }
+
+    #[test]
+    fn model_columns_parse_in_order() {
+        let columns: Vec<ModelColumn> = "id,input,context,reasoning"
+            .split(',')
+            .map(|name| ModelColumn::from_str(name, false).unwrap())
+            .collect();
+        assert_eq!(
+            columns,
+            [
+                ModelColumn::Id,
+                ModelColumn::InputPrice,
+                ModelColumn::Context,
+                ModelColumn::Reasoning
+            ]
+        );
+        assert!(ModelColumn::from_str("bogus", false).is_err());
+        assert_eq!(
+            ModelColumn::from_str("input-price", false),
+            Ok(ModelColumn::InputPrice)
+        );
+
+        let r = row("gpt-4o", "openai", "GPT-4o", "128k", None);
+        let cells: Vec<String> = columns.iter().map(|c| c.cell(&r)).collect();
+        assert_eq!(cells, ["openai/gpt-4o", "\u{2014}", "128k", "Yes"]);
+    }
+
+    #[test]
+    fn fitted_table_right_aligns_numbers_and_keeps_rows_whole() {
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header(vec!["ID", "Context"]);
+        table.add_row(vec![
+            "openai/a-very-long-model-identifier-".repeat(4),
+            "1M".into(),
+        ]);
+        table.add_row(vec!["x".to_string(), "128k".to_string()]);
+        // Tests don't run on a terminal, so nothing wraps
+        super::super::styles::fit_table(&mut table, &[false, true], Some(0));
+        let rendered = table.to_string();
+        assert_eq!(rendered.lines().count(), 4);
+        assert!(rendered.contains("|      1M |"));
+        assert!(rendered.contains("|    128k |"));
+    }
+
     #[test]
     fn filter_picker_entries_applies_query() {
         let rows = vec![
@@ -964,6 +1503,23 @@ mod tests {
         assert_eq!(filtered[0].id, "large");
     }
 
+    #[test]
+    fn filter_picker_entries_sorts_by_output_limit_and_drops_unknown() {
+        let mut long = row("long", "openai", "Long", "32k", Some(2.0));
+        long.output_tokens = Some(64_000);
+        let mut unknown = row("unknown", "openai", "Unknown", "128k", Some(2.0));
+        unknown.output_tokens = None;
+        let rows = vec![
+            row("short", "openai", "Short", "128k", Some(2.0)),
+            long,
+            unknown,
+        ];
+        let sort = ModelSort::OutputLimit;
+        let filtered = filter_picker_entries(&rows, "", sort, sort.default_descending());
+        let ids: Vec<&str> = filtered.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["long", "short"]);
+    }
+
     #[test]
     fn ambiguous_model_matches_message_uses_display_ids() {
         let rows = vec![
@@ -974,4 +1530,148 @@ mod tests {
         assert!(message.contains("openai/gpt-4o"));
         assert!(message.contains("azure/gpt-4o"));
     }
+
+    #[test]
+    fn release_range_filters_rows_and_rejects_bad_dates() {
+        let mut recent = row("new", "openai", "New", "128k", Some(2.0));
+        recent.release_date = Some("2025-03-01".to_string());
+        let mut undated = row("old", "openai", "Old", "128k", Some(2.0));
+        undated.release_date = None;
+
+        let range = ReleaseRange::new(Some("2025-01-01".to_string()), None).unwrap();
+        assert!(range.contains(&recent));
+        assert!(!range.contains(&undated));
+        assert!(ReleaseRange::default().contains(&undated));
+        assert!(ReleaseRange::new(None, Some("March".to_string())).is_err());
+    }
+
+    #[test]
+    fn min_knowledge_drops_rows_without_a_recent_cutoff() {
+        let mut recent = row("new", "openai", "New", "128k", Some(2.0));
+        recent.knowledge_cutoff = Some("2024-10".to_string());
+        let mut year_only = row("mid", "openai", "Mid", "128k", Some(2.0));
+        year_only.knowledge_cutoff = Some("2024".to_string());
+        let unknown = row("old", "openai", "Old", "128k", Some(2.0));
+
+        let range = ReleaseRange::default()
+            .with_min_knowledge(Some("2024-06".to_string()))
+            .unwrap();
+        assert!(range.contains(&recent));
+        assert!(!range.contains(&year_only));
+        assert!(!range.contains(&unknown));
+        assert!(ReleaseRange::default()
+            .with_min_knowledge(Some("mid 2024".to_string()))
+            .is_err());
+    }
+
+    #[test]
+    fn status_filter_treats_unset_status_as_active() {
+        let active = row("new", "openai", "New", "128k", Some(2.0));
+        let mut deprecated = row("old", "openai", "Old", "128k", Some(2.0));
+        deprecated.status = Some("deprecated".to_string());
+
+        let only_active = ReleaseRange::default().with_status(Some(ModelStatus::Active));
+        assert!(only_active.contains(&active));
+        assert!(!only_active.contains(&deprecated));
+        let only_deprecated = ReleaseRange::default().with_status(Some(ModelStatus::Deprecated));
+        assert!(!only_deprecated.contains(&active));
+        assert!(only_deprecated.contains(&deprecated));
+        assert!(ReleaseRange::default().contains(&deprecated));
+    }
+
+    #[test]
+    fn cheapest_rows_sorts_by_input_price_and_skips_unpriced() {
+        let mut rows = vec![
+            row("pricey", "openai", "Pricey", "128k", Some(10.0)),
+            row("unpriced", "openai", "Unpriced", "128k", None),
+            row("cheap", "deepseek", "Cheap", "64k", Some(0.27)),
+        ];
+        rows.extend((0..12).map(|i| row(&format!("m{i}"), "x", "M", "8k", Some(5.0))));
+        let cheapest = cheapest_rows(&rows);
+        assert_eq!(cheapest.len(), CHEAPEST_LIMIT);
+        assert_eq!(cheapest[0].id, "cheap");
+        assert!(cheapest
+            .iter()
+            .all(|r| r.id != "unpriced" && r.id != "pricey"));
+    }
+
+    #[test]
+    fn updated_rows_prefer_last_updated_and_sort_newest_first() {
+        let mut patched = row("patched", "openai", "Patched", "128k", Some(2.0));
+        patched.release_date = Some("2024-01-01".to_string());
+        patched.last_updated = Some("2025-06-10".to_string());
+        let mut fresh = row("fresh", "openai", "Fresh", "128k", Some(2.0));
+        fresh.release_date = Some("2025-07-01".to_string());
+        let mut stale = row("stale", "openai", "Stale", "128k", Some(2.0));
+        stale.last_updated = Some("2025-05-31".to_string());
+        let mut undated = row("undated", "openai", "Undated", "128k", Some(2.0));
+        undated.release_date = None;
+
+        let rows = updated_rows(vec![patched, fresh, stale, undated], "2025-06-01");
+        let ids: Vec<&str> = rows.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["fresh", "patched"]);
+    }
+
+    #[test]
+    fn group_rows_by_provider_sorts_groups_and_keeps_row_order() {
+        let rows = vec![
+            row("b", "openai", "B", "128k", Some(2.0)),
+            row("claude", "anthropic", "Claude", "200k", Some(3.0)),
+            row("a", "openai", "A", "128k", Some(2.0)),
+        ];
+        let groups = group_rows_by_provider(&rows);
+        let summary: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|(id, group)| (*id, group.iter().map(|r| r.id.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("anthropic", vec!["claude"]), ("openai", vec!["b", "a"])]
+        );
+    }
+
+    #[test]
+    fn structured_output_uses_exact_token_counts() {
+        let json =
+            serde_json::to_value(row("gpt-4o", "openai", "GPT-4o", "128k", Some(2.5))).unwrap();
+        assert_eq!(json["context"], 128_000);
+        assert_eq!(json["output"], 8_000);
+        assert_eq!(json["input_cost"], 2.5);
+    }
+
+    #[test]
+    fn cost_estimate_prices_each_token_kind() {
+        let mut model = row("gpt-4o", "openai", "GPT-4o", "128k", Some(2.5));
+        model.cache_read_cost = Some(1.25);
+        let usage = TokenUsage {
+            input: 12_000,
+            output: 800,
+            cache_read: 100_000,
+            cache_write: 1_000,
+        };
+        let estimate = estimate_cost(&model, usage).unwrap();
+        assert_eq!(estimate.input_cost, 0.03);
+        assert_eq!(estimate.output_cost, 0.004);
+        // Cache writes fall back to the input price
+        assert_eq!(estimate.cache_cost, 0.125 + 0.0025);
+        assert_eq!(estimate.total, 0.03 + 0.004 + 0.1275);
+
+        let json = serde_json::to_value(&estimate).unwrap();
+        assert_eq!(json["model"], "openai/gpt-4o");
+        assert_eq!(json["input_tokens"], 12_000);
+        assert_eq!(json["output_cost"], 0.004);
+
+        assert!(estimate_cost(&row("free", "x", "Free", "8k", None), usage).is_err());
+        assert_eq!(parse_token_arg("1.5M"), Ok(1_500_000));
+        assert!(parse_token_arg("lots").is_err());
+    }
+
+    #[test]
+    fn count_bar_scales_to_max() {
+        assert_eq!(count_bar(10, 10, 4), "████");
+        assert_eq!(count_bar(5, 10, 4), "██");
+        assert_eq!(count_bar(1, 16, 4), "▎");
+        assert_eq!(count_bar(1, 1000, 4), "▏");
+        assert_eq!(count_bar(0, 10, 4), "");
+    }
 }
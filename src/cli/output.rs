@@ -0,0 +1,133 @@
+use std::io::{BufWriter, Write};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::ser::{SerializeSeq, Serializer};
+use serde::Serialize;
+
+/// Structured output formats for `--format`. `--json` is shorthand for `--format json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Combine the `--json` flag with `--format`; `None` means human-readable output.
+    pub fn resolve(json: bool, format: Option<Self>) -> Option<Self> {
+        format.or(json.then_some(Self::Json))
+    }
+}
+
+/// Serialize a single record.
+pub fn render_value<T: Serialize + ?Sized>(value: &T, format: OutputFormat) -> Result<String> {
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value)?,
+        OutputFormat::Toml => toml::to_string_pretty(value)?,
+        OutputFormat::Yaml => serde_norway::to_string(value)?,
+    })
+}
+
+/// Serialize a list of records. TOML has no top-level arrays, so the list is
+/// wrapped in a table under `key` (an array of tables, `[[key]]`).
+pub fn render_list<T: Serialize>(items: &[T], key: &str, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Toml => {
+            let wrapped = std::collections::BTreeMap::from([(key, items)]);
+            render_value(&wrapped, format)
+        }
+        _ => render_value(items, format),
+    }
+}
+
+/// Print a list of records to stdout. JSON is streamed element by element so
+/// large lists (`list --json` across every provider) never exist as one string.
+pub fn print_list<T: Serialize>(items: &[T], key: &str, format: OutputFormat) -> Result<()> {
+    if format != OutputFormat::Json {
+        println!("{}", render_list(items, key, format)?.trim_end());
+        return Ok(());
+    }
+    let mut out = BufWriter::new(std::io::stdout().lock());
+    write_json_array(&mut out, items)?;
+    writeln!(out)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Write `items` as a pretty-printed JSON array, byte-for-byte what
+/// `serde_json::to_string_pretty` gives for the collected list.
+pub fn write_json_array<W, I>(out: W, items: I) -> Result<()>
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: Serialize,
+{
+    let mut serializer = serde_json::Serializer::pretty(out);
+    let mut seq = serializer.serialize_seq(None)?;
+    for item in items {
+        seq.serialize_element(&item)?;
+    }
+    seq.end()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        id: &'static str,
+        cost: Option<f64>,
+    }
+
+    const ROWS: [Row; 2] = [
+        Row {
+            id: "gpt-4o",
+            cost: Some(2.5),
+        },
+        Row {
+            id: "local",
+            cost: None,
+        },
+    ];
+
+    #[test]
+    fn resolve_prefers_explicit_format() {
+        assert_eq!(OutputFormat::resolve(false, None), None);
+        assert_eq!(OutputFormat::resolve(true, None), Some(OutputFormat::Json));
+        assert_eq!(
+            OutputFormat::resolve(false, Some(OutputFormat::Yaml)),
+            Some(OutputFormat::Yaml)
+        );
+    }
+
+    #[test]
+    fn toml_list_is_wrapped_under_key() {
+        let out = render_list(&ROWS, "models", OutputFormat::Toml).unwrap();
+        assert!(out.contains("[[models]]"));
+        assert!(out.contains("id = \"gpt-4o\""));
+        // TOML has no null, so None fields are omitted
+        assert_eq!(out.matches("cost").count(), 1);
+    }
+
+    #[test]
+    fn streamed_json_matches_buffered() {
+        let mut streamed = Vec::new();
+        write_json_array(&mut streamed, &ROWS).unwrap();
+        let buffered = render_list(&ROWS, "models", OutputFormat::Json).unwrap();
+        assert_eq!(String::from_utf8(streamed).unwrap(), buffered);
+
+        let mut empty = Vec::new();
+        write_json_array(&mut empty, std::iter::empty::<Row>()).unwrap();
+        assert_eq!(String::from_utf8(empty).unwrap(), "[]");
+    }
+
+    #[test]
+    fn yaml_list_is_top_level_sequence() {
+        let out = render_list(&ROWS, "models", OutputFormat::Yaml).unwrap();
+        assert!(out.starts_with("- id: gpt-4o"));
+        assert!(out.contains("cost: null"));
+    }
+}
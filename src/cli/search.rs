@@ -1,5 +1,14 @@
 use anyhow::Result;
 
-pub fn search(query: &str, json: bool) -> Result<()> {
-    super::models::search(query, json)
+use super::models::{ModelSort, ReleaseRange};
+use super::output::OutputFormat;
+
+pub fn search(
+    query: &str,
+    format: Option<OutputFormat>,
+    range: &ReleaseRange,
+    sort: Option<ModelSort>,
+    group_by_provider: bool,
+) -> Result<()> {
+    super::models::search(query, format, range, sort, group_by_provider)
 }
@@ -1,5 +1,7 @@
 use anyhow::Result;
 
-pub fn model(model_id: &str, json: bool) -> Result<()> {
-    super::models::show(model_id, json)
+use super::output::OutputFormat;
+
+pub fn model(model_id: &str, format: Option<OutputFormat>) -> Result<()> {
+    super::models::show(model_id, format)
 }
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use anyhow::{bail, Result};
+use anyhow::Result;
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Table as ComfyTable};
 use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
@@ -16,6 +16,7 @@ use ratatui::{
 };
 use serde::Serialize;
 
+use super::exit::CliError;
 use super::picker::{self, PickerTerminal};
 use crate::formatting::truncate;
 use crate::status::{
@@ -206,14 +207,17 @@ fn dispatch(command: Option<StatusCommand>) -> Result<()> {
 // ── Data fetching ───────────────────────────────────────────────────
 
 fn fetch_all_statuses() -> Result<Vec<ProviderStatus>> {
+    if crate::offline::is_offline() {
+        return Err(crate::offline::OfflineUnavailable(
+            "Provider status checks require network access".to_string(),
+        )
+        .into());
+    }
     let seeds: Vec<_> = STATUS_REGISTRY
         .iter()
         .map(|e| status_seed_for_provider(e.slug))
         .collect();
-    let client = reqwest::Client::builder()
-        .user_agent("models-cli")
-        .connect_timeout(Duration::from_secs(5))
-        .build()?;
+    let client = crate::http::client("models-cli");
     let fetcher = StatusFetcher::with_client(client);
     let runtime = tokio::runtime::Runtime::new()?;
     let StatusFetchResult::Fresh(entries) = runtime.block_on(fetcher.fetch(&seeds));
@@ -497,7 +501,7 @@ fn resolve_provider<'a>(entries: &'a [ProviderStatus], query: &str) -> Result<&'
         .collect();
 
     match matches.len() {
-        0 => bail!(
+        0 => Err(CliError::NotFound(format!(
             "Provider '{}' not found. Available: {}",
             query,
             entries
@@ -505,9 +509,10 @@ fn resolve_provider<'a>(entries: &'a [ProviderStatus], query: &str) -> Result<&'
                 .map(|e| e.slug.as_str())
                 .collect::<Vec<_>>()
                 .join(", ")
-        ),
+        ))
+        .into()),
         1 => Ok(matches[0]),
-        _ => bail!(
+        _ => Err(CliError::BadArgument(format!(
             "Provider query '{}' was ambiguous. Matches: {}",
             query,
             matches
@@ -515,7 +520,8 @@ fn resolve_provider<'a>(entries: &'a [ProviderStatus], query: &str) -> Result<&'
                 .map(|e| e.slug.as_str())
                 .collect::<Vec<_>>()
                 .join(", ")
-        ),
+        ))
+        .into()),
     }
 }
 
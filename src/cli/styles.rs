@@ -53,6 +53,14 @@ pub fn yellow_cell(text: &str) -> comfy_table::Cell {
     }
 }
 
+pub fn red_cell(text: &str) -> comfy_table::Cell {
+    if is_tty() {
+        comfy_table::Cell::new(text).fg(comfy_table::Color::Red)
+    } else {
+        comfy_table::Cell::new(text)
+    }
+}
+
 pub fn dim_cell(text: &str) -> comfy_table::Cell {
     if is_tty() {
         comfy_table::Cell::new(text).fg(comfy_table::Color::DarkGrey)
@@ -61,6 +69,31 @@ pub fn dim_cell(text: &str) -> comfy_table::Cell {
     }
 }
 
+/// Size `table` to its contents: on a terminal, shrink and wrap the widest
+/// columns to fit its width; piped output keeps every row on one line.
+/// Columns flagged in `right_aligned` (numbers, prices) line up on the right;
+/// the `whole` column (an id worth copying) is never wrapped.
+pub fn fit_table(table: &mut comfy_table::Table, right_aligned: &[bool], whole: Option<usize>) {
+    if is_tty() {
+        table.set_content_arrangement(comfy_table::ContentArrangement::Dynamic);
+        if let Ok((width, _)) = crossterm::terminal::size() {
+            table.set_width(width);
+        }
+    } else {
+        table.set_content_arrangement(comfy_table::ContentArrangement::Disabled);
+    }
+    for (i, &right) in right_aligned.iter().enumerate() {
+        if let Some(column) = table.column_mut(i) {
+            if right {
+                column.set_cell_alignment(comfy_table::CellAlignment::Right);
+            }
+        }
+    }
+    if let Some(column) = whole.and_then(|i| table.column_mut(i)) {
+        column.set_constraint(comfy_table::ColumnConstraint::ContentWidth);
+    }
+}
+
 // ── Inline text helpers (crossterm) ──────────────────────────────────
 pub fn agent_name(text: &str) -> String {
     if is_tty() {
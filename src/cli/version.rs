@@ -0,0 +1,57 @@
+use anyhow::Result;
+use serde::Serialize;
+
+/// Build details for bug reports, printed by `--version-json`.
+#[derive(Debug, Serialize)]
+struct VersionInfo {
+    version: &'static str,
+    profile: &'static str,
+    /// Set when the build exported `MODELS_GIT_COMMIT`
+    git_commit: Option<&'static str>,
+    tls_backend: &'static str,
+    agents_schema_version: u32,
+    benchmark_cache_version: u32,
+}
+
+impl VersionInfo {
+    fn current() -> Result<Self> {
+        Ok(Self {
+            version: env!("CARGO_PKG_VERSION"),
+            profile: if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            },
+            git_commit: option_env!("MODELS_GIT_COMMIT"),
+            tls_backend: if cfg!(feature = "native-tls") {
+                "native-tls"
+            } else {
+                "rustls"
+            },
+            agents_schema_version: crate::agents::load_agents()?.schema_version,
+            benchmark_cache_version: crate::benchmarks::BENCHMARK_CACHE_VERSION,
+        })
+    }
+}
+
+pub fn run() -> Result<()> {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&VersionInfo::current()?)?
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_info_reports_crate_version_and_schemas() {
+        let info = VersionInfo::current().unwrap();
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+        assert!(json["agents_schema_version"].as_u64().unwrap() >= 1);
+        assert!(json.get("git_commit").is_some());
+    }
+}
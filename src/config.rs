@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 
 /// Routing discriminant for symlink aliases -- not a config field, not serde-derived.
@@ -57,6 +57,30 @@ pub struct Config {
     pub status: StatusConfig,
     #[serde(default)]
     pub aliases: AliasesConfig,
+    #[serde(default)]
+    pub search_history: SearchHistoryConfig,
+    /// Recently viewed `provider/model` keys, most recent first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_models: Vec<String>,
+    /// Starred `provider/model` keys for the "Favorites" provider entry,
+    /// ordered so saves don't reshuffle the list
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub favorite_models: BTreeSet<String>,
+    /// Provider id → category name, overriding the built-in classification.
+    /// Kept as text so a mistyped name is reported (and saved back as-is)
+    /// instead of failing the whole load; see `provider_category::parse_overrides`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub provider_categories: HashMap<String, String>,
+    /// (model id, benchmark slug) pairs the benchmark matcher must never pair up
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub benchmark_blocklist: Vec<(String, String)>,
+    /// Currency code → units per US dollar, for `display.currency`/`--currency`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub exchange_rates: HashMap<String, f64>,
+    /// config.toml exists but couldn't be read or parsed: this is a stand-in
+    /// default and `save()` refuses to overwrite the user's file with it
+    #[serde(skip)]
+    pub load_failed: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -129,20 +153,39 @@ impl Default for AgentsConfig {
 pub struct CacheConfig {
     #[serde(default = "default_github_ttl")]
     pub github_ttl_seconds: u64,
+    /// Hours before cached benchmark data is re-fetched; zero or negative uses the default
+    #[serde(default = "default_benchmarks_ttl")]
+    pub benchmarks_ttl_hours: i64,
 }
 
 impl Default for CacheConfig {
     fn default() -> Self {
         Self {
             github_ttl_seconds: default_github_ttl(),
+            benchmarks_ttl_hours: default_benchmarks_ttl(),
         }
     }
 }
 
+impl CacheConfig {
+    pub fn benchmarks_ttl(&self) -> std::time::Duration {
+        let hours = if self.benchmarks_ttl_hours > 0 {
+            self.benchmarks_ttl_hours
+        } else {
+            default_benchmarks_ttl()
+        };
+        std::time::Duration::from_secs(hours as u64 * 3600)
+    }
+}
+
 fn default_github_ttl() -> u64 {
     3600
 }
 
+fn default_benchmarks_ttl() -> i64 {
+    24
+}
+
 /// Default: all status providers tracked.
 fn default_tracked_providers() -> HashSet<String> {
     crate::status::STATUS_REGISTRY
@@ -165,10 +208,218 @@ impl Default for StatusConfig {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+/// Copy string for `c` in the Models tab
+pub const DEFAULT_COPY_TEMPLATE: &str = "{provider}/{model}";
+
+/// Placeholders accepted in `display.copy_template`
+pub const COPY_TEMPLATE_PLACEHOLDERS: &[&str] =
+    &["provider", "model", "name", "input_cost", "context"];
+
+fn default_copy_template() -> String {
+    DEFAULT_COPY_TEMPLATE.to_string()
+}
+
+fn default_status_timeout_ms() -> u64 {
+    2000
+}
+
+fn default_env_status() -> bool {
+    true
+}
+
+/// How the TUI copies to the clipboard (`display.clipboard_backend`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardBackend {
+    /// arboard's own pick, falling back to OSC 52
+    #[default]
+    Auto,
+    /// `wl-copy`
+    Wayland,
+    /// `xclip -selection clipboard`
+    X11,
+    /// OSC 52 terminal escape only
+    Osc52,
+}
+
+/// Unit model prices are shown in (`display.cost_unit` or `--cost-unit`).
+/// models.dev quotes USD per million tokens; structured output keeps that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, clap::ValueEnum)]
+pub enum CostUnit {
+    #[default]
+    #[serde(rename = "per-1m")]
+    #[value(name = "per-1m")]
+    PerMillion,
+    #[serde(rename = "per-1k")]
+    #[value(name = "per-1k")]
+    PerThousand,
+}
+
+impl CostUnit {
+    /// Suffix after a price, as in `$2.50/M`
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::PerMillion => "/M",
+            Self::PerThousand => "/K",
+        }
+    }
+
+    /// Spelled out, for section headings
+    pub fn per_tokens(self) -> &'static str {
+        match self {
+            Self::PerMillion => "per million tokens",
+            Self::PerThousand => "per thousand tokens",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DisplayConfig {
+    /// Tab shown at launch: `models`, `agents`, `benchmarks`, or `status`
     #[serde(default)]
     pub default_tab: Option<String>,
+    /// Provider selected in the Models tab at launch instead of "All"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_provider: Option<String>,
+    /// Initial Models sort: `default`, `date`, `cost`, `context`, or `output`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub models_sort: Option<String>,
+    /// Initial Agents sort: `name`, `updated`, `stars`, or `status`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agents_sort: Option<String>,
+    /// Initial Benchmarks sort column, named as in `benchmarks list --sort`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmarks_sort: Option<String>,
+    /// Models tab provider column width in percent (15–60)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_panel_pct: Option<u16>,
+    /// Models tab detail column width in percent (15–60)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail_panel_pct: Option<u16>,
+    /// Template for the model copy string, e.g. `"{provider}:{model}"`
+    #[serde(default = "default_copy_template")]
+    pub copy_template: String,
+    /// Add a capability glyph column (🧠 🔧 👀 📎) to the Models list
+    #[serde(default)]
+    pub capability_glyphs: bool,
+    /// ASCII-only glyphs in the TUI, as with `--ascii`
+    #[serde(default)]
+    pub ascii: bool,
+    /// Clipboard backend for copy actions: `auto`, `wayland`, `x11`, or `osc52`
+    #[serde(default)]
+    pub clipboard_backend: ClipboardBackend,
+    /// Read the clipboard back after each copy and warn when it doesn't
+    /// hold the copied text; off by default as it adds latency
+    #[serde(default)]
+    pub clipboard_verify: bool,
+    /// How long status-bar messages stay up, in milliseconds; `0` keeps them
+    /// until the next key or click
+    #[serde(default = "default_status_timeout_ms")]
+    pub status_timeout_ms: u64,
+    /// Unit for model prices: `per-1m` (default) or `per-1k`
+    #[serde(default)]
+    pub cost_unit: CostUnit,
+    /// Currency code for model prices, converted with `[exchange_rates]`;
+    /// unset means USD
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Mark each provider env var in the Models detail as set or unset;
+    /// turn off to keep which keys you have off screen
+    #[serde(default = "default_env_status")]
+    pub env_status: bool,
+    /// List deprecated models in the Models tab at launch (`0` toggles them)
+    #[serde(default)]
+    pub show_deprecated: bool,
+    /// Draw the detail panels without borders at launch (`F` toggles)
+    #[serde(default)]
+    pub flat_details: bool,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            default_tab: None,
+            default_provider: None,
+            models_sort: None,
+            agents_sort: None,
+            benchmarks_sort: None,
+            provider_panel_pct: None,
+            detail_panel_pct: None,
+            copy_template: default_copy_template(),
+            capability_glyphs: false,
+            ascii: false,
+            clipboard_backend: ClipboardBackend::default(),
+            clipboard_verify: false,
+            status_timeout_ms: default_status_timeout_ms(),
+            cost_unit: CostUnit::default(),
+            currency: None,
+            env_status: default_env_status(),
+            show_deprecated: false,
+            flat_details: false,
+        }
+    }
+}
+
+impl DisplayConfig {
+    /// Replace a malformed template or one with unknown placeholders with the default.
+    fn normalize_copy_template(&mut self) {
+        let valid =
+            crate::formatting::template_placeholders(&self.copy_template).is_some_and(|names| {
+                names
+                    .iter()
+                    .all(|name| COPY_TEMPLATE_PLACEHOLDERS.contains(name))
+            });
+        if !valid {
+            self.copy_template = default_copy_template();
+        }
+    }
+}
+
+/// Most recent queries kept per tab
+pub const SEARCH_HISTORY_LIMIT: usize = 20;
+
+/// Recent search queries for each TUI tab, oldest first.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct SearchHistoryConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub models: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub benchmarks: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub status: Vec<String>,
+}
+
+/// Append a query to a history ring, moving repeats to the end and dropping
+/// the oldest entries past `SEARCH_HISTORY_LIMIT`. Returns false for blank queries.
+pub fn push_search_history(history: &mut Vec<String>, query: &str) -> bool {
+    let query = query.trim();
+    if query.is_empty() {
+        return false;
+    }
+    history.retain(|q| q != query);
+    history.push(query.to_string());
+    if history.len() > SEARCH_HISTORY_LIMIT {
+        let excess = history.len() - SEARCH_HISTORY_LIMIT;
+        history.drain(..excess);
+    }
+    true
+}
+
+/// Most recently viewed models kept for the "Recent" provider entry
+pub const RECENT_MODELS_LIMIT: usize = 20;
+
+/// Move `key` to the front of a most-recent-first list, dropping entries past
+/// `RECENT_MODELS_LIMIT`. Returns false if it was already at the front.
+pub fn push_recent_model(recent: &mut Vec<String>, key: &str) -> bool {
+    if recent.first().is_some_and(|k| k == key) {
+        return false;
+    }
+    recent.retain(|k| k != key);
+    recent.insert(0, key.to_string());
+    recent.truncate(RECENT_MODELS_LIMIT);
+    true
 }
 
 impl Config {
@@ -198,10 +449,30 @@ impl Config {
             .collect::<Vec<_>>()
             .join("\n");
 
-        toml::from_str(&content).context("Failed to parse config.toml")
+        let mut config: Self = toml::from_str(&content).context("Failed to parse config.toml")?;
+        config.display.normalize_copy_template();
+        Ok(config)
+    }
+
+    /// `load()`, or defaults marked `load_failed` when an existing file is
+    /// unreadable, with the error for the caller to report.
+    pub fn load_or_default() -> (Self, Option<anyhow::Error>) {
+        match Self::load() {
+            Ok(config) => (config, None),
+            Err(err) => {
+                let config = Self {
+                    load_failed: true,
+                    ..Self::default()
+                };
+                (config, Some(err))
+            }
+        }
     }
 
     pub fn save(&self) -> Result<()> {
+        if self.load_failed {
+            anyhow::bail!("config.toml failed to load; not overwriting it");
+        }
         let path = match Self::config_path() {
             Some(p) => p,
             None => anyhow::bail!("Could not determine config directory"),
@@ -264,6 +535,18 @@ impl Config {
         ]
     }
 
+    /// Exchange rate for a currency code (case-insensitive). USD is always 1.
+    pub fn exchange_rate(&self, code: &str) -> Option<f64> {
+        if code.eq_ignore_ascii_case("USD") {
+            return Some(1.0);
+        }
+        self.exchange_rates
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(code))
+            .map(|(_, &rate)| rate)
+            .filter(|rate| rate.is_finite() && *rate > 0.0)
+    }
+
     /// Given a binary name (from argv[0]), returns which alias it matches, if any.
     pub fn match_alias(&self, binary_name: &str) -> Option<AliasKind> {
         if binary_name == self.aliases.agents {
@@ -391,6 +674,49 @@ config_version = 1
         assert_eq!(config.aliases.status, "mstatus");
     }
 
+    #[test]
+    fn test_status_timeout_defaults_and_overrides() {
+        let config: Config = toml::from_str("[display]\nascii = true\n").expect("should parse");
+        assert_eq!(config.display.status_timeout_ms, 2000);
+
+        let config: Config =
+            toml::from_str("[display]\nstatus_timeout_ms = 0\n").expect("should parse");
+        assert_eq!(config.display.status_timeout_ms, 0);
+    }
+
+    #[test]
+    fn test_env_status_defaults_on() {
+        let config: Config = toml::from_str("").expect("should parse");
+        assert!(config.display.env_status);
+        let config: Config =
+            toml::from_str("[display]\nenv_status = false\n").expect("should parse");
+        assert!(!config.display.env_status);
+    }
+
+    #[test]
+    fn test_cost_unit_defaults_to_per_million() {
+        let config: Config = toml::from_str("[display]\nascii = true\n").expect("should parse");
+        assert_eq!(config.display.cost_unit, CostUnit::PerMillion);
+
+        let config: Config =
+            toml::from_str("[display]\ncost_unit = \"per-1k\"\n").expect("should parse");
+        assert_eq!(config.display.cost_unit, CostUnit::PerThousand);
+        assert!(toml::from_str::<Config>("[display]\ncost_unit = \"per-1g\"\n").is_err());
+    }
+
+    #[test]
+    fn test_exchange_rate_lookup() {
+        let config: Config = toml::from_str(
+            "[display]\ncurrency = \"eur\"\n[exchange_rates]\nEUR = 0.92\nGBP = -1.0\n",
+        )
+        .expect("should parse");
+        assert_eq!(config.display.currency.as_deref(), Some("eur"));
+        assert_eq!(config.exchange_rate("eur"), Some(0.92));
+        assert_eq!(config.exchange_rate("USD"), Some(1.0));
+        assert_eq!(config.exchange_rate("GBP"), None);
+        assert_eq!(config.exchange_rate("JPY"), None);
+    }
+
     #[test]
     fn test_aliases_config_custom_values() {
         let toml = r#"
@@ -407,4 +733,89 @@ status = "mystatus"
         assert_eq!(config.match_alias("mystatus"), Some(AliasKind::Status));
         assert_eq!(config.match_alias("agents"), None);
     }
+
+    #[test]
+    fn test_push_search_history_dedupes_and_caps() {
+        let mut history = Vec::new();
+        assert!(!push_search_history(&mut history, "  "));
+        assert!(push_search_history(&mut history, "claude"));
+        assert!(push_search_history(&mut history, "gpt"));
+        assert!(push_search_history(&mut history, "claude"));
+        assert_eq!(history, vec!["gpt", "claude"]);
+
+        for i in 0..SEARCH_HISTORY_LIMIT {
+            push_search_history(&mut history, &format!("q{}", i));
+        }
+        assert_eq!(history.len(), SEARCH_HISTORY_LIMIT);
+        assert_eq!(history[0], "q0");
+    }
+
+    #[test]
+    fn test_benchmarks_ttl_falls_back_to_default() {
+        let config = Config::default();
+        assert_eq!(config.cache.benchmarks_ttl().as_secs(), 24 * 3600);
+
+        let config: Config = toml::from_str("[cache]\nbenchmarks_ttl_hours = 72").unwrap();
+        assert_eq!(config.cache.benchmarks_ttl().as_secs(), 72 * 3600);
+
+        for bad in [0, -5] {
+            let toml = format!("[cache]\nbenchmarks_ttl_hours = {}", bad);
+            let config: Config = toml::from_str(&toml).unwrap();
+            assert_eq!(config.cache.benchmarks_ttl().as_secs(), 24 * 3600);
+        }
+    }
+
+    #[test]
+    fn copy_template_falls_back_on_unknown_placeholders() {
+        let mut display = DisplayConfig {
+            copy_template: "{provider}:{model} ({input_cost})".to_string(),
+            ..DisplayConfig::default()
+        };
+        display.normalize_copy_template();
+        assert_eq!(display.copy_template, "{provider}:{model} ({input_cost})");
+
+        display.copy_template = "{provider}/{slug}".to_string();
+        display.normalize_copy_template();
+        assert_eq!(display.copy_template, DEFAULT_COPY_TEMPLATE);
+
+        display.copy_template = "{model".to_string();
+        display.normalize_copy_template();
+        assert_eq!(display.copy_template, DEFAULT_COPY_TEMPLATE);
+    }
+
+    #[test]
+    fn push_recent_model_moves_repeats_to_front() {
+        let mut recent = Vec::new();
+        assert!(push_recent_model(&mut recent, "openai/gpt-4o"));
+        assert!(push_recent_model(&mut recent, "anthropic/claude"));
+        assert!(!push_recent_model(&mut recent, "anthropic/claude"));
+        assert!(push_recent_model(&mut recent, "openai/gpt-4o"));
+        assert_eq!(recent, vec!["openai/gpt-4o", "anthropic/claude"]);
+
+        for i in 0..RECENT_MODELS_LIMIT + 5 {
+            push_recent_model(&mut recent, &format!("p/m{i}"));
+        }
+        assert_eq!(recent.len(), RECENT_MODELS_LIMIT);
+    }
+
+    #[test]
+    fn recent_models_round_trip_through_toml() {
+        let config = Config {
+            recent_models: vec!["openai/gpt-4o".to_string()],
+            ..Config::default()
+        };
+        let text = toml::to_string_pretty(&config).unwrap();
+        let parsed: Config = toml::from_str(&text).unwrap();
+        assert_eq!(parsed.recent_models, config.recent_models);
+    }
+
+    #[test]
+    fn failed_load_never_saves() {
+        let config = Config {
+            load_failed: true,
+            ..Config::default()
+        };
+        let err = config.save().unwrap_err().to_string();
+        assert!(err.contains("not overwriting"));
+    }
 }
@@ -82,10 +82,59 @@ pub struct Modalities {
     pub output: Vec<String>,
 }
 
+/// What a model mainly produces, for the Models tab's output grouping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputCategory {
+    Text,
+    Image,
+    Audio,
+    Video,
+    Embedding,
+}
+
+impl OutputCategory {
+    /// Display order in the Models tab sidebar.
+    pub const ALL: [OutputCategory; 5] = [
+        OutputCategory::Text,
+        OutputCategory::Image,
+        OutputCategory::Audio,
+        OutputCategory::Video,
+        OutputCategory::Embedding,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Text => "Text",
+            Self::Image => "Image",
+            Self::Audio => "Audio",
+            Self::Video => "Video",
+            Self::Embedding => "Embeddings",
+        }
+    }
+}
+
+/// Capability keywords shared by the Models tab search and `models cheapest`.
+pub const CAPABILITY_KEYWORDS: &[&str] = &["reasoning", "tools", "files", "vision", "open", "free"];
+
 impl Model {
+    /// Whether the model has a `CAPABILITY_KEYWORDS` capability; unknown keywords match.
+    pub fn has_capability(&self, keyword: &str) -> bool {
+        match keyword {
+            "reasoning" => self.reasoning,
+            "tools" => self.tool_call,
+            "files" => self.attachment,
+            "vision" => self
+                .modalities
+                .as_ref()
+                .is_some_and(|m| m.input.iter().any(|i| i == "image")),
+            "open" => self.open_weights,
+            "free" => self.is_free(),
+            _ => true,
+        }
+    }
+
     /// Returns true if this model outputs text (or has no modalities specified).
     /// Non-text models (image gen, video gen, embeddings) return false.
-    #[cfg(test)]
     pub fn is_text_model(&self) -> bool {
         match &self.modalities {
             Some(m) => m.output.iter().any(|o| o == "text"),
@@ -93,6 +142,33 @@ impl Model {
         }
     }
 
+    /// Embedding models return vectors, so chat benchmarks and output
+    /// pricing don't apply. models.dev often lists their output as text.
+    pub fn is_embedding_model(&self) -> bool {
+        self.output_modality_category() == OutputCategory::Embedding
+    }
+
+    /// The model's most specific output: embedding models (by id or family)
+    /// first, then video, image, and audio outputs, else text. Image
+    /// generators that also return text count as image models.
+    pub fn output_modality_category(&self) -> OutputCategory {
+        let is_embedding = |s: &str| s.to_lowercase().contains("embed");
+        if is_embedding(&self.id) || self.family.as_deref().is_some_and(is_embedding) {
+            return OutputCategory::Embedding;
+        }
+        let outputs = self.modalities.as_ref().map_or(&[][..], |m| &m.output[..]);
+        let has = |kind: &str| outputs.iter().any(|o| o == kind);
+        if has("video") {
+            OutputCategory::Video
+        } else if has("image") {
+            OutputCategory::Image
+        } else if has("audio") {
+            OutputCategory::Audio
+        } else {
+            OutputCategory::Text
+        }
+    }
+
     pub fn context_str(&self) -> String {
         self.limit
             .as_ref()
@@ -124,16 +200,19 @@ impl Model {
         }
     }
 
+    /// `$input/$output` in the configured cost unit and currency.
     pub fn cost_str(&self) -> String {
+        let unit = formatting::cost_unit();
+        let sym = formatting::currency_symbol();
         match &self.cost {
             Some(c) => {
                 let input = c
                     .input
-                    .map(|v| format!("${}", v))
+                    .map(|v| format!("{sym}{}", formatting::scale_price(v, unit)))
                     .unwrap_or(formatting::EM_DASH.to_string());
                 let output = c
                     .output
-                    .map(|v| format!("${}", v))
+                    .map(|v| format!("{sym}{}", formatting::scale_price(v, unit)))
                     .unwrap_or(formatting::EM_DASH.to_string());
                 format!("{}/{}", input, output)
             }
@@ -141,13 +220,20 @@ impl Model {
         }
     }
 
-    /// Compact cost string for list columns (rounded to 1 decimal place).
+    /// Compact cost string for list columns (rounded to 1 decimal place),
+    /// in the configured cost unit and currency. With `--raw-numbers`, the
+    /// exact price.
     pub fn cost_short(value: Option<f64>) -> String {
-        match value {
-            Some(v) if v >= 100.0 => format!("${:.0}", v),
-            Some(v) if v >= 1.0 => format!("${:.1}", v),
-            Some(v) if v >= 0.01 => format!("${:.2}", v),
-            Some(v) => format!("${:.3}", v),
+        let unit = formatting::cost_unit();
+        let sym = formatting::currency_symbol();
+        match value.map(|v| formatting::scale_price(v, unit)) {
+            Some(v) if formatting::raw_numbers() => format!("{sym}{}", v),
+            Some(v) if v >= 100.0 => format!("{sym}{:.0}", v),
+            Some(v) if v >= 1.0 => format!("{sym}{:.1}", v),
+            Some(v) if v >= 0.01 => {
+                format!("{sym}{:.*}", formatting::price_decimals(v, unit, 2), v)
+            }
+            Some(v) => format!("{sym}{:.*}", formatting::price_decimals(v, unit, 3), v),
             None => "\u{2014}".to_string(),
         }
     }
@@ -257,4 +343,25 @@ mod tests {
         let m = make_model(Some(vec![]));
         assert!(!m.is_text_model(), "Empty output modalities is not text");
     }
+
+    #[test]
+    fn test_output_modality_category() {
+        let category = |out| make_model(out).output_modality_category();
+        assert_eq!(category(None), OutputCategory::Text);
+        assert_eq!(category(Some(vec!["text", "image"])), OutputCategory::Image);
+        assert_eq!(category(Some(vec!["audio"])), OutputCategory::Audio);
+        assert_eq!(
+            category(Some(vec!["video", "audio"])),
+            OutputCategory::Video
+        );
+
+        let mut m = make_model(Some(vec!["text"]));
+        m.id = "text-embedding-3-small".to_string();
+        assert_eq!(m.output_modality_category(), OutputCategory::Embedding);
+        assert!(m.is_embedding_model());
+        assert!(
+            m.is_text_model(),
+            "Text-output embeddings still count as text"
+        );
+    }
 }
@@ -3,9 +3,163 @@
 //! This module consolidates functions that were duplicated across CLI pickers,
 //! TUI rendering, and data modules.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use regex::{Regex, RegexBuilder};
 use std::cmp::Ordering;
 use std::fmt::Display;
+use std::sync::OnceLock;
+
+use crate::config::CostUnit;
+
+static RAW_NUMBERS: OnceLock<bool> = OnceLock::new();
+
+static ASCII: OnceLock<bool> = OnceLock::new();
+
+static COST_UNIT: OnceLock<CostUnit> = OnceLock::new();
+
+static CURRENCY: OnceLock<Currency> = OnceLock::new();
+
+/// Display currency for model prices; models.dev quotes USD.
+struct Currency {
+    symbol: String,
+    rate: f64,
+}
+
+/// Set once from `--raw-numbers`: token counts and prices print unabbreviated.
+pub fn enable_raw_numbers() {
+    let _ = RAW_NUMBERS.set(true);
+}
+
+pub(crate) fn raw_numbers() -> bool {
+    RAW_NUMBERS.get().copied().unwrap_or(false)
+}
+
+/// Set once from `--ascii`: glyphs fall back to plain ASCII markers.
+pub fn enable_ascii() {
+    let _ = ASCII.set(true);
+}
+
+pub(crate) fn ascii() -> bool {
+    ASCII.get().copied().unwrap_or(false)
+}
+
+/// Set once from `--cost-unit` or `display.cost_unit`.
+pub fn set_cost_unit(unit: CostUnit) {
+    let _ = COST_UNIT.set(unit);
+}
+
+pub(crate) fn cost_unit() -> CostUnit {
+    COST_UNIT.get().copied().unwrap_or_default()
+}
+
+/// Set once from `--currency` or `display.currency`, with the rate from
+/// `[exchange_rates]`. Call after `enable_ascii()`: the symbol depends on it.
+pub fn set_currency(code: &str, rate: f64) {
+    let code = code.to_ascii_uppercase();
+    let symbol = match code.as_str() {
+        "USD" => "$",
+        "EUR" if !ascii() => "\u{20ac}",
+        "GBP" if !ascii() => "\u{a3}",
+        "JPY" | "CNY" if !ascii() => "\u{a5}",
+        "INR" if !ascii() => "\u{20b9}",
+        "KRW" if !ascii() => "\u{20a9}",
+        _ => "",
+    };
+    let symbol = if symbol.is_empty() {
+        format!("{code} ")
+    } else {
+        symbol.to_string()
+    };
+    let _ = CURRENCY.set(Currency { symbol, rate });
+}
+
+/// Prefix for displayed model prices: `$` unless a currency is set.
+pub(crate) fn currency_symbol() -> &'static str {
+    CURRENCY.get().map_or("$", |c| c.symbol.as_str())
+}
+
+/// Display-currency units per US dollar: 1.0 unless a currency is set.
+pub(crate) fn currency_rate() -> f64 {
+    CURRENCY.get().map_or(1.0, |c| c.rate)
+}
+
+/// A models.dev price (USD per 1M tokens) in `unit` and the display
+/// currency. Rounded to strip the float noise dividing by 1000 leaves, so
+/// `{}` formatting stays clean.
+pub(crate) fn scale_price(per_million: f64, unit: CostUnit) -> f64 {
+    let converted = per_million * currency_rate();
+    match unit {
+        CostUnit::PerMillion => (converted * 1e10).round() / 1e10,
+        CostUnit::PerThousand => (converted / 1000.0 * 1e10).round() / 1e10,
+    }
+}
+
+/// Decimals for a scaled price below $1. Per-1K prices are mostly fractions
+/// of a cent, so they keep up to two significant digits (`0.0025`, `0.001`);
+/// per-1M uses `default`.
+pub(crate) fn price_decimals(price: f64, unit: CostUnit, default: usize) -> usize {
+    if unit == CostUnit::PerMillion || price <= 0.0 || price >= 1.0 {
+        return default;
+    }
+    let two_digits = (1.0 - price.log10().floor()) as i32;
+    let one_digit = price * 10f64.powi(two_digits - 1);
+    let decimals = if (one_digit - one_digit.round()).abs() < 1e-9 {
+        two_digits - 1
+    } else {
+        two_digits
+    };
+    (decimals as usize).clamp(default, 8)
+}
+
+/// The pattern inside a `/pattern/` search query, if the query has that form.
+pub(crate) fn slash_pattern(query: &str) -> Option<&str> {
+    query
+        .trim()
+        .strip_prefix('/')?
+        .strip_suffix('/')
+        .filter(|pattern| !pattern.is_empty())
+}
+
+/// The compile error for a `/pattern/` search query that isn't a valid
+/// regex, for showing next to the search prompt.
+pub(crate) fn search_regex_error(query: &str) -> Option<String> {
+    let pattern = slash_pattern(query)?;
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .build()
+        .err()
+        .map(|err| match err {
+            regex::Error::Syntax(msg) => msg.lines().last().unwrap_or_default().to_string(),
+            other => other.to_string(),
+        })
+}
+
+/// Matcher for a search box. `/pattern/` is a case-insensitive regex; any
+/// other query, or a pattern that fails to compile (slashes stripped), is a
+/// case-insensitive substring.
+pub(crate) enum SearchMatcher {
+    Substring(String),
+    Regex(Regex),
+}
+
+impl SearchMatcher {
+    pub(crate) fn new(query: &str) -> Self {
+        match slash_pattern(query) {
+            Some(pattern) => match RegexBuilder::new(pattern).case_insensitive(true).build() {
+                Ok(regex) => Self::Regex(regex),
+                Err(_) => Self::Substring(pattern.to_lowercase()),
+            },
+            None => Self::Substring(query.to_lowercase()),
+        }
+    }
+
+    pub(crate) fn is_match(&self, text: &str) -> bool {
+        match self {
+            Self::Substring(needle) => text.to_lowercase().contains(needle),
+            Self::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
 
 /// Em-dash sentinel for missing values (style guide mandated).
 pub(crate) const EM_DASH: &str = "\u{2014}";
@@ -36,8 +190,11 @@ pub(crate) fn truncate(s: &str, max_chars: usize) -> String {
 
 /// Format a token count: `128000` → `"128k"`, `1500000` → `"1.5M"`, `2000000` → `"2M"`.
 /// Whole values omit the decimal; sub-1k values render as raw numbers.
+/// With `--raw-numbers`, every value renders as the exact integer.
 pub(crate) fn format_tokens(n: u64) -> String {
-    if n >= 1_000_000 {
+    if raw_numbers() {
+        n.to_string()
+    } else if n >= 1_000_000 {
         let m = n as f64 / 1_000_000.0;
         if m.fract() == 0.0 {
             format!("{}M", m as u64)
@@ -123,6 +280,102 @@ pub(crate) fn parse_date_to_numeric(date_str: &str) -> Option<f64> {
     }
 }
 
+/// Parse a `YYYY-MM-DD` date string.
+pub(crate) fn parse_ymd(date_str: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date_str.trim(), "%Y-%m-%d").ok()
+}
+
+/// Check a release date against inclusive `after`/`before` bounds.
+/// A missing or unparseable release date fails whenever either bound is set.
+pub(crate) fn release_date_in_range(
+    release_date: Option<&str>,
+    after: Option<&str>,
+    before: Option<&str>,
+) -> bool {
+    if after.is_none() && before.is_none() {
+        return true;
+    }
+    let Some(date) = release_date.and_then(parse_ymd) else {
+        return false;
+    };
+    if after.and_then(parse_ymd).is_some_and(|a| date < a) {
+        return false;
+    }
+    if before.and_then(parse_ymd).is_some_and(|b| date > b) {
+        return false;
+    }
+    true
+}
+
+/// Normalize a knowledge cutoff (`YYYY`, `YYYY-MM`, or `YYYY-MM-DD`) to a
+/// comparable `YYYY-MM`. A bare year counts as January, so it never passes a
+/// bound later in that year.
+pub(crate) fn knowledge_month(value: &str) -> Option<String> {
+    let mut parts = value.trim().split('-');
+    let year = parts.next().filter(|y| y.len() == 4)?.parse::<u16>().ok()?;
+    let month = match parts.next() {
+        Some(m) => m.parse::<u8>().ok().filter(|m| (1..=12).contains(m))?,
+        None => 1,
+    };
+    if let Some(day) = parts.next() {
+        day.parse::<u8>().ok().filter(|d| (1..=31).contains(d))?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(format!("{year:04}-{month:02}"))
+}
+
+/// Check a knowledge cutoff against an inclusive lower bound (any format
+/// `knowledge_month` accepts). A missing or unparseable cutoff fails when the
+/// bound is set.
+pub(crate) fn knowledge_on_or_after(knowledge: Option<&str>, min: Option<&str>) -> bool {
+    let Some(min) = min.and_then(knowledge_month) else {
+        return true;
+    };
+    knowledge
+        .and_then(knowledge_month)
+        .is_some_and(|month| month >= min)
+}
+
+/// Names of the `{placeholder}`s in a template, in order. `None` if a brace is
+/// unbalanced or a placeholder is empty.
+pub(crate) fn template_placeholders(template: &str) -> Option<Vec<&str>> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return None;
+        }
+        let after = &rest[start + 1..];
+        let end = after.find('}')?;
+        let name = &after[..end];
+        if name.is_empty() || name.contains('{') {
+            return None;
+        }
+        names.push(name);
+        rest = &after[end + 1..];
+    }
+    Some(names)
+}
+
+/// Replace each `{placeholder}` in a template with `lookup(name)`. Callers
+/// validate the template first; unbalanced braces are copied through as-is.
+pub(crate) fn expand_template(template: &str, lookup: impl Fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&lookup(&rest[start + 1..start + end]));
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
 /// Compare two `Option<f64>` values. `None` sorts last (Greater).
 pub(crate) fn cmp_opt_f64(a: Option<f64>, b: Option<f64>) -> Ordering {
     match (a, b) {
@@ -178,6 +431,44 @@ mod tests {
         assert_eq!(format_tokens(2000000), "2M");
     }
 
+    #[test]
+    fn test_scale_price_and_decimals() {
+        assert_eq!(scale_price(2.5, CostUnit::PerMillion), 2.5);
+        assert_eq!(scale_price(2.5, CostUnit::PerThousand), 0.0025);
+        assert_eq!(
+            scale_price(0.15, CostUnit::PerThousand).to_string(),
+            "0.00015"
+        );
+
+        assert_eq!(price_decimals(0.0025, CostUnit::PerMillion, 3), 3);
+        assert_eq!(price_decimals(0.0025, CostUnit::PerThousand, 3), 4);
+        assert_eq!(price_decimals(0.001, CostUnit::PerThousand, 2), 3);
+        assert_eq!(price_decimals(0.015, CostUnit::PerThousand, 2), 3);
+        assert_eq!(price_decimals(0.5, CostUnit::PerThousand, 2), 2);
+        assert_eq!(price_decimals(0.0, CostUnit::PerThousand, 2), 2);
+    }
+
+    #[test]
+    fn test_search_matcher_regex_and_fallback() {
+        let regex = SearchMatcher::new("/^gpt-4o?$/");
+        assert!(matches!(regex, SearchMatcher::Regex(_)));
+        assert!(regex.is_match("GPT-4"));
+        assert!(regex.is_match("gpt-4o"));
+        assert!(!regex.is_match("gpt-4o-mini"));
+
+        // Not slash-delimited: plain substring
+        assert!(SearchMatcher::new("/gpt").is_match("openai/gpt-4o"));
+        assert!(SearchMatcher::new("Mini").is_match("gpt-4o-mini"));
+
+        // Invalid pattern: literal search on the text between the slashes
+        let broken = SearchMatcher::new("/gpt(/");
+        assert!(matches!(broken, SearchMatcher::Substring(_)));
+        assert!(broken.is_match("weird gpt( name"));
+        assert!(search_regex_error("/gpt(/").is_some());
+        assert!(search_regex_error("/gpt/").is_none());
+        assert!(search_regex_error("gpt(").is_none());
+    }
+
     #[test]
     fn test_format_stars() {
         assert_eq!(format_stars(0), "0");
@@ -283,4 +574,62 @@ mod tests {
         assert_eq!(cmp_opt_f64(None, Some(1.0)), Ordering::Greater);
         assert_eq!(cmp_opt_f64(None, None), Ordering::Equal);
     }
+
+    #[test]
+    fn test_release_date_in_range() {
+        assert!(release_date_in_range(None, None, None));
+        assert!(!release_date_in_range(None, Some("2024-01-01"), None));
+        assert!(release_date_in_range(
+            Some("2024-06-01"),
+            Some("2024-06-01"),
+            Some("2024-12-31")
+        ));
+        assert!(!release_date_in_range(
+            Some("2023-12-31"),
+            Some("2024-01-01"),
+            None
+        ));
+        assert!(!release_date_in_range(
+            Some("2025-01-01"),
+            None,
+            Some("2024-12-31")
+        ));
+    }
+
+    #[test]
+    fn test_knowledge_cutoff_formats() {
+        assert_eq!(knowledge_month("2024").as_deref(), Some("2024-01"));
+        assert_eq!(knowledge_month("2024-4").as_deref(), Some("2024-04"));
+        assert_eq!(knowledge_month("2024-04-30").as_deref(), Some("2024-04"));
+        assert_eq!(knowledge_month("April 2024"), None);
+        assert_eq!(knowledge_month("2024-13"), None);
+
+        assert!(knowledge_on_or_after(None, None));
+        assert!(!knowledge_on_or_after(None, Some("2024-01")));
+        assert!(knowledge_on_or_after(Some("2024-06"), Some("2024-06-15")));
+        assert!(!knowledge_on_or_after(Some("2024"), Some("2024-06")));
+        assert!(knowledge_on_or_after(Some("2025"), Some("2024-06-01")));
+    }
+
+    #[test]
+    fn test_template_placeholders() {
+        assert_eq!(
+            template_placeholders("{provider}/{model}"),
+            Some(vec!["provider", "model"])
+        );
+        assert_eq!(template_placeholders("plain"), Some(vec![]));
+        assert_eq!(template_placeholders("{model"), None);
+        assert_eq!(template_placeholders("model}"), None);
+        assert_eq!(template_placeholders("{}"), None);
+    }
+
+    #[test]
+    fn test_expand_template() {
+        let out = expand_template("{model} ({input_cost}/M)", |name| match name {
+            "model" => "gpt-4o".to_string(),
+            "input_cost" => "$2.5".to_string(),
+            _ => String::new(),
+        });
+        assert_eq!(out, "gpt-4o ($2.5/M)");
+    }
 }
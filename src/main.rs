@@ -5,11 +5,15 @@ mod cli;
 mod config;
 mod data;
 mod formatting;
+mod http;
+mod notice;
+mod offline;
 mod provider_category;
 mod status;
 mod tui;
 
 use std::path::PathBuf;
+use std::process::ExitCode;
 
 use anyhow::Result;
 use clap::{CommandFactory, Parser, Subcommand};
@@ -30,14 +34,24 @@ use clap_complete::Shell;
   providers      List providers
   show           Show detailed information about a model
   search         Search models by name or provider
+  env            Print a provider's environment variables
+  cost           Estimate a request's cost on a model
+  budget         Fail when a request would exceed a cost ceiling
+  cheapest       Cheapest models by input price across providers
+  updated        Models updated on or after a date
+  diff           Compare two saved models.dev snapshots
 
 \x1b[1;4mSetup:\x1b[0m
   completions    Generate shell completions
+  doctor         Diagnose config, caches, and network access
   link           Create shell symlinks for `agents`, `benchmarks`, and `status` commands
 
 \x1b[1;4mAdditional:\x1b[0m
   agents         Track AI coding agent releases and changelogs
   benchmarks     Query benchmark data from the command line
+  leaderboard    Top benchmark entries for one metric
+  open-weights   Audit how benchmark entries resolve to open or closed weights
+  dump           Every text model joined with its benchmark scores
   status         Check AI provider service health
 
 \x1b[1;4mOptions:\x1b[0m
@@ -49,10 +63,53 @@ use clap_complete::Shell;
   models benchmarks list              Open the inline benchmark picker
   models agents claude                Browse Claude Code releases
   models link                         Create `agents`, `benchmarks`, and `mstatus` symlinks
+
+\x1b[1;4mExit Codes:\x1b[0m
+  0 success   1 other error   2 not found   3 network error   4 bad argument
+  5 over budget (`models budget`)
 ")]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    /// Suppress informational stderr output (progress, cache notices)
+    #[arg(long, short, global = true)]
+    quiet: bool,
+    /// Never touch the network; use cached data only
+    #[arg(long, global = true)]
+    offline: bool,
+    /// HTTP connect/read timeout in seconds
+    #[arg(
+        long,
+        global = true,
+        value_name = "SECS",
+        default_value_t = http::DEFAULT_TIMEOUT_SECS,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    timeout: u64,
+    /// Proxy URL for all requests (overrides HTTPS_PROXY/HTTP_PROXY)
+    #[arg(long, global = true, value_name = "URL", value_parser = http::parse_proxy_url)]
+    proxy: Option<String>,
+    /// Providers data URL (default https://models.dev/api.json; env MODELS_DEV_URL)
+    #[arg(long, global = true, value_name = "URL", value_parser = api::parse_api_url)]
+    api_url: Option<String>,
+    /// Read providers data from a saved api.json instead of fetching it
+    #[arg(long, global = true, value_name = "PATH", conflicts_with = "api_url")]
+    data_file: Option<std::path::PathBuf>,
+    /// Print exact token counts and prices instead of abbreviations like 128k
+    #[arg(long, global = true)]
+    raw_numbers: bool,
+    /// Use ASCII instead of Unicode glyphs (also `display.ascii` in config)
+    #[arg(long, global = true)]
+    ascii: bool,
+    /// Show model prices per million or per thousand tokens (also `display.cost_unit`)
+    #[arg(long, global = true, value_enum, value_name = "UNIT")]
+    cost_unit: Option<config::CostUnit>,
+    /// Show model prices in this currency, using `[exchange_rates]` from config
+    #[arg(long, global = true, value_name = "CODE")]
+    currency: Option<String>,
+    /// Print version and build details as JSON
+    #[arg(long, hide = true)]
+    version_json: bool,
 }
 
 #[derive(Subcommand)]
@@ -62,37 +119,86 @@ enum Commands {
 \x1b[1;4mExamples:\x1b[0m
   models list                         Open the interactive model picker
   models list openai                  Picker prefiltered to a provider
-  models list --json                  Dump model rows as JSON")]
+  models list --json                  Dump model rows as JSON
+  models list openai --format yaml    Dump model rows as YAML
+  models list --released-after 2025-01-01
+  models list --min-knowledge 2024-06
+  models list openai --status active  Hide beta and deprecated models
+  models list --sort output           Largest max output first
+  models list --columns id,input,context,reasoning")]
     List {
         /// Filter by provider ID or exact provider name
         provider: Option<String>,
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Structured output format
+        #[arg(long, value_enum, value_name = "FORMAT", conflicts_with = "json")]
+        format: Option<cli::output::OutputFormat>,
+        /// Only models released on or after this date (YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        released_after: Option<String>,
+        /// Only models released on or before this date (YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        released_before: Option<String>,
+        /// Only models with a knowledge cutoff on or after this date
+        /// (YYYY, YYYY-MM, or YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        min_knowledge: Option<String>,
+        /// Only models with this lifecycle status (active means no status set)
+        #[arg(long, value_enum)]
+        status: Option<cli::models::ModelStatus>,
+        /// Sort column (table, picker, and structured output); drops models
+        /// without a value for it
+        #[arg(long, value_enum)]
+        sort: Option<cli::models::ModelSort>,
+        /// Print a table with these columns, in this order (comma-separated)
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            value_name = "COLUMNS",
+            conflicts_with_all = ["json", "format"]
+        )]
+        columns: Vec<cli::list::ModelColumn>,
     },
     /// List providers
     #[command(after_help = "\
 \x1b[1;4mExamples:\x1b[0m
   models providers
+  models providers --summary          Largest providers first, with bars
   models providers --json")]
     Providers {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Sort by model count and draw a bar per provider
+        #[arg(long)]
+        summary: bool,
     },
     /// Show detailed information about a model
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models show gpt-4o
+  models show gpt-4o --json
+  models show claude-opus-4-1 --format toml")]
     Show {
         /// Model ID (e.g., claude-opus-4-1, gpt-4o)
         model_id: String,
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Structured output format
+        #[arg(long, value_enum, value_name = "FORMAT", conflicts_with = "json")]
+        format: Option<cli::output::OutputFormat>,
     },
     /// Search models by name or provider
     #[command(after_help = "\
 \x1b[1;4mExamples:\x1b[0m
   models search claude
   models search gpt-4o --json
+  models search claude --released-after 2025-01-01
+  models search llama --group-by-provider
 
 \x1b[1;4mNote:\x1b[0m
   Search now uses the same matcher and interactive picker flow as `models list`.")]
@@ -102,11 +208,228 @@ enum Commands {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Structured output format
+        #[arg(long, value_enum, value_name = "FORMAT", conflicts_with = "json")]
+        format: Option<cli::output::OutputFormat>,
+        /// Only models released on or after this date (YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        released_after: Option<String>,
+        /// Only models released on or before this date (YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        released_before: Option<String>,
+        /// Only models with a knowledge cutoff on or after this date
+        /// (YYYY, YYYY-MM, or YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        min_knowledge: Option<String>,
+        /// Only models with this lifecycle status (active means no status set)
+        #[arg(long, value_enum)]
+        status: Option<cli::models::ModelStatus>,
+        /// Sort column (table, picker, and structured output); drops models
+        /// without a value for it
+        #[arg(long, value_enum)]
+        sort: Option<cli::models::ModelSort>,
+        /// Print matches as one table per provider instead of the picker
+        /// (ignored with --json/--format)
+        #[arg(long)]
+        group_by_provider: bool,
+    },
+    /// Print a provider's environment variables as shell exports
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models env anthropic                Print `export ANTHROPIC_API_KEY=`
+  models env amazon-bedrock --check   Report which variables are set")]
+    Env {
+        /// Provider ID or exact provider name
+        provider_id: String,
+        /// Report which variables are set in the current environment
+        #[arg(long)]
+        check: bool,
+    },
+    /// Estimate what a request costs on a model
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models cost gpt-4o --input 12000 --output 800
+  models cost claude-sonnet-4 --input 200k --output 4k --cache-read 150k
+  models cost openai/gpt-4o --input 1M --output 100k --json
+
+\x1b[1;4mNote:\x1b[0m
+  Cache tokens use the input price when a model lists no cache pricing.
+  --json reports USD at full precision: model, input_tokens, output_tokens,
+  input_cost, output_cost, cache_cost, and total.")]
+    Cost {
+        /// Model ID, provider/model, or name
+        model: String,
+        /// Input (prompt) tokens, e.g. 12000 or 200k
+        #[arg(long, value_parser = cli::models::parse_token_arg)]
+        input: u64,
+        /// Output (completion) tokens
+        #[arg(long, value_parser = cli::models::parse_token_arg)]
+        output: u64,
+        /// Tokens read from the prompt cache
+        #[arg(long, value_parser = cli::models::parse_token_arg, default_value = "0")]
+        cache_read: u64,
+        /// Tokens written to the prompt cache
+        #[arg(long, value_parser = cli::models::parse_token_arg, default_value = "0")]
+        cache_write: u64,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Fail when a request on a model would cost more than a ceiling
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models budget gpt-4o --input 12k --output 1k --max 0.05
+  models budget openai/o1 --input 200k --output 20k --max 1 || echo \"too pricey\"
+
+\x1b[1;4mNote:\x1b[0m
+  Costs are computed like `models cost`, in USD. Exits 0 within budget and
+  5 when over, printing the cost and the limit either way.")]
+    Budget {
+        /// Model ID, provider/model, or name
+        model: String,
+        /// Input (prompt) tokens, e.g. 12000 or 200k
+        #[arg(long, value_parser = cli::models::parse_token_arg)]
+        input: u64,
+        /// Output (completion) tokens
+        #[arg(long, value_parser = cli::models::parse_token_arg)]
+        output: u64,
+        /// Cost ceiling in USD
+        #[arg(long)]
+        max: f64,
+    },
+    /// Cheapest models by input price across all providers
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models cheapest                     Top 10 cheapest models
+  models cheapest reasoning           Cheapest reasoning models
+  models cheapest vision --json
+
+\x1b[1;4mCapabilities:\x1b[0m
+  reasoning, tools, files, vision, open, free")]
+    Cheapest {
+        /// Only models with this capability
+        capability: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Models updated on or after a date, newest first
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models updated 2025-06-01
+  models updated 2025-06-01 --json
+
+\x1b[1;4mNote:\x1b[0m
+  Uses `last_updated`, or `release_date` when a model has no update date.
+  Models with neither are skipped.")]
+    Updated {
+        /// Earliest date to include (YYYY-MM-DD)
+        since: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare two saved models.dev snapshots
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  curl -s https://models.dev/api.json > api-$(date +%F).json
+  models diff api-2025-06-01.json api-2025-07-01.json
+  models diff old.json new.json --json
+
+\x1b[1;4mNote:\x1b[0m
+  Models are matched by provider/model. Changed fields are input_cost,
+  output_cost, context, output_limit, and status.")]
+    Diff {
+        /// Older api.json snapshot
+        old: PathBuf,
+        /// Newer api.json snapshot
+        new: PathBuf,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Every text model joined with its benchmark entry, for scripting
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models dump > models.jsonl          One JSON record per line
+  models dump --json                  A single JSON array
+
+\x1b[1;4mNote:\x1b[0m
+  Each record has the model's `list --json` fields plus `matched`,
+  `match_tier` (exact, creator, or global), `match_score`, and `benchmark`
+  (null when no benchmark entry matches).")]
+    Dump {
+        /// Output a single JSON array instead of JSON Lines
+        #[arg(long)]
+        json: bool,
+    },
+    /// Diagnose config, caches, and network access
+    #[command(after_help = "\
+\x1b[1;4mChecks:\x1b[0m
+  Config file validity, cache ages, models.dev and GitHub API reachability
+  (with rate limit), and clipboard availability. Exits non-zero when the
+  config or models.dev check fails.")]
+    Doctor,
+    /// Top benchmark entries for one metric
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models leaderboard coding           Top 10 by Coding Index
+  models leaderboard speed --top 20   Fastest output speed
+  models leaderboard intelligence --min 50
+  models leaderboard coding --open-only   Only open-weight models
+  models leaderboard price-blended --json
+
+\x1b[1;4mMetrics:\x1b[0m
+  Any `benchmarks list --sort` column except name and release-date.
+  Latency and price metrics rank lowest first.")]
+    Leaderboard {
+        /// Metric to rank by (e.g. intelligence, coding, speed)
+        metric: String,
+        /// Number of entries to show
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Only entries scoring at least this value
+        #[arg(long, value_name = "VALUE", allow_negative_numbers = true)]
+        min: Option<f64>,
+        /// Only entries whose matched models.dev model has open weights
+        #[arg(long)]
+        open_only: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Resolved open/closed weights for every benchmark entry
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models open-weights                 Table with the match stage per entry
+  models open-weights --json          Entries plus per-stage counts
+
+\x1b[1;4mStages:\x1b[0m
+  creator        Matched a model from the entry's creator's providers
+  global         Creator-scoped match failed or scored lower; matched any provider
+  known creator  No model matched; fell back to the creator's known license
+  unmatched      No source; shown as \u{2014} in the TUI")]
+    OpenWeights {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Generate shell completions
+    #[command(after_help = "\
+\x1b[1;4mExamples:\x1b[0m
+  models completions zsh > ~/.zfunc/_models
+  models completions bash --with-ids > ~/.local/share/bash-completion/completions/models
+
+\x1b[1;4mNote:\x1b[0m
+  --with-ids completes model ids for `show`, `cost`, and `budget`, and provider
+  ids for `list` and `env` (bash and zsh), read from the model cache. The ids
+  are fixed when the script is generated; regenerate it to pick up new models.")]
     Completions {
         /// Shell to generate completions for
         shell: Shell,
+        /// Bake the current model and provider ids into the script
+        #[arg(long)]
+        with_ids: bool,
     },
     /// Track AI coding agent releases and changelogs
     #[command(after_help = "\
@@ -121,10 +444,15 @@ enum Commands {
   agents claude                 Browse Claude Code releases
   agents claude --latest        Latest Claude Code changelog
   agents cursor --list          All Cursor versions
-  agents cursor --version 1.0.0 Show a specific Cursor version")]
+  agents cursor --version 1.0.0 Show a specific Cursor version
+  agents --json                 Tracked agents and latest versions as JSON")]
+    #[command(args_conflicts_with_subcommands = true)]
     Agents {
         #[command(subcommand)]
         command: Option<cli::agents::AgentsCommand>,
+        /// Output tracked agents and their cached latest versions as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Query benchmark data from the command line
     #[command(after_help = "\
@@ -174,7 +502,18 @@ enum Commands {
     },
 }
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            // Same rendering as returning `Result` from main
+            eprintln!("Error: {:?}", err);
+            ExitCode::from(cli::exit::code_for(&err))
+        }
+    }
+}
+
+fn run() -> Result<()> {
     // Check if invoked via a symlink alias (e.g. "agents", "benchmarks", "mstatus")
     let binary_name = std::env::args()
         .next()
@@ -186,6 +525,7 @@ fn main() -> Result<()> {
         .unwrap_or_default();
 
     let config = config::Config::load().unwrap_or_default();
+    benchmarks::set_blocklist(&config.benchmark_blocklist);
     if let Some(kind) = config.match_alias(&binary_name) {
         return match kind {
             config::AliasKind::Agents => cli::agents::run(),
@@ -194,17 +534,159 @@ fn main() -> Result<()> {
         };
     }
 
-    let cli = Cli::parse();
+    // Usage errors exit with BAD_ARGUMENT instead of clap's default of 2,
+    // which is reserved for "not found"
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) if err.use_stderr() => {
+            let _ = err.print();
+            std::process::exit(cli::exit::BAD_ARGUMENT.into());
+        }
+        Err(err) => err.exit(),
+    };
+    if cli.quiet {
+        notice::set_verbosity(notice::Verbosity::Quiet);
+    }
+    if cli.offline {
+        offline::enable();
+    }
+    http::set_timeout(std::time::Duration::from_secs(cli.timeout));
+    if let Some(proxy) = cli.proxy {
+        http::set_proxy(proxy);
+    }
+    if let Some(url) = cli.api_url {
+        api::set_api_url(url);
+    }
+    if let Some(path) = cli.data_file {
+        api::set_data_file(path);
+    }
+    // The TUI keeps abbreviations; its columns are sized for them
+    if cli.raw_numbers && cli.command.is_some() {
+        formatting::enable_raw_numbers();
+    }
+    // `display.ascii` only covers the TUI; set before the currency symbol is picked
+    if cli.ascii || (cli.command.is_none() && config.display.ascii) {
+        formatting::enable_ascii();
+    }
+    formatting::set_cost_unit(cli.cost_unit.unwrap_or(config.display.cost_unit));
+    let missing_rate = |code: &str| {
+        format!(
+            "No exchange rate for {code}; add one under [exchange_rates] in config.toml \
+             (units per US dollar, e.g. {} = 0.92)",
+            code.to_ascii_uppercase()
+        )
+    };
+    if let Some(code) = cli.currency.as_ref() {
+        let Some(rate) = config.exchange_rate(code) else {
+            return Err(cli::exit::CliError::BadArgument(missing_rate(code)).into());
+        };
+        formatting::set_currency(code, rate);
+    } else if let Some(code) = config.display.currency.as_ref() {
+        // A bad config value isn't a usage error; fall back to USD
+        match config.exchange_rate(code) {
+            Some(rate) => formatting::set_currency(code, rate),
+            None => notice::info(&format!("warning: {}; showing USD", missing_rate(code))),
+        }
+    }
+    if cli.version_json {
+        return cli::version::run();
+    }
 
     match cli.command {
-        Some(Commands::List { provider, json }) => cli::list::models(provider, json)?,
-        Some(Commands::Providers { json }) => cli::list::providers(json)?,
-        Some(Commands::Show { model_id, json }) => cli::show::model(&model_id, json)?,
-        Some(Commands::Search { query, json }) => cli::search::search(&query, json)?,
-        Some(Commands::Completions { shell }) => {
-            clap_complete::generate(shell, &mut Cli::command(), "models", &mut std::io::stdout());
+        Some(Commands::List {
+            provider,
+            json,
+            format,
+            released_after,
+            released_before,
+            min_knowledge,
+            status,
+            sort,
+            columns,
+        }) => {
+            let range = cli::models::ReleaseRange::new(released_after, released_before)?
+                .with_min_knowledge(min_knowledge)?
+                .with_status(status);
+            let format = cli::output::OutputFormat::resolve(json, format);
+            cli::list::models(provider, format, &range, sort, &columns)?
         }
-        Some(Commands::Agents { command }) => cli::agents::run_with_command(command)?,
+        Some(Commands::Providers { json, summary }) => cli::list::providers(json, summary)?,
+        Some(Commands::Show {
+            model_id,
+            json,
+            format,
+        }) => {
+            let format = cli::output::OutputFormat::resolve(json, format);
+            cli::show::model(&model_id, format)?
+        }
+        Some(Commands::Search {
+            query,
+            json,
+            format,
+            released_after,
+            released_before,
+            min_knowledge,
+            status,
+            sort,
+            group_by_provider,
+        }) => {
+            let range = cli::models::ReleaseRange::new(released_after, released_before)?
+                .with_min_knowledge(min_knowledge)?
+                .with_status(status);
+            let format = cli::output::OutputFormat::resolve(json, format);
+            cli::search::search(&query, format, &range, sort, group_by_provider)?
+        }
+        Some(Commands::Env { provider_id, check }) => cli::env::run(&provider_id, check)?,
+        Some(Commands::Cost {
+            model,
+            input,
+            output,
+            cache_read,
+            cache_write,
+            json,
+        }) => cli::models::cost(
+            &model,
+            cli::models::TokenUsage {
+                input,
+                output,
+                cache_read,
+                cache_write,
+            },
+            json,
+        )?,
+        Some(Commands::Budget {
+            model,
+            input,
+            output,
+            max,
+        }) => cli::models::budget(
+            &model,
+            cli::models::TokenUsage {
+                input,
+                output,
+                ..Default::default()
+            },
+            max,
+        )?,
+        Some(Commands::Cheapest { capability, json }) => {
+            cli::models::cheapest(capability.as_deref(), json)?
+        }
+        Some(Commands::Updated { since, json }) => cli::models::updated(&since, json)?,
+        Some(Commands::Diff { old, new, json }) => cli::diff::run(&old, &new, json)?,
+        Some(Commands::Dump { json }) => cli::dump::run(json)?,
+        Some(Commands::Doctor) => cli::doctor::run()?,
+        Some(Commands::Leaderboard {
+            metric,
+            top,
+            min,
+            open_only,
+            json,
+        }) => cli::benchmarks::leaderboard(&metric, top, min, open_only, json)?,
+        Some(Commands::OpenWeights { json }) => cli::benchmarks::open_weights(json)?,
+        Some(Commands::Completions { shell, with_ids }) => {
+            cli::completions::run(Cli::command(), shell, with_ids)?
+        }
+        Some(Commands::Agents { command, json }) => cli::agents::run_with_command(command, json)?,
         Some(Commands::Benchmarks { command }) => cli::benchmarks::run_with_command(command)?,
         Some(Commands::Status { command }) => cli::status::run_with_command(command)?,
         Some(Commands::Link {
@@ -213,12 +695,10 @@ fn main() -> Result<()> {
             status,
         }) => cli::link::run(dir, remove, status, &config)?,
         None => {
-            // Fetch providers before entering async runtime to avoid blocking in async context
-            let providers = api::fetch_providers()?;
-
-            // Create and run the async runtime only for the TUI
+            // Create and run the async runtime only for the TUI; providers are
+            // fetched in the background behind a loading screen
             let runtime = tokio::runtime::Runtime::new()?;
-            runtime.block_on(tui::run(providers))?;
+            runtime.block_on(tui::run())?;
         }
     }
 
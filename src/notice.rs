@@ -0,0 +1,39 @@
+//! Informational stderr messages (progress, cache fallbacks) for CLI commands.
+//!
+//! Routed through here so `--quiet` can silence them. Errors that fail the
+//! command are returned as `anyhow::Error` from `main` and are never suppressed.
+//! Never call these from TUI code; stderr corrupts the alternate screen.
+
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Verbosity {
+    #[default]
+    Normal,
+    Quiet,
+}
+
+static VERBOSITY: OnceLock<Verbosity> = OnceLock::new();
+
+/// Set once from the parsed CLI; later calls are ignored.
+pub fn set_verbosity(verbosity: Verbosity) {
+    let _ = VERBOSITY.set(verbosity);
+}
+
+fn is_quiet() -> bool {
+    VERBOSITY.get().copied().unwrap_or_default() == Verbosity::Quiet
+}
+
+/// Print a full informational line to stderr.
+pub fn info(msg: &str) {
+    if !is_quiet() {
+        eprintln!("{}", msg);
+    }
+}
+
+/// Print a progress fragment to stderr without a newline (e.g. "Fetching...").
+pub fn progress(msg: &str) {
+    if !is_quiet() {
+        eprint!("{}", msg);
+    }
+}
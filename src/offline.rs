@@ -0,0 +1,28 @@
+//! Global `--offline` switch. When set, every fetcher serves cached data only
+//! and never opens a network connection.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+static OFFLINE: OnceLock<bool> = OnceLock::new();
+
+/// Set once from the parsed CLI, before any fetch starts.
+pub fn enable() {
+    let _ = OFFLINE.set(true);
+}
+
+pub fn is_offline() -> bool {
+    OFFLINE.get().copied().unwrap_or(false)
+}
+
+/// Returned when `--offline` is set and no cached copy of the requested data exists.
+#[derive(Debug)]
+pub struct OfflineUnavailable(pub String);
+
+impl fmt::Display for OfflineUnavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (offline mode)", self.0)
+    }
+}
+
+impl std::error::Error for OfflineUnavailable {}
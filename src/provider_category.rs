@@ -1,7 +1,17 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+/// `[provider_categories]` from config, set once at TUI startup.
+static OVERRIDES: OnceLock<HashMap<String, ProviderCategory>> = OnceLock::new();
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ProviderCategory {
+    /// Filter-only pseudo category; not a valid override
+    #[serde(skip)]
     All,
     Origin,
     Cloud,
@@ -69,7 +79,69 @@ impl ProviderCategory {
     }
 }
 
+/// Split config overrides into valid ones and `id = "name"` descriptions of
+/// entries whose category name isn't one of origin/cloud/inference/gateway/tool.
+pub fn parse_overrides(
+    raw: &HashMap<String, String>,
+) -> (HashMap<String, ProviderCategory>, Vec<String>) {
+    use serde::de::IntoDeserializer;
+    let mut valid = HashMap::new();
+    let mut invalid = Vec::new();
+    for (id, name) in raw {
+        let parsed: Result<ProviderCategory, serde::de::value::Error> =
+            ProviderCategory::deserialize(name.as_str().into_deserializer());
+        match parsed {
+            Ok(category) => {
+                valid.insert(id.clone(), category);
+            }
+            Err(_) => invalid.push(format!("{id} = \"{name}\"")),
+        }
+    }
+    invalid.sort();
+    (valid, invalid)
+}
+
+/// Install the user's category overrides; later calls are ignored.
+pub fn set_overrides(overrides: HashMap<String, ProviderCategory>) {
+    let _ = OVERRIDES.set(overrides);
+}
+
+/// Category for a provider id: the config override if present, else the
+/// built-in classification.
 pub fn provider_category(id: &str) -> ProviderCategory {
+    resolve(id, OVERRIDES.get())
+}
+
+fn resolve(id: &str, overrides: Option<&HashMap<String, ProviderCategory>>) -> ProviderCategory {
+    overrides
+        .and_then(|o| o.get(id).copied())
+        .unwrap_or_else(|| builtin_category(id))
+}
+
+/// Override ids that match no loaded provider, sorted. Usually a typo, or a
+/// provider that left models.dev.
+pub fn unknown_overrides<'a>(provider_ids: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    OVERRIDES
+        .get()
+        .map(|o| unknown_ids(o, provider_ids))
+        .unwrap_or_default()
+}
+
+fn unknown_ids<'a>(
+    overrides: &HashMap<String, ProviderCategory>,
+    provider_ids: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let known: std::collections::HashSet<&str> = provider_ids.into_iter().collect();
+    let mut unknown: Vec<String> = overrides
+        .keys()
+        .filter(|id| !known.contains(id.as_str()))
+        .cloned()
+        .collect();
+    unknown.sort();
+    unknown
+}
+
+fn builtin_category(id: &str) -> ProviderCategory {
     match id {
         // Origin (26): Provider created these models
         "anthropic"
@@ -185,6 +257,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_overrides_take_precedence() {
+        let overrides: HashMap<String, ProviderCategory> =
+            toml::from_str("openrouter = \"origin\"\nnew-host = \"gateway\"\n").unwrap();
+        assert_eq!(
+            resolve("openrouter", Some(&overrides)),
+            ProviderCategory::Origin
+        );
+        assert_eq!(
+            resolve("new-host", Some(&overrides)),
+            ProviderCategory::Gateway
+        );
+        assert_eq!(
+            resolve("groq", Some(&overrides)),
+            ProviderCategory::Inference
+        );
+        assert_eq!(resolve("openrouter", None), ProviderCategory::Gateway);
+
+        assert_eq!(
+            unknown_ids(&overrides, ["openrouter", "groq"]),
+            vec!["new-host".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_override_names_are_validated() {
+        let raw: HashMap<String, String> =
+            toml::from_str("x = \"all\"\ny = \"router\"\nz = \"gateway\"\n").unwrap();
+        let (valid, invalid) = parse_overrides(&raw);
+        assert_eq!(valid.len(), 1);
+        assert_eq!(valid["z"], ProviderCategory::Gateway);
+        assert_eq!(invalid, ["x = \"all\"", "y = \"router\""]);
+    }
+
     #[test]
     fn test_cycle_behavior() {
         let start = ProviderCategory::All;
@@ -4,6 +4,7 @@ use ratatui::widgets::ListState;
 
 use crate::agents::{detect_installed, AgentEntry, AgentsFile, FetchStatus, GitHubData};
 use crate::config::Config;
+use crate::formatting::SearchMatcher;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AgentSortOrder {
@@ -24,6 +25,19 @@ impl AgentSortOrder {
         }
     }
 
+    /// Parse `display.agents_sort` (case-insensitive).
+    pub fn from_config_key(key: &str) -> Option<Self> {
+        let key = key.trim().to_ascii_lowercase();
+        [
+            AgentSortOrder::Name,
+            AgentSortOrder::Updated,
+            AgentSortOrder::Stars,
+            AgentSortOrder::Status,
+        ]
+        .into_iter()
+        .find(|order| order.label() == key)
+    }
+
     pub fn label(&self) -> &'static str {
         match self {
             AgentSortOrder::Name => "name",
@@ -213,7 +227,8 @@ impl AgentsApp {
 
     pub fn update_filtered(&mut self) {
         let category = AgentCategory::variants()[self.selected_category];
-        let query_lower = self.search_query.to_lowercase();
+        let searching = !self.search_query.is_empty();
+        let matcher = SearchMatcher::new(&self.search_query);
 
         self.filtered_entries = self
             .entries
@@ -242,14 +257,14 @@ impl AgentsApp {
                     && (!self.filters.open_source_only || entry.agent.open_source);
 
                 // Search filter (includes changelog content)
-                let search_match = query_lower.is_empty()
-                    || entry.agent.name.to_lowercase().contains(&query_lower)
-                    || entry.id.to_lowercase().contains(&query_lower)
-                    || entry.github.releases.iter().any(|r| {
-                        r.changelog
-                            .as_ref()
-                            .is_some_and(|c| c.to_lowercase().contains(&query_lower))
-                    });
+                let search_match = !searching
+                    || matcher.is_match(&entry.agent.name)
+                    || matcher.is_match(&entry.id)
+                    || entry
+                        .github
+                        .releases
+                        .iter()
+                        .any(|r| r.changelog.as_ref().is_some_and(|c| matcher.is_match(c)));
 
                 category_match && filter_match && search_match
             })
@@ -265,11 +280,29 @@ impl AgentsApp {
         self.agent_list_state.select(Some(self.selected_agent));
     }
 
+    /// Clear search, filters, and category, restore `sort_order`, and select
+    /// the first agent.
+    pub fn reset_view(&mut self, sort_order: AgentSortOrder) {
+        self.filters = AgentFilters::default();
+        self.search_query.clear();
+        self.selected_category = 0;
+        self.sort_order = sort_order;
+        self.update_filtered();
+        self.selected_agent = 0;
+        self.agent_list_state.select(Some(0));
+        self.detail_scroll = 0;
+    }
+
     pub fn cycle_sort(&mut self) {
         self.sort_order = self.sort_order.next();
         self.apply_sort();
     }
 
+    pub fn set_sort_order(&mut self, order: AgentSortOrder) {
+        self.sort_order = order;
+        self.apply_sort();
+    }
+
     pub fn apply_sort(&mut self) {
         let entries = &self.entries;
         self.filtered_entries.sort_by(|&a, &b| {
@@ -318,6 +351,9 @@ impl AgentsApp {
                     status_a.cmp(&status_b)
                 }
             }
+            // Ties fall back to name, then id, so re-sorts never reorder rows
+            .then_with(|| ea.agent.name.cmp(&eb.agent.name))
+            .then_with(|| ea.id.cmp(&eb.id))
         });
     }
 
@@ -343,6 +379,14 @@ impl AgentsApp {
         }
     }
 
+    pub fn select_agent_at(&mut self, index: usize) {
+        if index < self.filtered_entries.len() && index != self.selected_agent {
+            self.selected_agent = index;
+            self.agent_list_state.select(Some(index));
+            self.detail_scroll = 0;
+        }
+    }
+
     pub fn select_first_agent(&mut self) {
         if self.selected_agent > 0 {
             self.selected_agent = 0;
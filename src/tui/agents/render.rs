@@ -9,9 +9,10 @@ use ratatui::{
 use crate::agents::{format_stars, FetchStatus};
 use crate::formatting::truncate;
 use crate::formatting::EM_DASH;
-use crate::tui::app::App;
+use crate::tui::app::{App, ListHitArea, ListRegion};
 use crate::tui::ui::{
-    caret, centered_rect_fixed, filter_toggle_spans, focus_border, selection_style,
+    caret, centered_rect_fixed, filter_toggle_spans, focus_border, highlight_matches,
+    selection_style,
 };
 use crate::tui::widgets::scroll_offset::ScrollOffset;
 use crate::tui::widgets::scrollable_panel::ScrollablePanel;
@@ -132,6 +133,11 @@ fn draw_agent_list(f: &mut Frame, area: Rect, app: &mut App) {
 
     // Agent rows (manual highlight to preserve status dot color)
     let selected = agents_app.agent_list_state.selected();
+    let search_terms: Vec<String> = if agents_app.search_query.is_empty() {
+        Vec::new()
+    } else {
+        vec![agents_app.search_query.clone()]
+    };
 
     for (row_idx, &idx) in agents_app.filtered_entries.iter().enumerate() {
         if let Some(entry) = agents_app.entries.get(idx) {
@@ -169,20 +175,22 @@ fn draw_agent_list(f: &mut Frame, area: Rect, app: &mut App) {
                 ("  ", Style::default())
             };
 
-            let row = Line::from(vec![
+            let mut spans = vec![
                 Span::styled(prefix, text_style),
                 Span::styled(status_indicator, status_style),
-                Span::styled(
-                    format!(
-                        " {:<width$}  {:>6}",
-                        truncate(&entry.agent.name, max_name_len),
-                        agent_type,
-                        width = max_name_len,
-                    ),
-                    text_style,
+                Span::styled(" ", text_style),
+            ];
+            spans.extend(highlight_matches(
+                &format!(
+                    "{:<width$}",
+                    truncate(&entry.agent.name, max_name_len),
+                    width = max_name_len,
                 ),
-            ]);
-            items.push(ListItem::new(row));
+                &search_terms,
+                text_style,
+            ));
+            spans.push(Span::styled(format!("  {:>6}", agent_type), text_style));
+            items.push(ListItem::new(Line::from(spans)));
         }
     }
 
@@ -194,6 +202,12 @@ fn draw_agent_list(f: &mut Frame, area: Rect, app: &mut App) {
         state.select(Some(selected + 1));
     }
     f.render_stateful_widget(list, chunks[1], &mut state);
+    app.list_areas.push(ListHitArea {
+        region: ListRegion::Agents,
+        area: chunks[1],
+        offset: state.offset(),
+        header_rows: 1,
+    });
 }
 
 fn draw_agent_detail(f: &mut Frame, area: Rect, app: &mut App) {
@@ -480,7 +494,8 @@ fn draw_agent_detail(f: &mut Frame, area: Rect, app: &mut App) {
         .unwrap_or(0);
 
     let scroll_offset = ScrollOffset::new(scroll_pos);
-    let panel = ScrollablePanel::new(detail_title, lines, &scroll_offset, is_focused);
+    let panel = ScrollablePanel::new(detail_title, lines, &scroll_offset, is_focused)
+        .with_flat(app.flat_details);
     let state = panel.render(f, area);
 
     // Compute visual offsets for match lines from the panel state
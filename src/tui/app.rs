@@ -1,24 +1,43 @@
-use super::agents::AgentsApp;
-use super::benchmarks::BenchmarksApp;
-use super::models::ModelsApp;
-use super::status::StatusApp;
+use chrono::{DateTime, Utc};
+use ratatui::layout::{Position, Rect};
+
+use super::agents::{AgentFocus, AgentSortOrder, AgentsApp};
+use super::benchmarks::{BenchmarkFocus, BenchmarkSortColumn, BenchmarksApp};
+use super::clipboard::CopyOutcome;
+use super::comparison::{ComparisonItem, MAX_COMPARISON};
+use super::models::{Focus, ModelsApp, PanelSplit, ProviderListItem, SortOrder};
+use super::palette::{CommandPalette, PaletteTarget};
+use super::status::{StatusApp, StatusFocus};
 
 /// Page size for page up/down navigation
 const PAGE_SIZE: usize = 10;
 
 pub const MAX_SELECTIONS: usize = 8;
+
+/// Columns moved per horizontal scroll step in the provider card
+const HSCROLL_STEP: u16 = 8;
+
+/// Status messages kept for the status log popup
+const STATUS_LOG_LIMIT: usize = 100;
 use crate::agents::{AgentsFile, FetchStatus, GitHubData};
-use std::collections::HashMap;
+use crate::api::DataSource;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::time::Instant;
 
 use crate::benchmarks::{BenchmarkEntry, BenchmarkStore};
-use crate::config::Config;
+use crate::config::{push_search_history, Config};
 use crate::data::{Provider, ProvidersMap};
+use crate::formatting::EM_DASH;
 use crate::tui::widgets::scroll_offset::ScrollOffset;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     Normal,
     Search,
+    /// Typing a Benchmarks minimum score
+    MinScore,
+    /// Typing in the command palette
+    Palette,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -40,6 +59,17 @@ impl Tab {
         }
     }
 
+    /// Parse `display.default_tab` (case-insensitive).
+    pub fn from_config_key(key: &str) -> Option<Self> {
+        match key.trim().to_ascii_lowercase().as_str() {
+            "models" => Some(Tab::Models),
+            "agents" => Some(Tab::Agents),
+            "benchmarks" => Some(Tab::Benchmarks),
+            "status" => Some(Tab::Status),
+            _ => None,
+        }
+    }
+
     pub fn prev(self) -> Self {
         match self {
             Tab::Models => Tab::Status,
@@ -59,6 +89,14 @@ pub enum Message {
     PrevModel,
     SelectFirstProvider,
     SelectLastProvider,
+    JumpToProviderLetter(char),
+    ClickListRow(ListRegion, usize), // Mouse click on a list row (data index)
+    ToggleDetailWrap,                // Toggle provider card wrapping vs horizontal scroll
+    ToggleFlatDetails,               // Draw the detail panels without borders
+    ScrollDetailLeft,                // Scroll unwrapped provider card left
+    ScrollDetailRight,               // Scroll unwrapped provider card right
+    ResizeProviderPanel(i16),        // Widen/narrow the provider column (percent)
+    ResizeDetailPanel(i16),          // Widen/narrow the detail column (percent)
     SelectFirstModel,
     SelectLastModel,
     PageDownProvider,
@@ -68,22 +106,52 @@ pub enum Message {
     EnterSearch,
     ExitSearch,
     SearchInput(char),
+    SearchHistoryPrev, // Recall an older query in search mode
+    SearchHistoryNext, // Recall a newer query (or the draft) in search mode
     SearchBackspace,
     ClearSearch,
-    CopyFull,          // Copy provider/model-id
-    CopyModelId,       // Copy just model-id
-    CopyProviderDoc,   // Copy provider documentation URL
-    CopyProviderApi,   // Copy provider API URL
-    OpenProviderDoc,   // Open provider documentation URL in browser
-    CycleSort,         // Cycle through sort options
-    ToggleSortDir,     // Toggle sort direction (ascending/descending)
-    ToggleReasoning,   // Toggle reasoning filter
-    ToggleTools,       // Toggle tools filter
-    ToggleOpenWeights, // Toggle open weights filter
-    ToggleFree,        // Toggle free models filter
-    ToggleHelp,        // Toggle help popup
-    ScrollHelpUp,      // Scroll help popup up
-    ScrollHelpDown,    // Scroll help popup down
+    CopyFull,             // Copy display.copy_template (default provider/model-id)
+    CopyModelId,          // Copy just model-id
+    CopyProviderDoc,      // Copy provider documentation URL
+    CopyProviderApi,      // Copy provider API URL
+    OpenProviderDoc,      // Open provider documentation URL in browser
+    OpenProviderHomepage, // Open provider homepage (or models.dev) in browser
+    ToggleModelSelection, // Toggle checkmark on the selected model row
+    ToggleFavorite,       // Star/unstar the selected model
+    CopySelectedModels,   // Copy all checked provider/model ids
+    CopyModelConfig,      // Copy OpenAI-compatible config snippet
+    CycleSort,            // Cycle through sort options
+    ToggleSortDir,        // Toggle sort direction (ascending/descending)
+    ToggleReasoning,      // Toggle reasoning filter
+    ToggleTools,          // Toggle tools filter
+    ToggleOpenWeights,    // Toggle open weights filter
+    ToggleFree,           // Toggle free models filter
+    CycleReleaseWindow,   // Cycle released-after window (30d/90d/1y/off)
+    CycleKnowledgeWindow, // Cycle minimum knowledge cutoff (6mo/1y/2y/off)
+    ToggleDedupe,         // Collapse cross-provider duplicates in the All list
+    ToggleDeprecated,     // Show/hide models with status "deprecated"
+    ToggleHelp,           // Toggle help popup
+    EnterMinScore,        // Prompt for a Benchmarks minimum score
+    MinScoreInput(char),
+    MinScoreBackspace,
+    ApplyMinScore,
+    CancelMinScore,
+    ScrollHelpUp,   // Scroll help popup up
+    ScrollHelpDown, // Scroll help popup down
+    HelpFilterInput(char),
+    HelpFilterBackspace,
+    ClearHelpFilter,
+    ToggleStatusLog,
+    ResetView, // Reset the current tab's search, filters, sort, and selection
+    ScrollStatusLogUp,
+    ScrollStatusLogDown,
+    /// Add the selected model or benchmark entry to the comparison buffer,
+    /// or remove it if it's already there
+    AddToComparison,
+    ToggleComparison,
+    ClearComparison,
+    ScrollComparisonUp,
+    ScrollComparisonDown,
     NextTab,
     PrevTab,
     // Agents tab messages
@@ -132,6 +200,8 @@ pub enum Message {
     // Provider categories
     CycleProviderCategory,
     ToggleGrouping,
+    ToggleOutputGrouping, // List output groups (text, image, ...) instead of providers
+    CycleProviderSort,    // Providers by name or by model count
     // Benchmarks tab messages
     NextBenchmark,
     PrevBenchmark,
@@ -169,7 +239,20 @@ pub enum Message {
     QuickSortSpeed,
     #[allow(dead_code)]
     CopyBenchmarkName,
+    CopyBenchmarkMarkdown,
+    CopyBenchmarkCsv,
     OpenBenchmarkUrl,
+    /// Jump to the models.dev model matching the selected benchmark entry
+    OpenBenchmarkModel,
+    /// Jump to the benchmark entry matching the selected model
+    OpenModelBenchmarks,
+    OpenPalette,
+    PaletteInput(char),
+    PaletteBackspace,
+    PaletteNext,
+    PalettePrev,
+    PaletteConfirm,
+    ClosePalette,
     ToggleBenchmarkSelection,
     ClearBenchmarkSelections,
     ToggleDetailOverlay,
@@ -206,19 +289,143 @@ pub enum Message {
     // Async data messages
     GitHubDataReceived(String, GitHubData),
     GitHubFetchFailed(String, String), // (agent_id, error_message)
+    // Provider data messages
+    ProvidersReceived(ProvidersMap, DataSource),
+    CopyFinished(String, String, CopyOutcome), // (text, status on success, outcome)
     // Benchmark data messages
-    BenchmarkDataReceived(Vec<BenchmarkEntry>),
+    BenchmarkDataReceived(Vec<BenchmarkEntry>, DateTime<Utc>),
     BenchmarkFetchFailed,
+    BenchmarkFetchCertificateRejected,
+    BenchmarkFetchTimedOut,
+    BenchmarkChanges(String), // Summary of changes since the previous snapshot
+    RefreshBenchmarks,        // Re-fetch benchmark data from the CDN
     // Provider status data messages
     StatusDataReceived(Vec<crate::status::ProviderStatus>),
 }
 
+/// Lists that respond to mouse clicks and wheel scrolling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListRegion {
+    ModelProviders,
+    Models,
+    Agents,
+    BenchmarkCreators,
+    Benchmarks,
+    StatusProviders,
+}
+
+impl ListRegion {
+    /// The keyboard message equivalent to one wheel notch over this list.
+    pub fn step_message(self, down: bool) -> Message {
+        match (self, down) {
+            (Self::ModelProviders, true) => Message::NextProvider,
+            (Self::ModelProviders, false) => Message::PrevProvider,
+            (Self::Models, true) => Message::NextModel,
+            (Self::Models, false) => Message::PrevModel,
+            (Self::Agents, true) => Message::NextAgent,
+            (Self::Agents, false) => Message::PrevAgent,
+            (Self::BenchmarkCreators, true) => Message::NextBenchmarkCreator,
+            (Self::BenchmarkCreators, false) => Message::PrevBenchmarkCreator,
+            (Self::Benchmarks, true) => Message::NextBenchmark,
+            (Self::Benchmarks, false) => Message::PrevBenchmark,
+            (Self::StatusProviders, true) => Message::NextStatusProvider,
+            (Self::StatusProviders, false) => Message::PrevStatusProvider,
+        }
+    }
+}
+
+/// Where a list was last drawn, recorded at render time so mouse events can
+/// be mapped back to list rows.
+#[derive(Debug, Clone, Copy)]
+pub struct ListHitArea {
+    pub region: ListRegion,
+    /// Area the list items occupy (inside any border)
+    pub area: Rect,
+    /// Scroll offset of the rendered `ListState`
+    pub offset: usize,
+    /// Non-selectable column header rows at the top of the list
+    pub header_rows: usize,
+}
+
+impl ListHitArea {
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        self.area.contains(Position::new(column, row))
+    }
+
+    /// Data index under the cursor, or `None` outside the list or on a header row.
+    pub fn row_at(&self, column: u16, row: u16) -> Option<usize> {
+        if !self.contains(column, row) {
+            return None;
+        }
+        let visual = self.offset + (row - self.area.y) as usize;
+        visual.checked_sub(self.header_rows)
+    }
+}
+
+/// Digits typed ahead of a motion key (vim-style count), see `event.rs`.
+#[derive(Debug, Clone)]
+pub struct CountPrefix {
+    pub digits: String,
+    pub started: Instant,
+}
+
+impl Default for CountPrefix {
+    fn default() -> Self {
+        Self {
+            digits: String::new(),
+            started: Instant::now(),
+        }
+    }
+}
+
+/// Position while browsing search history with Up/Down in search mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryCursor {
+    /// Index into the current tab's history (0 = oldest)
+    pub index: usize,
+    /// Query that was being typed before browsing started, restored past the newest entry
+    pub draft: String,
+}
+
 pub struct App {
     pub providers: Vec<(String, Provider)>,
+    /// Whether `providers` came from a live fetch or the on-disk cache
+    pub data_source: DataSource,
+    /// Set while the initial provider fetch is in flight (drives the loading screen)
+    pub providers_loading: Option<Instant>,
+    /// Wrap long lines in the provider card; when false they scroll horizontally
+    pub detail_wrap: bool,
+    /// Horizontal scroll of the unwrapped provider card (clamped at render time)
+    pub detail_hscroll: ScrollOffset,
+    /// Draw the model, provider, and agent detail panels without borders,
+    /// for copy-paste and narrow terminals
+    pub flat_details: bool,
+    /// List areas from the last frame, for mouse hit-testing
+    pub list_areas: Vec<ListHitArea>,
+    /// Pending vim-style count prefix in normal mode
+    pub count_prefix: Option<CountPrefix>,
+    /// Set after `g` in the provider list, awaiting a letter to jump to
+    pub pending_g: Option<Instant>,
+    /// Last letter used by `g<letter>`; repeating it cycles through matches
+    pub last_jump_letter: Option<char>,
     pub mode: Mode,
+    /// Set while recalling history in search mode; reset on entering search or typing
+    pub search_history_cursor: Option<HistoryCursor>,
+    /// Search history, recent or starred models, or the panel split changed
+    /// since load; saved to config on quit
+    pub config_dirty: bool,
+    /// Models tab column widths, from `display.*_panel_pct`
+    pub panel_split: PanelSplit,
     pub status_message: Option<String>,
+    /// Recent status messages as (HH:MM:SS, text), oldest first, capped at
+    /// `STATUS_LOG_LIMIT`
+    pub status_log: Vec<(String, String)>,
+    pub show_status_log: bool,
+    pub status_log_scroll: ScrollOffset,
     pub show_help: bool,
     pub help_scroll: ScrollOffset,
+    /// Typed into the open help popup; only lines containing it are shown
+    pub help_filter: String,
     pub current_tab: Tab,
     pub models_app: ModelsApp,
     pub agents_app: Option<AgentsApp>,
@@ -233,8 +440,76 @@ pub struct App {
     pub last_detail_height: u16,
     /// Store indices of selected models for comparison (shared between tabs)
     pub selections: Vec<usize>,
+    /// Checked rows in the Models tab, keyed by `provider/model`
+    pub model_selections: HashSet<String>,
     pub pending_status_refresh: bool,
     pub force_status_refresh: bool,
+    /// Set by `RefreshBenchmarks`; the main loop spawns the CDN fetch
+    pub pending_benchmark_refresh: bool,
+    /// `provider/model` key to select in the Models tab once providers are
+    /// loaded (`OpenBenchmarkModel`, the command palette)
+    pub pending_model_jump: Option<String>,
+    /// Open command palette (`Mode::Palette`)
+    pub palette: Option<CommandPalette>,
+    /// Models and benchmark entries added with `+`, in order, for the
+    /// cross-tab comparison overlay (`V`)
+    pub comparison: Vec<ComparisonItem>,
+    pub show_comparison: bool,
+    pub comparison_scroll: ScrollOffset,
+}
+
+/// `scheme://host` of an http(s) URL, optionally dropping an `api.` subdomain.
+fn url_origin(url: &str, strip_api: bool) -> Option<String> {
+    let parsed = reqwest::Url::parse(url).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = parsed.host_str()?;
+    let host = if strip_api {
+        host.strip_prefix("api.").unwrap_or(host)
+    } else {
+        host
+    };
+    Some(format!("{}://{}", parsed.scheme(), host))
+}
+
+/// Quote a CSV field when it holds a comma, quote, or newline (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// A fresh `ModelsApp` with the `[display]` settings and saved models applied,
+/// used at startup and again when fetched providers replace the placeholder.
+fn configured_models_app(
+    providers: &[(String, Provider)],
+    config: &Config,
+    recent: Vec<String>,
+    favorites: BTreeSet<String>,
+) -> ModelsApp {
+    let display = &config.display;
+    let mut models_app = ModelsApp::new(providers);
+    if let Some(order) = display
+        .models_sort
+        .as_deref()
+        .and_then(SortOrder::from_config_key)
+    {
+        models_app.set_sort_order(order, providers);
+    }
+    if !recent.is_empty() || !favorites.is_empty() {
+        models_app.set_saved_models(recent, favorites, providers);
+    }
+    if display.show_deprecated {
+        models_app.toggle_deprecated(providers);
+    }
+    // An unknown provider id leaves "All" selected
+    if let Some(id) = display.default_provider.as_deref() {
+        models_app.select_provider_id(id, providers);
+    }
+    models_app
 }
 
 impl App {
@@ -248,21 +523,72 @@ impl App {
         providers.sort_by(|a, b| a.0.cmp(&b.0));
 
         let config = config.unwrap_or_default();
-        let agents_app = agents_file.map(|af| AgentsApp::new(af, &config));
+        let display = &config.display;
+        let mut agents_app = agents_file.map(|af| AgentsApp::new(af, &config));
+        if let (Some(app), Some(order)) = (
+            agents_app.as_mut(),
+            display
+                .agents_sort
+                .as_deref()
+                .and_then(AgentSortOrder::from_config_key),
+        ) {
+            app.set_sort_order(order);
+        }
         let status_app = Some(StatusApp::new(&config));
         let open_weights_map =
             crate::benchmarks::build_open_weights_map(&providers, benchmark_store.entries());
         crate::benchmarks::apply_model_traits(&providers, benchmark_store.entries_mut());
-        let benchmarks_app = BenchmarksApp::new(&benchmark_store, &open_weights_map);
-        let models_app = ModelsApp::new(&providers);
+        let mut benchmarks_app = BenchmarksApp::new(&benchmark_store, &open_weights_map);
+        if let Some(col) = display
+            .benchmarks_sort
+            .as_deref()
+            .and_then(BenchmarkSortColumn::from_config_key)
+        {
+            benchmarks_app.set_sort_column(col, &benchmark_store, &open_weights_map);
+        }
+        let models_app = configured_models_app(
+            &providers,
+            &config,
+            config.recent_models.clone(),
+            config.favorite_models.clone(),
+        );
+        // Unknown names fall back to the built-in defaults
+        let current_tab = display
+            .default_tab
+            .as_deref()
+            .and_then(Tab::from_config_key)
+            .unwrap_or_default();
+        let default_split = PanelSplit::default();
+        let panel_split = PanelSplit::new(
+            display
+                .provider_panel_pct
+                .unwrap_or(default_split.providers),
+            display.detail_panel_pct.unwrap_or(default_split.details),
+        );
 
         Self {
             providers,
+            data_source: DataSource::default(),
+            providers_loading: None,
+            detail_wrap: true,
+            detail_hscroll: ScrollOffset::default(),
+            flat_details: display.flat_details,
+            list_areas: Vec::new(),
+            count_prefix: None,
+            pending_g: None,
+            last_jump_letter: None,
             mode: Mode::Normal,
+            search_history_cursor: None,
+            config_dirty: false,
+            panel_split,
             status_message: None,
+            status_log: Vec::new(),
+            show_status_log: false,
+            status_log_scroll: ScrollOffset::default(),
             show_help: false,
             help_scroll: ScrollOffset::default(),
-            current_tab: Tab::default(),
+            help_filter: String::new(),
+            current_tab,
             models_app,
             agents_app,
             config,
@@ -273,8 +599,15 @@ impl App {
             open_weights_map,
             last_detail_height: 0,
             selections: Vec::new(),
+            model_selections: HashSet::new(),
             pending_status_refresh: false,
             force_status_refresh: false,
+            pending_benchmark_refresh: false,
+            pending_model_jump: None,
+            palette: None,
+            comparison: Vec::new(),
+            show_comparison: false,
+            comparison_scroll: ScrollOffset::default(),
         }
     }
 
@@ -290,8 +623,99 @@ impl App {
         self.selections.clear();
     }
 
+    /// Select the clicked row and move focus to its list.
+    pub fn select_list_row(&mut self, region: ListRegion, index: usize) {
+        match region {
+            ListRegion::ModelProviders => {
+                self.models_app.focus = Focus::Providers;
+                if index < self.models_app.provider_list_len() {
+                    let index = self.models_app.find_selectable_index(index, true);
+                    if index != self.models_app.selected_provider {
+                        self.models_app
+                            .select_provider_at_index(index, &self.providers);
+                    }
+                }
+            }
+            ListRegion::Models => {
+                self.models_app.focus = Focus::Models;
+                self.models_app.select_model_at(index);
+            }
+            ListRegion::Agents => {
+                if let Some(ref mut agents_app) = self.agents_app {
+                    agents_app.focus = AgentFocus::List;
+                    agents_app.select_agent_at(index);
+                }
+            }
+            ListRegion::BenchmarkCreators => {
+                self.benchmarks_app.focus = BenchmarkFocus::Creators;
+                self.benchmarks_app.select_creator_at(index);
+                self.benchmarks_app
+                    .update_filtered(&self.benchmark_store, &self.open_weights_map);
+            }
+            ListRegion::Benchmarks => {
+                self.benchmarks_app.focus = BenchmarkFocus::List;
+                self.benchmarks_app.select_at(index);
+            }
+            ListRegion::StatusProviders => {
+                if let Some(ref mut status_app) = self.status_app {
+                    status_app.focus = StatusFocus::List;
+                    status_app.select_at(index);
+                }
+            }
+        }
+    }
+
+    /// Jump to the next provider whose id starts with `c`. Repeating the same
+    /// letter cycles through matches; a new letter starts from the top.
+    pub fn jump_provider_to_letter(&mut self, c: char) {
+        let c = c.to_ascii_lowercase();
+        let items = &self.models_app.provider_list_items;
+        let len = items.len();
+        if len == 0 {
+            return;
+        }
+        let start = if self.last_jump_letter == Some(c) {
+            self.models_app.selected_provider + 1
+        } else {
+            0
+        };
+        self.last_jump_letter = Some(c);
+
+        let target = (0..len).map(|i| (start + i) % len).find(|&i| {
+            matches!(items[i], ProviderListItem::Provider(idx, _)
+                if self.providers.get(idx).is_some_and(|(id, _)| id.to_ascii_lowercase().starts_with(c)))
+        });
+        if let Some(target) = target {
+            let target = self.models_app.find_selectable_index(target, true);
+            if target != self.models_app.selected_provider {
+                self.models_app
+                    .select_provider_at_index(target, &self.providers);
+            }
+        }
+    }
+
+    pub fn toggle_model_selection(&mut self) {
+        if let Some(key) = self.models_app.get_copy_full() {
+            if !self.model_selections.remove(&key) {
+                self.model_selections.insert(key);
+            }
+        }
+    }
+
+    /// Newline-joined `provider/model` ids of all checked rows, sorted for stable output.
+    pub fn get_copy_selected(&self) -> Option<String> {
+        if self.model_selections.is_empty() {
+            return None;
+        }
+        let mut keys: Vec<&str> = self.model_selections.iter().map(String::as_str).collect();
+        keys.sort_unstable();
+        Some(keys.join("\n"))
+    }
+
+    /// Selected model's copy string, per `display.copy_template`.
     pub fn get_copy_full(&self) -> Option<String> {
-        self.models_app.get_copy_full()
+        self.models_app
+            .get_copy_templated(&self.config.display.copy_template)
     }
 
     pub fn get_copy_model_id(&self) -> Option<String> {
@@ -306,7 +730,356 @@ impl App {
         self.models_app.get_provider_api(&self.providers)
     }
 
+    /// The selected model's provider homepage, derived from its doc URL (or
+    /// API URL minus an `api.` host prefix). Falls back to a models.dev search
+    /// for the model when the provider publishes neither.
+    pub fn get_provider_homepage(&self) -> Option<String> {
+        let entry = self.models_app.current_model()?;
+        let provider = self
+            .providers
+            .iter()
+            .find(|(id, _)| id == &entry.provider_id)
+            .map(|(_, p)| p);
+        let homepage = provider.and_then(|p| {
+            p.doc
+                .as_deref()
+                .and_then(|doc| url_origin(doc, false))
+                .or_else(|| p.api.as_deref().and_then(|api| url_origin(api, true)))
+        });
+        homepage.or_else(|| {
+            reqwest::Url::parse_with_params("https://models.dev/", [("search", &entry.id)])
+                .ok()
+                .map(String::from)
+        })
+    }
+
+    /// JSON snippet describing the selected model as an OpenAI-compatible
+    /// `{provider, model, base_url, env}` config. `base_url` is null when the
+    /// provider does not publish an API URL.
+    pub fn get_copy_config(&self) -> Option<String> {
+        let entry = self.models_app.current_model()?;
+        let provider = self
+            .providers
+            .iter()
+            .find(|(id, _)| id == &entry.provider_id)
+            .map(|(_, p)| p);
+        let snippet = serde_json::json!({
+            "provider": entry.provider_id,
+            "model": entry.id,
+            "base_url": provider.and_then(|p| p.api.clone()),
+            "env": provider.map(|p| p.env.clone()).unwrap_or_default(),
+        });
+        serde_json::to_string_pretty(&snippet).ok()
+    }
+
+    /// The selected benchmark entry's scores as a markdown `Metric | Value`
+    /// table, with `—` for missing values.
+    pub fn get_benchmark_markdown(&self) -> Option<String> {
+        let entry = self.benchmarks_app.current_entry(&self.benchmark_store)?;
+        let fmt = |value: Option<f64>, render: fn(f64) -> String| {
+            value.map(render).unwrap_or_else(|| EM_DASH.to_string())
+        };
+        let index = |v: f64| format!("{v:.1}");
+        let pct = |v: f64| format!("{:.1}%", v * 100.0);
+        let rows = [
+            ("Intelligence", fmt(entry.intelligence_index, index)),
+            ("Coding", fmt(entry.coding_index, index)),
+            ("Math", fmt(entry.math_index, index)),
+            ("GPQA", fmt(entry.gpqa, pct)),
+            ("MMLU-Pro", fmt(entry.mmlu_pro, pct)),
+            ("HLE", fmt(entry.hle, pct)),
+            ("LiveCodeBench", fmt(entry.livecodebench, pct)),
+            ("SciCode", fmt(entry.scicode, pct)),
+            ("IFBench", fmt(entry.ifbench, pct)),
+            ("Terminal-Bench Hard", fmt(entry.terminalbench_hard, pct)),
+            ("Tau2", fmt(entry.tau2, pct)),
+            ("LCR", fmt(entry.lcr, pct)),
+            ("MATH-500", fmt(entry.math_500, pct)),
+            ("AIME", fmt(entry.aime, pct)),
+            ("AIME'25", fmt(entry.aime_25, pct)),
+            ("Speed", fmt(entry.output_tps, |v| format!("{v:.0} tok/s"))),
+            ("TTFT", fmt(entry.ttft, |v| format!("{v:.2}s"))),
+            ("Input $/M", fmt(entry.price_input, |v| format!("${v:.2}"))),
+            (
+                "Output $/M",
+                fmt(entry.price_output, |v| format!("${v:.2}")),
+            ),
+        ];
+
+        let mut markdown = format!("### {}\n\n| Metric | Value |\n|---|---|\n", entry.name);
+        for (metric, value) in rows {
+            markdown.push_str(&format!("| {metric} | {value} |\n"));
+        }
+        Some(markdown)
+    }
+
+    /// The filtered benchmark list, in its current order, as CSV: a header row
+    /// and one row per entry for the columns the list shows.
+    pub fn get_benchmarks_csv(&self) -> Option<String> {
+        use super::benchmarks::render::benchmark_col_text;
+
+        let bench = &self.benchmarks_app;
+        if bench.filtered_indices.is_empty() {
+            return None;
+        }
+        let entries = self.benchmark_store.entries();
+        let cols = bench.sort_column.visible_columns();
+        let row = |entry: Option<&BenchmarkEntry>| {
+            cols.iter()
+                .map(|&col| csv_field(&benchmark_col_text(entry, col)))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let mut csv = row(None);
+        csv.push('\n');
+        for &i in &bench.filtered_indices {
+            csv.push_str(&row(Some(&entries[i])));
+            csv.push('\n');
+        }
+        Some(csv)
+    }
+
+    /// Add the Models or Benchmarks tab selection to the comparison buffer,
+    /// or take it out if it (or its cross-tab match) is already in.
+    fn toggle_comparison_item(&mut self) {
+        let item = match self.current_tab {
+            Tab::Models => self.models_app.current_model().map(|model| {
+                ComparisonItem::from_model(
+                    self.benchmark_store.entries(),
+                    &model.provider_id,
+                    &model.id,
+                )
+            }),
+            Tab::Benchmarks => self
+                .benchmarks_app
+                .current_entry(&self.benchmark_store)
+                .map(|entry| ComparisonItem::from_entry(&self.providers, entry)),
+            Tab::Agents | Tab::Status => None,
+        };
+        let Some(item) = item else {
+            return;
+        };
+        let label = item.label(self.benchmark_store.entries());
+        if let Some(pos) = self.comparison.iter().position(|c| c.same_as(&item)) {
+            self.comparison.remove(pos);
+            self.set_status(format!("Removed {label} from comparison"));
+        } else if self.comparison.len() >= MAX_COMPARISON {
+            self.set_status(format!(
+                "Comparison holds {MAX_COMPARISON} items; remove one or clear with c in V"
+            ));
+        } else {
+            self.comparison.push(item);
+            self.set_status(format!(
+                "Added {label} to comparison ({}/{MAX_COMPARISON}, V to view)",
+                self.comparison.len()
+            ));
+        }
+    }
+
+    /// Select `pending_model_jump` in the Models tab: the "All" list searched
+    /// for the model id, with the cursor on that provider's listing (or any
+    /// listing, when dedupe collapsed it). Waits while the initial provider
+    /// fetch is in flight.
+    fn apply_pending_model_jump(&mut self) {
+        if self.providers_loading.is_some() {
+            return;
+        }
+        let Some(key) = self.pending_model_jump.take() else {
+            return;
+        };
+        let Some((provider_id, model_id)) = key.split_once('/') else {
+            return;
+        };
+        let models_app = &mut self.models_app;
+        models_app.set_search(model_id.to_string(), &self.providers);
+        models_app.select_first_provider(&self.providers);
+        let models = models_app.filtered_models();
+        let index = models
+            .iter()
+            .position(|entry| entry.provider_id == provider_id && entry.id == model_id)
+            .or_else(|| models.iter().position(|entry| entry.id == model_id));
+        if let Some(index) = index {
+            models_app.select_model_at(index);
+        }
+        models_app.focus = Focus::Models;
+    }
+
+    /// Show a provider in the Models tab's provider list, clearing the search
+    /// so it is visible.
+    fn jump_to_provider(&mut self, provider_id: &str) {
+        self.models_app.clear_search(&self.providers);
+        self.models_app
+            .select_provider_id(provider_id, &self.providers);
+        self.models_app.focus = Focus::Providers;
+    }
+
+    fn search_history(&self) -> &[String] {
+        let history = &self.config.search_history;
+        match self.current_tab {
+            Tab::Models => &history.models,
+            Tab::Agents => &history.agents,
+            Tab::Benchmarks => &history.benchmarks,
+            Tab::Status => &history.status,
+        }
+    }
+
+    fn search_history_mut(&mut self) -> &mut Vec<String> {
+        let history = &mut self.config.search_history;
+        match self.current_tab {
+            Tab::Models => &mut history.models,
+            Tab::Agents => &mut history.agents,
+            Tab::Benchmarks => &mut history.benchmarks,
+            Tab::Status => &mut history.status,
+        }
+    }
+
+    fn current_search_query(&self) -> String {
+        match self.current_tab {
+            Tab::Models => self.models_app.search_query.clone(),
+            Tab::Agents => self
+                .agents_app
+                .as_ref()
+                .map(|a| a.search_query.clone())
+                .unwrap_or_default(),
+            Tab::Benchmarks => self.benchmarks_app.search_query.clone(),
+            Tab::Status => self
+                .status_app
+                .as_ref()
+                .map(|s| s.search_query.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Replace the current tab's search query and refilter its list.
+    fn set_search_query(&mut self, query: String) {
+        match self.current_tab {
+            Tab::Models => {
+                self.models_app.set_search(query, &self.providers);
+            }
+            Tab::Agents => {
+                if let Some(ref mut agents_app) = self.agents_app {
+                    agents_app.search_query = query;
+                    agents_app.selected_agent = 0;
+                    agents_app.update_filtered();
+                }
+            }
+            Tab::Benchmarks => {
+                self.benchmarks_app.search_query = query;
+                self.benchmarks_app
+                    .rebuild_after_filter_change(&self.benchmark_store, &self.open_weights_map);
+            }
+            Tab::Status => {
+                if let Some(ref mut status_app) = self.status_app {
+                    status_app.search_query = query;
+                    status_app.selected = 0;
+                    status_app.update_filtered();
+                }
+            }
+        }
+    }
+
+    /// Step back to an older query, stashing the in-progress query as the draft.
+    fn search_history_prev(&mut self) {
+        let len = self.search_history().len();
+        if len == 0 {
+            return;
+        }
+        let cursor = match self.search_history_cursor.take() {
+            Some(c) => HistoryCursor {
+                index: c.index.saturating_sub(1),
+                draft: c.draft,
+            },
+            None => HistoryCursor {
+                index: len - 1,
+                draft: self.current_search_query(),
+            },
+        };
+        let query = self.search_history()[cursor.index].clone();
+        self.search_history_cursor = Some(cursor);
+        self.set_search_query(query);
+    }
+
+    /// Step forward to a newer query; past the newest entry the draft is restored.
+    fn search_history_next(&mut self) {
+        let Some(cursor) = self.search_history_cursor.take() else {
+            return;
+        };
+        let next = cursor.index + 1;
+        if next < self.search_history().len() {
+            let query = self.search_history()[next].clone();
+            self.search_history_cursor = Some(HistoryCursor {
+                index: next,
+                draft: cursor.draft,
+            });
+            self.set_search_query(query);
+        } else {
+            self.set_search_query(cursor.draft);
+        }
+    }
+
+    /// 1-based position and length of the focused list on the Models, Agents,
+    /// or Benchmarks tab, for the footer's "row X/Y". `None` for an empty list.
+    pub fn list_position(&self) -> Option<(usize, usize)> {
+        let (selected, len) = match self.current_tab {
+            Tab::Models => match self.models_app.focus {
+                Focus::Providers => (
+                    self.models_app.selected_provider,
+                    self.models_app.provider_list_items.len(),
+                ),
+                Focus::Models | Focus::Details => (
+                    self.models_app.selected_model,
+                    self.models_app.filtered_models().len(),
+                ),
+            },
+            Tab::Agents => {
+                let agents = self.agents_app.as_ref()?;
+                (agents.selected_agent, agents.filtered_entries.len())
+            }
+            Tab::Benchmarks => {
+                let bench = &self.benchmarks_app;
+                match bench.focus {
+                    BenchmarkFocus::Creators => {
+                        (bench.selected_creator, bench.creator_list_items.len())
+                    }
+                    _ => (bench.selected, bench.filtered_indices.len()),
+                }
+            }
+            Tab::Status => return None,
+        };
+        (len > 0).then(|| (selected.min(len - 1) + 1, len))
+    }
+
+    fn set_panel_split(&mut self, split: PanelSplit) {
+        if split != self.panel_split {
+            self.panel_split = split;
+            self.config.display.provider_panel_pct = Some(split.providers);
+            self.config.display.detail_panel_pct = Some(split.details);
+            self.config_dirty = true;
+        }
+    }
+
+    /// Persist search history, recent/starred models, and the panel split if
+    /// they changed this session. Best-effort: a failed write shouldn't block
+    /// quitting. `Config::save` refuses when config.toml failed to load, so a
+    /// parse error never ends with the user's file replaced by defaults.
+    pub fn save_session_config(&mut self) {
+        if self.config_dirty {
+            self.config
+                .recent_models
+                .clone_from(&self.models_app.recent_models);
+            self.config
+                .favorite_models
+                .clone_from(&self.models_app.favorite_models);
+            let _ = self.config.save();
+            self.config_dirty = false;
+        }
+    }
+
     pub fn update(&mut self, msg: Message) -> bool {
+        // Editing a recalled query makes it the new draft
+        if matches!(msg, Message::SearchInput(_) | Message::SearchBackspace) {
+            self.search_history_cursor = None;
+        }
         match msg {
             Message::Quit => return false,
             Message::NextProvider => {
@@ -321,6 +1094,33 @@ impl App {
             Message::PrevModel => {
                 self.models_app.prev_model();
             }
+            Message::ToggleDetailWrap => {
+                self.detail_wrap = !self.detail_wrap;
+                self.detail_hscroll.jump_top();
+            }
+            Message::ToggleFlatDetails => {
+                self.flat_details = !self.flat_details;
+            }
+            Message::ScrollDetailLeft => {
+                self.detail_hscroll.decrement(HSCROLL_STEP);
+            }
+            Message::ScrollDetailRight => {
+                if !self.detail_wrap {
+                    self.detail_hscroll.increment(HSCROLL_STEP);
+                }
+            }
+            Message::ResizeProviderPanel(delta) => {
+                self.set_panel_split(self.panel_split.resize_providers(delta));
+            }
+            Message::ResizeDetailPanel(delta) => {
+                self.set_panel_split(self.panel_split.resize_details(delta));
+            }
+            Message::ClickListRow(region, index) => {
+                self.select_list_row(region, index);
+            }
+            Message::JumpToProviderLetter(c) => {
+                self.jump_provider_to_letter(c);
+            }
             Message::SelectFirstProvider => {
                 self.models_app.select_first_provider(&self.providers);
             }
@@ -371,9 +1171,96 @@ impl App {
             }
             Message::EnterSearch => {
                 self.mode = Mode::Search;
+                self.search_history_cursor = None;
+            }
+            Message::EnterMinScore => {
+                self.mode = Mode::MinScore;
+                self.benchmarks_app.min_score_input = self
+                    .benchmarks_app
+                    .min_score
+                    .map(|min| min.to_string())
+                    .unwrap_or_default();
+            }
+            Message::MinScoreInput(c) => {
+                if c.is_ascii_digit() || c == '.' || c == '-' {
+                    self.benchmarks_app.min_score_input.push(c);
+                }
+            }
+            Message::MinScoreBackspace => {
+                self.benchmarks_app.min_score_input.pop();
+            }
+            Message::ApplyMinScore => {
+                self.mode = Mode::Normal;
+                let input = self.benchmarks_app.min_score_input.clone();
+                if !self
+                    .benchmarks_app
+                    .apply_min_score(&self.benchmark_store, &self.open_weights_map)
+                {
+                    self.set_status(format!("Invalid minimum score: {}", input));
+                }
+            }
+            Message::OpenPalette => {
+                self.palette = Some(CommandPalette::new(&self.providers));
+                self.mode = Mode::Palette;
+            }
+            Message::PaletteInput(c) => {
+                if let Some(palette) = self.palette.as_mut() {
+                    palette.input(c);
+                }
+            }
+            Message::PaletteBackspace => {
+                if let Some(palette) = self.palette.as_mut() {
+                    palette.backspace();
+                }
+            }
+            Message::PaletteNext => {
+                if let Some(palette) = self.palette.as_mut() {
+                    palette.next();
+                }
+            }
+            Message::PalettePrev => {
+                if let Some(palette) = self.palette.as_mut() {
+                    palette.prev();
+                }
+            }
+            Message::PaletteConfirm => {
+                self.mode = Mode::Normal;
+                let target = self
+                    .palette
+                    .take()
+                    .and_then(|p| p.selected_target().cloned());
+                if let Some(target) = target {
+                    self.current_tab = Tab::Models;
+                    match target {
+                        PaletteTarget::Provider(id) => self.jump_to_provider(&id),
+                        PaletteTarget::Model { .. } => {
+                            self.pending_model_jump = Some(target.label());
+                            self.apply_pending_model_jump();
+                        }
+                    }
+                }
+            }
+            Message::ClosePalette => {
+                self.mode = Mode::Normal;
+                self.palette = None;
+            }
+            Message::CancelMinScore => {
+                self.mode = Mode::Normal;
+                self.benchmarks_app.min_score_input.clear();
             }
             Message::ExitSearch => {
                 self.mode = Mode::Normal;
+                self.search_history_cursor = None;
+                let query = self.current_search_query();
+                if push_search_history(self.search_history_mut(), &query) {
+                    self.config_dirty = true;
+                }
+            }
+            Message::SearchHistoryPrev => {
+                self.search_history_prev();
+            }
+            Message::SearchHistoryNext => {
+                self.search_history_next();
             }
             Message::SearchInput(c) => match self.current_tab {
                 Tab::Models => {
@@ -452,7 +1339,23 @@ impl App {
             | Message::CopyModelId
             | Message::CopyProviderDoc
             | Message::CopyProviderApi
-            | Message::OpenProviderDoc => {}
+            | Message::CopySelectedModels
+            | Message::CopyModelConfig
+            | Message::OpenProviderDoc
+            | Message::OpenProviderHomepage => {}
+            Message::ToggleModelSelection => {
+                self.toggle_model_selection();
+            }
+            Message::ToggleFavorite => {
+                if let Some(starred) = self.models_app.toggle_favorite(&self.providers) {
+                    self.config_dirty = true;
+                    self.set_status(if starred {
+                        "Added to favorites".to_string()
+                    } else {
+                        "Removed from favorites".to_string()
+                    });
+                }
+            }
             Message::CycleSort => {
                 self.models_app.cycle_sort(&self.providers);
             }
@@ -468,15 +1371,40 @@ impl App {
             Message::ToggleOpenWeights => {
                 self.models_app.toggle_open_weights(&self.providers);
             }
+            Message::CycleReleaseWindow => {
+                self.models_app.cycle_release_window(&self.providers);
+            }
+            Message::CycleKnowledgeWindow => {
+                self.models_app.cycle_knowledge_window(&self.providers);
+            }
+            Message::ToggleDedupe => {
+                self.models_app.toggle_dedupe(&self.providers);
+            }
+            Message::ToggleDeprecated => {
+                self.models_app.toggle_deprecated(&self.providers);
+            }
             Message::ToggleFree => {
                 self.models_app.toggle_free(&self.providers);
             }
             Message::ToggleHelp => {
                 self.show_help = !self.show_help;
+                self.help_filter.clear();
                 if self.show_help {
                     self.help_scroll.jump_top(); // Reset scroll when opening
                 }
             }
+            Message::HelpFilterInput(c) => {
+                self.help_filter.push(c);
+                self.help_scroll.jump_top();
+            }
+            Message::HelpFilterBackspace => {
+                self.help_filter.pop();
+                self.help_scroll.jump_top();
+            }
+            Message::ClearHelpFilter => {
+                self.help_filter.clear();
+                self.help_scroll.jump_top();
+            }
             Message::ScrollHelpUp => {
                 self.help_scroll.decrement(1);
             }
@@ -485,6 +1413,77 @@ impl App {
                 // past content, so we just increment here.
                 self.help_scroll.increment(1);
             }
+            Message::ResetView => {
+                let display = &self.config.display;
+                match self.current_tab {
+                    Tab::Models => {
+                        let order = display
+                            .models_sort
+                            .as_deref()
+                            .and_then(SortOrder::from_config_key)
+                            .unwrap_or(SortOrder::ReleaseDate);
+                        self.models_app
+                            .reset_view(order, display.show_deprecated, &self.providers);
+                    }
+                    Tab::Agents => {
+                        let order = display
+                            .agents_sort
+                            .as_deref()
+                            .and_then(AgentSortOrder::from_config_key)
+                            .unwrap_or_default();
+                        if let Some(ref mut agents_app) = self.agents_app {
+                            agents_app.reset_view(order);
+                        }
+                    }
+                    Tab::Benchmarks => {
+                        let col = display
+                            .benchmarks_sort
+                            .as_deref()
+                            .and_then(BenchmarkSortColumn::from_config_key)
+                            .unwrap_or_default();
+                        self.benchmarks_app.reset_view(
+                            col,
+                            &self.benchmark_store,
+                            &self.open_weights_map,
+                        );
+                    }
+                    Tab::Status => {
+                        if let Some(ref mut status_app) = self.status_app {
+                            status_app.reset_view();
+                        }
+                    }
+                }
+                self.set_status("View reset: search, filters, and sort cleared".to_string());
+            }
+            Message::ToggleStatusLog => {
+                self.show_status_log = !self.show_status_log;
+                if self.show_status_log {
+                    self.status_log_scroll.jump_top();
+                }
+            }
+            Message::ScrollStatusLogUp => {
+                self.status_log_scroll.decrement(1);
+            }
+            Message::ScrollStatusLogDown => {
+                self.status_log_scroll.increment(1);
+            }
+            Message::AddToComparison => self.toggle_comparison_item(),
+            Message::ToggleComparison => {
+                self.show_comparison = !self.show_comparison;
+                if self.show_comparison {
+                    self.comparison_scroll.jump_top();
+                }
+            }
+            Message::ClearComparison => {
+                self.comparison.clear();
+                self.set_status("Comparison cleared".to_string());
+            }
+            Message::ScrollComparisonUp => {
+                self.comparison_scroll.decrement(1);
+            }
+            Message::ScrollComparisonDown => {
+                self.comparison_scroll.increment(1);
+            }
             Message::NextTab => {
                 self.current_tab = self.current_tab.next();
             }
@@ -556,6 +1555,12 @@ impl App {
                     status_app.switch_focus();
                 }
             }
+            Message::RefreshBenchmarks => {
+                if !self.benchmarks_app.loading {
+                    self.benchmarks_app.loading = true;
+                    self.pending_benchmark_refresh = true;
+                }
+            }
             Message::RefreshStatus => {
                 if let Some(ref mut status_app) = self.status_app {
                     status_app.loading = true;
@@ -810,6 +1815,12 @@ impl App {
             Message::ToggleGrouping => {
                 self.models_app.toggle_grouping(&self.providers);
             }
+            Message::ToggleOutputGrouping => {
+                self.models_app.toggle_output_grouping(&self.providers);
+            }
+            Message::CycleProviderSort => {
+                self.models_app.cycle_provider_sort(&self.providers);
+            }
             // Benchmarks tab messages
             Message::NextBenchmark => {
                 self.benchmarks_app.next();
@@ -1033,7 +2044,65 @@ impl App {
             Message::CycleRadarPreset => {
                 self.benchmarks_app.cycle_radar_preset();
             }
-            Message::CopyBenchmarkName | Message::OpenBenchmarkUrl => {
+            Message::OpenBenchmarkModel => {
+                if let Some(entry) = self.benchmarks_app.current_entry(&self.benchmark_store) {
+                    match crate::benchmarks::find_model_for_entry(&self.providers, entry) {
+                        Some((provider_id, model_id)) => {
+                            self.pending_model_jump = Some(format!("{provider_id}/{model_id}"));
+                            self.current_tab = Tab::Models;
+                            self.apply_pending_model_jump();
+                        }
+                        None => {
+                            let name = entry.name.clone();
+                            self.set_status(format!("No models.dev match for {name}"));
+                        }
+                    }
+                }
+            }
+            Message::OpenModelBenchmarks
+                if self
+                    .models_app
+                    .current_model()
+                    .is_some_and(|entry| entry.model.is_embedding_model()) =>
+            {
+                self.set_status("Embedding model: chat benchmarks don't apply".to_string());
+            }
+            Message::OpenModelBenchmarks => {
+                let matched = self.models_app.current_model().map(|model| {
+                    crate::benchmarks::find_entry_for_model(
+                        self.benchmark_store.entries(),
+                        &model.provider_id,
+                        &model.id,
+                    )
+                });
+                match matched {
+                    None => {}
+                    Some(Some(found)) => {
+                        self.current_tab = Tab::Benchmarks;
+                        if !self.benchmarks_app.select_entry(
+                            found.index,
+                            &self.benchmark_store,
+                            &self.open_weights_map,
+                        ) {
+                            self.set_status(
+                                "Benchmark entry is hidden by the current filters".to_string(),
+                            );
+                        } else if found.tier.is_fuzzy() {
+                            // "~" flags a fuzzy pick that may be the wrong variant
+                            let name = self.benchmark_store.entries()[found.index].name.clone();
+                            self.set_status(format!(
+                                "~{name} (fuzzy match, {:.0}% similar)",
+                                found.score * 100.0
+                            ));
+                        }
+                    }
+                    Some(None) => self.set_status("No benchmark data for this model".to_string()),
+                }
+            }
+            Message::CopyBenchmarkName
+            | Message::CopyBenchmarkMarkdown
+            | Message::CopyBenchmarkCsv
+            | Message::OpenBenchmarkUrl => {
                 // Handled in main loop
             }
             Message::GitHubDataReceived(agent_id, data) => {
@@ -1066,9 +2135,64 @@ impl App {
                     }
                 }
             }
-            Message::BenchmarkDataReceived(entries) => {
+            Message::CopyFinished(text, copied, outcome) => {
+                // Show the text itself when nothing could copy it
+                let status = match outcome {
+                    CopyOutcome::Clipboard | CopyOutcome::Osc52 => copied,
+                    CopyOutcome::Unverified => format!(
+                        "{copied} {EM_DASH} but the clipboard reads back differently; \
+                         try display.clipboard_backend"
+                    ),
+                    CopyOutcome::Failed => format!(
+                        "Copy failed {EM_DASH} showing instead: {}",
+                        text.replace('\n', " ")
+                    ),
+                };
+                self.set_status(status);
+            }
+            Message::ProvidersReceived(providers_map, data_source) => {
+                let mut providers: Vec<(String, Provider)> = providers_map.into_iter().collect();
+                providers.sort_by(|a, b| a.0.cmp(&b.0));
+                self.providers = providers;
+                self.data_source = data_source;
+                self.providers_loading = None;
+                let recent = std::mem::take(&mut self.models_app.recent_models);
+                let favorites = std::mem::take(&mut self.models_app.favorite_models);
+                self.models_app =
+                    configured_models_app(&self.providers, &self.config, recent, favorites);
+                self.open_weights_map = crate::benchmarks::build_open_weights_map(
+                    &self.providers,
+                    self.benchmark_store.entries(),
+                );
+                crate::benchmarks::apply_model_traits(
+                    &self.providers,
+                    self.benchmark_store.entries_mut(),
+                );
+                self.benchmarks_app
+                    .rebuild(&self.benchmark_store, &self.open_weights_map);
+                let unknown = crate::provider_category::unknown_overrides(
+                    self.providers.iter().map(|(id, _)| id.as_str()),
+                );
+                if !unknown.is_empty() {
+                    self.set_status(format!(
+                        "provider_categories: unknown provider {}",
+                        unknown.join(", ")
+                    ));
+                }
+                if let Some(info) = crate::api::last_response() {
+                    if let Some(version) = info.version_label() {
+                        self.log_status(format!("models.dev data: {}", version));
+                    }
+                    if let Some(warning) = info.schema_warning() {
+                        self.set_status(warning);
+                    }
+                }
+                self.apply_pending_model_jump();
+            }
+            Message::BenchmarkDataReceived(entries, fetched_at) => {
                 self.selections.clear();
                 self.benchmarks_app.loading = false;
+                self.benchmarks_app.fetched_at = Some(fetched_at);
                 self.benchmark_store = BenchmarkStore::from_entries(entries);
                 self.open_weights_map = crate::benchmarks::build_open_weights_map(
                     &self.providers,
@@ -1081,9 +2205,24 @@ impl App {
                 self.benchmarks_app
                     .rebuild(&self.benchmark_store, &self.open_weights_map);
             }
+            Message::BenchmarkChanges(summary) => {
+                self.set_status(format!("Benchmarks updated: {summary}"));
+            }
+            Message::BenchmarkFetchTimedOut => {
+                self.benchmarks_app.loading = false;
+                self.set_status("Benchmark fetch: request timed out".to_string());
+            }
+            Message::BenchmarkFetchCertificateRejected => {
+                self.benchmarks_app.loading = false;
+                self.set_status(format!("Benchmark fetch: {}", crate::http::NATIVE_TLS_HINT));
+            }
             Message::BenchmarkFetchFailed => {
                 self.benchmarks_app.loading = false;
-                self.set_status("Failed to fetch benchmark data".to_string());
+                if crate::offline::is_offline() {
+                    self.set_status("Benchmark data unavailable offline".to_string());
+                } else {
+                    self.set_status("Failed to fetch benchmark data".to_string());
+                }
             }
             Message::StatusDataReceived(entries) => {
                 if let Some(ref mut status_app) = self.status_app {
@@ -1091,13 +2230,35 @@ impl App {
                 }
             }
         }
+        self.record_recent_model();
         true
     }
 
+    /// Models the user moves through in the model list or detail panel count as
+    /// viewed; the initial selection while browsing providers does not.
+    fn record_recent_model(&mut self) {
+        if self.current_tab == Tab::Models
+            && self.models_app.focus != Focus::Providers
+            && self.models_app.record_recent_model()
+        {
+            self.config_dirty = true;
+        }
+    }
+
     pub fn set_status(&mut self, msg: String) {
+        self.log_status(msg.clone());
         self.status_message = Some(msg);
     }
 
+    /// Record a message in the status log (`L`) without showing it.
+    pub fn log_status(&mut self, msg: String) {
+        if self.status_log.len() == STATUS_LOG_LIMIT {
+            self.status_log.remove(0);
+        }
+        let time = chrono::Local::now().format("%H:%M:%S").to_string();
+        self.status_log.push((time, msg));
+    }
+
     pub fn clear_status(&mut self) {
         self.status_message = None;
     }
@@ -1107,6 +2268,8 @@ impl App {
 mod tests {
     use super::*;
     use crate::agents::{Agent, AgentsFile};
+    use crate::data::OutputCategory;
+    use crate::tui::models::ProviderSort;
     use std::collections::{HashMap, HashSet};
     use std::ffi::OsString;
     use std::path::PathBuf;
@@ -1264,6 +2427,774 @@ mod tests {
         assert!(!app.selections.contains(&100));
     }
 
+    fn make_models_test_app() -> App {
+        make_models_test_app_with(BenchmarkStore::empty())
+    }
+
+    fn make_models_test_app_with(benchmark_store: BenchmarkStore) -> App {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "openai": {
+                "id": "openai",
+                "name": "OpenAI",
+                "models": {
+                    "gpt-4o": { "id": "gpt-4o", "name": "GPT-4o" },
+                    "gpt-4o-mini": { "id": "gpt-4o-mini", "name": "GPT-4o mini" }
+                }
+            }
+        }))
+        .expect("valid providers json");
+        App::new(providers, None, None, benchmark_store)
+    }
+
+    #[test]
+    fn test_default_tab_and_sorts_from_config() {
+        let config: Config = toml::from_str(
+            "[display]\ndefault_tab = \"Benchmarks\"\nmodels_sort = \"cost\"\nbenchmarks_sort = \"price-input\"\n",
+        )
+        .unwrap();
+        let app = App::new(HashMap::new(), None, Some(config), BenchmarkStore::empty());
+        assert_eq!(app.current_tab, Tab::Benchmarks);
+        assert_eq!(app.models_app.sort_order, SortOrder::Cost);
+        assert_eq!(
+            app.benchmarks_app.sort_column,
+            BenchmarkSortColumn::PriceInput
+        );
+        assert!(!app.benchmarks_app.sort_descending);
+
+        let config: Config =
+            toml::from_str("[display]\ndefault_tab = \"bogus\"\nmodels_sort = \"size\"\n").unwrap();
+        let app = App::new(HashMap::new(), None, Some(config), BenchmarkStore::empty());
+        assert_eq!(app.current_tab, Tab::Models);
+        assert_eq!(app.models_app.sort_order, SortOrder::ReleaseDate);
+    }
+
+    #[test]
+    fn test_min_score_threshold_filters_benchmarks() {
+        let entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "Strong", "slug": "strong", "intelligence_index": 70.0 },
+            { "name": "Weak", "slug": "weak", "intelligence_index": 30.0 },
+        ]))
+        .unwrap();
+        let mut app = App::new(
+            HashMap::new(),
+            None,
+            None,
+            BenchmarkStore::from_entries(entries),
+        );
+        app.update(Message::QuickSortIntelligence);
+        assert_eq!(app.benchmarks_app.filtered_indices.len(), 2);
+
+        app.update(Message::EnterMinScore);
+        assert_eq!(app.mode, Mode::MinScore);
+        for c in "5x0".chars() {
+            app.update(Message::MinScoreInput(c));
+        }
+        app.update(Message::ApplyMinScore);
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.benchmarks_app.min_score, Some(50.0));
+        assert_eq!(app.benchmarks_app.filtered_indices.len(), 1);
+
+        // Switching columns drops the threshold
+        app.update(Message::QuickSortSpeed);
+        assert_eq!(app.benchmarks_app.min_score, None);
+    }
+
+    #[test]
+    fn test_benchmark_markdown_marks_missing_scores() {
+        let entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            {
+                "name": "GPT-4o",
+                "slug": "gpt-4o",
+                "release_date": "2024-05-13",
+                "intelligence_index": 41.25,
+                "gpqa": 0.5,
+            },
+        ]))
+        .unwrap();
+        let app = App::new(
+            HashMap::new(),
+            None,
+            None,
+            BenchmarkStore::from_entries(entries),
+        );
+        let markdown = app.get_benchmark_markdown().expect("entry selected");
+        assert!(markdown.starts_with("### GPT-4o\n\n| Metric | Value |\n|---|---|\n"));
+        assert!(markdown.contains("| Intelligence | 41.2 |\n"));
+        assert!(markdown.contains("| GPQA | 50.0% |\n"));
+        assert!(markdown.contains("| Coding | \u{2014} |\n"));
+    }
+
+    #[test]
+    fn test_open_benchmark_model_selects_match_in_models_tab() {
+        let mut entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "GPT-4o mini", "slug": "gpt-4o-mini", "release_date": "2024-07-18" },
+        ]))
+        .unwrap();
+        entries[0].creator = "openai".to_string();
+        let mut app = make_models_test_app_with(BenchmarkStore::from_entries(entries));
+        app.current_tab = Tab::Benchmarks;
+
+        app.update(Message::OpenBenchmarkModel);
+        assert_eq!(app.current_tab, Tab::Models);
+        assert_eq!(app.pending_model_jump, None);
+        assert_eq!(app.models_app.focus, Focus::Models);
+        assert_eq!(
+            app.models_app.current_model().map(|m| m.id.as_str()),
+            Some("gpt-4o-mini")
+        );
+    }
+
+    #[test]
+    fn test_open_model_benchmarks_selects_entry() {
+        let mut entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "GPT-4o", "slug": "gpt-4o", "release_date": "2024-05-13" },
+            { "name": "GPT-4o mini", "slug": "gpt-4o-mini", "release_date": "2024-07-18" },
+        ]))
+        .unwrap();
+        for entry in &mut entries {
+            entry.creator = "openai".to_string();
+        }
+        let mut app = make_models_test_app();
+        app.benchmark_store = BenchmarkStore::from_entries(entries);
+        app.benchmarks_app
+            .rebuild(&app.benchmark_store, &app.open_weights_map);
+        let index = app
+            .models_app
+            .filtered_models()
+            .iter()
+            .position(|m| m.id == "gpt-4o")
+            .unwrap();
+        app.models_app.select_model_at(index);
+
+        app.update(Message::OpenModelBenchmarks);
+        assert_eq!(app.current_tab, Tab::Benchmarks);
+        assert_eq!(
+            app.benchmarks_app
+                .current_entry(&app.benchmark_store)
+                .map(|e| e.slug.as_str()),
+            Some("gpt-4o")
+        );
+
+        let mut app = make_models_test_app();
+        app.update(Message::OpenModelBenchmarks);
+        assert_eq!(app.current_tab, Tab::Models);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("No benchmark data for this model")
+        );
+    }
+
+    #[test]
+    fn test_palette_jumps_to_model() {
+        let mut app = make_models_test_app();
+        app.current_tab = Tab::Benchmarks;
+        app.update(Message::OpenPalette);
+        assert_eq!(app.mode, Mode::Palette);
+        for c in "mini".chars() {
+            app.update(Message::PaletteInput(c));
+        }
+        app.update(Message::PaletteConfirm);
+
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.palette.is_none());
+        assert_eq!(app.current_tab, Tab::Models);
+        assert_eq!(app.models_app.focus, Focus::Models);
+        assert_eq!(
+            app.models_app.current_model().map(|m| m.id.as_str()),
+            Some("gpt-4o-mini")
+        );
+    }
+
+    #[test]
+    fn test_reset_view_clears_models_filters_and_sort() {
+        let mut app = make_models_test_app();
+        app.update(Message::ToggleReasoning);
+        app.update(Message::CycleReleaseWindow);
+        app.models_app
+            .set_search("mini".to_string(), &app.providers.clone());
+        app.models_app
+            .set_sort_order(SortOrder::Cost, &app.providers.clone());
+
+        app.update(Message::ResetView);
+        assert!(!app.models_app.filters.reasoning);
+        assert!(app.models_app.filters.released_after.is_none());
+        assert!(app.models_app.search_query.is_empty());
+        assert_eq!(app.models_app.sort_order, SortOrder::ReleaseDate);
+        assert!(app.models_app.is_all_selected());
+        assert!(app.status_message.is_some());
+
+        // The configured deprecated visibility survives the reset
+        app.config.display.show_deprecated = true;
+        app.update(Message::ResetView);
+        assert!(app.models_app.filters.show_deprecated);
+    }
+
+    #[test]
+    fn test_status_log_keeps_recent_messages() {
+        let mut app = make_models_test_app();
+        for i in 0..STATUS_LOG_LIMIT + 5 {
+            app.set_status(format!("status {i}"));
+        }
+        app.clear_status();
+        assert_eq!(app.status_log.len(), STATUS_LOG_LIMIT);
+        assert_eq!(app.status_log[0].1, "status 5");
+        assert_eq!(
+            app.status_log.last().map(|(_, msg)| msg.as_str()),
+            Some("status 104")
+        );
+
+        app.update(Message::ToggleStatusLog);
+        assert!(app.show_status_log);
+    }
+
+    #[test]
+    fn test_benchmarks_csv_follows_visible_columns_and_order() {
+        let entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "Beta, Large", "slug": "beta", "release_date": "2025-02-01", "intelligence_index": 60.0 },
+            { "name": "Alpha", "slug": "alpha", "release_date": "2025-03-01", "coding_index": 41.25 },
+        ]))
+        .unwrap();
+        let app = App::new(
+            ProvidersMap::default(),
+            None,
+            None,
+            BenchmarkStore::from_entries(entries),
+        );
+        assert_eq!(
+            app.benchmarks_app.sort_column,
+            BenchmarkSortColumn::ReleaseDate
+        );
+        assert_eq!(
+            app.get_benchmarks_csv().unwrap(),
+            "Name,Intel,Code,Math,Released\n\
+             Alpha,,41.2,,2025-03-01\n\
+             \"Beta, Large\",60.0,,,2025-02-01\n"
+        );
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_embedding_model_skips_benchmark_lookup() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "openai": {
+                "id": "openai",
+                "name": "OpenAI",
+                "models": {
+                    "text-embedding-3-small": { "id": "text-embedding-3-small", "name": "Embedding 3 Small" }
+                }
+            }
+        }))
+        .unwrap();
+        let mut app = App::new(providers, None, None, BenchmarkStore::empty());
+        app.update(Message::OpenModelBenchmarks);
+        assert_eq!(app.current_tab, Tab::Models);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Embedding model: chat benchmarks don't apply")
+        );
+    }
+
+    #[test]
+    fn test_provider_sort_by_model_count_keeps_selection() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "acme": { "id": "acme", "name": "Acme", "models": {
+                "a1": { "id": "a1", "name": "A1" }
+            } },
+            "zeta": { "id": "zeta", "name": "Zeta", "models": {
+                "z1": { "id": "z1", "name": "Z1" },
+                "z2": { "id": "z2", "name": "Z2" }
+            } }
+        }))
+        .unwrap();
+        let mut app = App::new(providers, None, None, BenchmarkStore::empty());
+        let order = |app: &App| -> Vec<String> {
+            app.models_app
+                .provider_list_items
+                .iter()
+                .filter_map(|item| match item {
+                    ProviderListItem::Provider(idx, _) => Some(app.providers[*idx].0.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(order(&app), ["acme", "zeta"]);
+        assert!(app
+            .models_app
+            .select_provider_id("acme", &app.providers.clone()));
+
+        app.update(Message::CycleProviderSort);
+        assert_eq!(app.models_app.provider_sort, ProviderSort::ModelCount);
+        assert_eq!(order(&app), ["zeta", "acme"]);
+        let selected = app.models_app.selected_provider_data(&app.providers);
+        assert_eq!(selected.map(|(id, _)| id.as_str()), Some("acme"));
+
+        app.update(Message::CycleProviderSort);
+        assert_eq!(order(&app), ["acme", "zeta"]);
+    }
+
+    #[test]
+    fn test_output_grouping_lists_models_by_output() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "openai": {
+                "id": "openai",
+                "name": "OpenAI",
+                "models": {
+                    "gpt-4o": { "id": "gpt-4o", "name": "GPT-4o" },
+                    "gpt-image-1": { "id": "gpt-image-1", "name": "GPT Image", "modalities": { "input": ["text"], "output": ["image"] } },
+                    "tts-1": { "id": "tts-1", "name": "TTS", "modalities": { "input": ["text"], "output": ["audio"] } }
+                }
+            }
+        }))
+        .unwrap();
+        let mut app = App::new(providers, None, None, BenchmarkStore::empty());
+        app.update(Message::ToggleOutputGrouping);
+        assert_eq!(
+            app.models_app.provider_list_items,
+            [
+                ProviderListItem::All,
+                ProviderListItem::OutputHeader,
+                ProviderListItem::Output(OutputCategory::Text, 1),
+                ProviderListItem::Output(OutputCategory::Image, 1),
+                ProviderListItem::Output(OutputCategory::Audio, 1),
+            ]
+        );
+        assert_eq!(app.models_app.filtered_model_count(), 3);
+
+        // Clicking the header lands on the first group
+        app.update(Message::ClickListRow(ListRegion::ModelProviders, 1));
+        assert_eq!(app.models_app.selected_output(), Some(OutputCategory::Text));
+        app.update(Message::ClickListRow(ListRegion::ModelProviders, 3));
+        let ids: Vec<&str> = app
+            .models_app
+            .filtered_models()
+            .iter()
+            .map(|e| e.id.as_str())
+            .collect();
+        assert_eq!(ids, ["gpt-image-1"]);
+
+        app.update(Message::ToggleOutputGrouping);
+        assert!(app.models_app.is_all_selected());
+        assert!(matches!(
+            app.models_app.provider_list_items.last(),
+            Some(ProviderListItem::Provider(_, 3))
+        ));
+    }
+
+    #[test]
+    fn test_deprecated_models_hidden_until_toggled() {
+        let providers = || -> ProvidersMap {
+            serde_json::from_value(serde_json::json!({
+                "openai": {
+                    "id": "openai",
+                    "name": "OpenAI",
+                    "models": {
+                        "gpt-4o": { "id": "gpt-4o", "name": "GPT-4o" },
+                        "gpt-4": { "id": "gpt-4", "name": "GPT-4", "status": "deprecated" }
+                    }
+                }
+            }))
+            .unwrap()
+        };
+        let mut app = App::new(providers(), None, None, BenchmarkStore::empty());
+        assert_eq!(app.models_app.filtered_models().len(), 1);
+        assert_eq!(app.models_app.hidden_deprecated, 1);
+
+        app.update(Message::ToggleDeprecated);
+        assert_eq!(app.models_app.filtered_models().len(), 2);
+        assert_eq!(app.models_app.hidden_deprecated, 0);
+
+        let config: Config = toml::from_str("[display]\nshow_deprecated = true\n").unwrap();
+        let app = App::new(providers(), None, Some(config), BenchmarkStore::empty());
+        assert_eq!(app.models_app.filtered_models().len(), 2);
+
+        // With one provider selected, the provider id doesn't match the
+        // search, so neither the list nor the hidden count includes its models
+        let mut app = App::new(providers(), None, None, BenchmarkStore::empty());
+        let providers = app.providers.clone();
+        assert!(app.models_app.select_provider_id("openai", &providers));
+        app.models_app.set_search("openai".to_string(), &providers);
+        assert!(app.models_app.filtered_models().is_empty());
+        assert_eq!(app.models_app.hidden_deprecated, 0);
+    }
+
+    #[test]
+    fn test_output_limit_sort_follows_context_in_cycle() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "openai": {
+                "id": "openai",
+                "name": "OpenAI",
+                "models": {
+                    "wide": { "id": "wide", "name": "Wide", "limit": { "context": 1000000, "output": 8000 } },
+                    "long": { "id": "long", "name": "Long", "limit": { "context": 200000, "output": 64000 } },
+                    "bare": { "id": "bare", "name": "Bare" }
+                }
+            }
+        }))
+        .unwrap();
+        let mut app = App::new(providers, None, None, BenchmarkStore::empty());
+        app.models_app
+            .set_sort_order(SortOrder::Context, &app.providers.clone());
+        app.update(Message::CycleSort);
+        assert_eq!(app.models_app.sort_order, SortOrder::OutputLimit);
+        let ids: Vec<&str> = app
+            .models_app
+            .filtered_models()
+            .iter()
+            .map(|e| e.id.as_str())
+            .collect();
+        assert_eq!(ids, ["long", "wide", "bare"]);
+
+        // Models without a limit stay last when ascending too
+        app.update(Message::ToggleSortDir);
+        let ids: Vec<&str> = app
+            .models_app
+            .filtered_models()
+            .iter()
+            .map(|e| e.id.as_str())
+            .collect();
+        assert_eq!(ids, ["wide", "long", "bare"]);
+        assert_eq!(
+            SortOrder::from_config_key("output"),
+            Some(SortOrder::OutputLimit)
+        );
+    }
+
+    #[test]
+    fn test_add_to_comparison_toggles_selected_model() {
+        let mut app = make_models_test_app();
+        app.update(Message::AddToComparison);
+        assert_eq!(app.comparison.len(), 1);
+        assert!(app.comparison[0].model_key.is_some());
+
+        app.update(Message::AddToComparison);
+        assert!(app.comparison.is_empty());
+
+        app.update(Message::AddToComparison);
+        app.update(Message::ToggleComparison);
+        assert!(app.show_comparison);
+        app.update(Message::ClearComparison);
+        assert!(app.comparison.is_empty());
+    }
+
+    #[test]
+    fn test_panel_resize_marks_config_dirty() {
+        let config: Config =
+            toml::from_str("[display]\nprovider_panel_pct = 90\ndetail_panel_pct = 30\n").unwrap();
+        let mut app = App::new(HashMap::new(), None, Some(config), BenchmarkStore::empty());
+        assert_eq!(app.panel_split, PanelSplit::new(60, 15));
+
+        app.update(Message::ResizeProviderPanel(PanelSplit::STEP));
+        assert!(!app.config_dirty, "already at the maximum");
+
+        app.update(Message::ResizeProviderPanel(-PanelSplit::STEP));
+        assert_eq!(app.panel_split.providers, 55);
+        assert_eq!(app.config.display.provider_panel_pct, Some(55));
+        assert!(app.config_dirty);
+    }
+
+    #[test]
+    fn test_list_position_follows_focus() {
+        let mut app = make_models_test_app();
+        let providers = app.models_app.provider_list_items.len();
+        assert_eq!(app.list_position(), Some((1, providers)));
+
+        app.update(Message::FocusModelRight);
+        let models = app.models_app.filtered_models().len();
+        app.update(Message::NextModel);
+        assert_eq!(app.list_position(), Some((2, models)));
+
+        app.current_tab = Tab::Status;
+        assert_eq!(app.list_position(), None);
+    }
+
+    #[test]
+    fn test_model_selection_toggle_and_bulk_copy() {
+        let mut app = make_models_test_app();
+        assert_eq!(app.get_copy_selected(), None);
+
+        app.update(Message::ToggleModelSelection);
+        app.update(Message::NextModel);
+        app.update(Message::ToggleModelSelection);
+        assert_eq!(app.model_selections.len(), 2);
+        assert_eq!(
+            app.get_copy_selected().as_deref(),
+            Some("openai/gpt-4o\nopenai/gpt-4o-mini")
+        );
+
+        app.update(Message::ToggleModelSelection);
+        assert_eq!(app.model_selections.len(), 1);
+    }
+
+    #[test]
+    fn test_recent_models_recorded_and_listed() {
+        let mut app = make_models_test_app();
+        // Browsing providers doesn't count as viewing a model
+        app.update(Message::NextProvider);
+        assert!(app.models_app.recent_models.is_empty());
+
+        app.models_app.focus = Focus::Models;
+        app.update(Message::SelectFirstProvider);
+        app.update(Message::NextModel);
+        app.update(Message::PrevModel);
+        assert_eq!(
+            app.models_app.recent_models,
+            vec!["openai/gpt-4o", "openai/gpt-4o-mini"]
+        );
+        assert!(app.config_dirty);
+
+        // The Recent entry appears after a rebuild, most recent first
+        app.models_app.clear_search(&app.providers);
+        assert_eq!(
+            app.models_app.provider_list_items[1],
+            ProviderListItem::Recent(2)
+        );
+        app.models_app.focus = Focus::Providers;
+        app.update(Message::NextProvider);
+        assert!(app.models_app.is_recent_selected());
+        let ids: Vec<&str> = app
+            .models_app
+            .filtered_models()
+            .iter()
+            .map(|e| e.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["gpt-4o", "gpt-4o-mini"]);
+    }
+
+    #[test]
+    fn test_favorites_toggle_and_list() {
+        let mut app = make_models_test_app();
+        app.update(Message::NextModel);
+        app.update(Message::ToggleFavorite);
+        assert!(app.config_dirty);
+        assert!(app
+            .models_app
+            .favorite_models
+            .contains("openai/gpt-4o-mini"));
+        let favorites_pos = app
+            .models_app
+            .provider_list_items
+            .iter()
+            .position(|item| *item == ProviderListItem::Favorites(1))
+            .expect("favorites entry shown");
+
+        app.models_app
+            .select_provider_at_index(favorites_pos, &app.providers);
+        assert_eq!(app.models_app.filtered_models().len(), 1);
+        assert_eq!(app.models_app.filtered_models()[0].id, "gpt-4o-mini");
+
+        // Unstarring the last favorite drops the entry and falls back to All
+        app.update(Message::ToggleFavorite);
+        assert!(app.models_app.favorite_models.is_empty());
+        assert!(app.models_app.is_all_selected());
+    }
+
+    #[test]
+    fn test_copy_full_uses_copy_template() {
+        let mut app = make_models_test_app();
+        assert_eq!(app.get_copy_full().as_deref(), Some("openai/gpt-4o"));
+
+        app.config.display.copy_template = "{provider}:{model} ({name}, {input_cost})".to_string();
+        assert_eq!(
+            app.get_copy_full().as_deref(),
+            Some("openai:gpt-4o (GPT-4o, \u{2014})")
+        );
+    }
+
+    #[test]
+    fn test_provider_homepage_derivation() {
+        assert_eq!(
+            url_origin("https://platform.openai.com/docs/models", false).as_deref(),
+            Some("https://platform.openai.com")
+        );
+        assert_eq!(
+            url_origin("https://api.x.ai/v1", true).as_deref(),
+            Some("https://x.ai")
+        );
+        assert_eq!(url_origin("not a url", false), None);
+
+        // No doc or API URL: models.dev search for the model
+        let app = make_models_test_app();
+        assert_eq!(
+            app.get_provider_homepage().as_deref(),
+            Some("https://models.dev/?search=gpt-4o")
+        );
+
+        // The model id is query-encoded
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "acme": {
+                "id": "acme",
+                "name": "Acme",
+                "models": { "org/a b&c": { "id": "org/a b&c", "name": "ABC" } }
+            }
+        }))
+        .unwrap();
+        let app = App::new(providers, None, None, BenchmarkStore::empty());
+        assert_eq!(
+            app.get_provider_homepage().as_deref(),
+            Some("https://models.dev/?search=org%2Fa+b%26c")
+        );
+    }
+
+    #[test]
+    fn test_copy_config_falls_back_without_api_url() {
+        let app = make_models_test_app();
+        let config = app.get_copy_config().expect("model is selected");
+        let value: serde_json::Value = serde_json::from_str(&config).unwrap();
+        assert_eq!(value["provider"], "openai");
+        assert_eq!(value["model"], "gpt-4o");
+        assert!(value["base_url"].is_null());
+        assert_eq!(value["env"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_providers_received_ends_loading_and_rebuilds_models() {
+        let mut app = make_test_app();
+        app.providers_loading = Some(Instant::now());
+        let source = make_models_test_app();
+        let providers: ProvidersMap = source.providers.into_iter().collect();
+
+        app.update(Message::ProvidersReceived(providers, DataSource::Live));
+
+        assert!(app.providers_loading.is_none());
+        assert_eq!(app.providers.len(), 1);
+        assert_eq!(app.models_app.filtered_models().len(), 2);
+    }
+
+    #[test]
+    fn test_copy_finished_reports_outcome() {
+        let mut app = make_models_test_app();
+        app.update(Message::CopyFinished(
+            "gpt-4o".to_string(),
+            "Copied: gpt-4o".to_string(),
+            CopyOutcome::Osc52,
+        ));
+        assert_eq!(app.status_message.as_deref(), Some("Copied: gpt-4o"));
+
+        app.update(Message::CopyFinished(
+            "a\nb".to_string(),
+            "Copied 2 models".to_string(),
+            CopyOutcome::Failed,
+        ));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Copy failed \u{2014} showing instead: a b")
+        );
+    }
+
+    #[test]
+    fn test_providers_received_keeps_display_config() {
+        let mut config = Config::default();
+        config.display.models_sort = Some("context".to_string());
+        config.display.show_deprecated = true;
+        config.display.default_provider = Some("openai".to_string());
+        let mut app = App::new(HashMap::new(), None, Some(config), BenchmarkStore::empty());
+        app.providers_loading = Some(Instant::now());
+        let providers: ProvidersMap = make_models_test_app().providers.into_iter().collect();
+
+        app.update(Message::ProvidersReceived(providers, DataSource::Live));
+
+        assert_eq!(app.models_app.sort_order, SortOrder::Context);
+        assert!(app.models_app.filters.show_deprecated);
+        let selected = app.models_app.selected_provider_data(&app.providers);
+        assert_eq!(selected.map(|(id, _)| id.as_str()), Some("openai"));
+    }
+
+    #[test]
+    fn test_jump_provider_to_letter_cycles_matches() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "anthropic": { "id": "anthropic", "name": "Anthropic" },
+            "azure": { "id": "azure", "name": "Azure" },
+            "openai": { "id": "openai", "name": "OpenAI" }
+        }))
+        .expect("valid providers json");
+        let mut app = App::new(providers, None, None, BenchmarkStore::empty());
+        let selected_id = |app: &App| {
+            app.models_app
+                .selected_provider_data(&app.providers)
+                .map(|(id, _)| id.clone())
+        };
+
+        app.jump_provider_to_letter('a');
+        assert_eq!(selected_id(&app).as_deref(), Some("anthropic"));
+        app.jump_provider_to_letter('a');
+        assert_eq!(selected_id(&app).as_deref(), Some("azure"));
+        app.jump_provider_to_letter('a');
+        assert_eq!(selected_id(&app).as_deref(), Some("anthropic"));
+        app.jump_provider_to_letter('o');
+        assert_eq!(selected_id(&app).as_deref(), Some("openai"));
+    }
+
+    #[test]
+    fn test_default_provider_selected_at_launch() {
+        let providers = || -> ProvidersMap {
+            serde_json::from_value(serde_json::json!({
+                "anthropic": { "id": "anthropic", "name": "Anthropic" },
+                "openai": { "id": "openai", "name": "OpenAI" }
+            }))
+            .expect("valid providers json")
+        };
+        let config = |id: &str| -> Config {
+            toml::from_str(&format!("[display]\ndefault_provider = \"{id}\"\n")).unwrap()
+        };
+
+        let app = App::new(
+            providers(),
+            None,
+            Some(config("openai")),
+            BenchmarkStore::empty(),
+        );
+        let selected = app.models_app.selected_provider_data(&app.providers);
+        assert_eq!(selected.map(|(id, _)| id.as_str()), Some("openai"));
+
+        // Still applied when providers arrive after startup
+        let mut app = App::new(
+            HashMap::new(),
+            None,
+            Some(config("openai")),
+            BenchmarkStore::empty(),
+        );
+        app.update(Message::ProvidersReceived(providers(), DataSource::Live));
+        let selected = app.models_app.selected_provider_data(&app.providers);
+        assert_eq!(selected.map(|(id, _)| id.as_str()), Some("openai"));
+
+        let app = App::new(
+            providers(),
+            None,
+            Some(config("bogus")),
+            BenchmarkStore::empty(),
+        );
+        assert_eq!(app.models_app.selected_provider, 0);
+        assert!(app
+            .models_app
+            .selected_provider_data(&app.providers)
+            .is_none());
+    }
+
+    #[test]
+    fn test_list_hit_area_maps_rows_past_header_and_offset() {
+        let hit = ListHitArea {
+            region: ListRegion::Models,
+            area: Rect::new(10, 5, 20, 8),
+            offset: 3,
+            header_rows: 1,
+        };
+        assert_eq!(hit.row_at(12, 5), Some(2));
+        assert_eq!(hit.row_at(12, 7), Some(4));
+        assert_eq!(hit.row_at(9, 7), None);
+        assert_eq!(hit.row_at(12, 13), None);
+
+        let top = ListHitArea { offset: 0, ..hit };
+        assert_eq!(top.row_at(12, 5), None, "header row is not selectable");
+    }
+
+    #[test]
+    fn test_click_list_row_selects_model_and_focuses_list() {
+        let mut app = make_models_test_app();
+        app.update(Message::ClickListRow(ListRegion::Models, 1));
+        assert_eq!(app.models_app.focus, Focus::Models);
+        assert_eq!(app.models_app.selected_model, 1);
+    }
+
     #[test]
     fn test_clear_selections() {
         let mut app = make_test_app();
@@ -1461,4 +3392,101 @@ mod tests {
         assert_eq!(app.benchmarks_app.bottom_view, BottomView::H2H);
         assert_eq!(app.benchmarks_app.h2h_scroll.get(), 0);
     }
+
+    #[test]
+    fn test_detail_wrap_toggle_resets_hscroll() {
+        let mut app = make_models_test_app();
+        // Horizontal scroll only applies when unwrapped
+        app.update(Message::ScrollDetailRight);
+        assert_eq!(app.detail_hscroll.get(), 0);
+
+        app.update(Message::ToggleDetailWrap);
+        assert!(!app.detail_wrap);
+        app.update(Message::ScrollDetailRight);
+        app.update(Message::ScrollDetailRight);
+        assert_eq!(app.detail_hscroll.get(), 2 * HSCROLL_STEP);
+        app.update(Message::ScrollDetailLeft);
+        assert_eq!(app.detail_hscroll.get(), HSCROLL_STEP);
+
+        app.update(Message::ToggleDetailWrap);
+        assert!(app.detail_wrap);
+        assert_eq!(app.detail_hscroll.get(), 0);
+    }
+
+    #[test]
+    fn test_flat_details_starts_from_config_and_toggles() {
+        let mut app = make_models_test_app();
+        assert!(!app.flat_details);
+        app.update(Message::ToggleFlatDetails);
+        assert!(app.flat_details);
+
+        let config: Config = toml::from_str("[display]\nflat_details = true\n").unwrap();
+        let app = App::new(
+            ProvidersMap::new(),
+            None,
+            Some(config),
+            BenchmarkStore::empty(),
+        );
+        assert!(app.flat_details);
+    }
+
+    #[test]
+    fn test_search_history_recall_and_draft() {
+        let mut app = make_models_test_app();
+        for query in ["gpt", "mini"] {
+            app.update(Message::EnterSearch);
+            app.set_search_query(query.to_string());
+            app.update(Message::ExitSearch);
+        }
+        assert_eq!(app.config.search_history.models, vec!["gpt", "mini"]);
+        assert!(app.config_dirty);
+        // Other tabs keep their own history
+        assert!(app.config.search_history.agents.is_empty());
+
+        app.update(Message::ClearSearch);
+        app.update(Message::EnterSearch);
+        app.update(Message::SearchInput('4'));
+        app.update(Message::SearchHistoryPrev);
+        assert_eq!(app.models_app.search_query, "mini");
+        app.update(Message::SearchHistoryPrev);
+        app.update(Message::SearchHistoryPrev);
+        assert_eq!(app.models_app.search_query, "gpt");
+        app.update(Message::SearchHistoryNext);
+        assert_eq!(app.models_app.search_query, "mini");
+        app.update(Message::SearchHistoryNext);
+        assert_eq!(app.models_app.search_query, "4");
+        assert!(app.search_history_cursor.is_none());
+    }
+
+    #[test]
+    fn test_refresh_benchmarks_sets_pending_once_loaded() {
+        let mut app = make_test_app();
+        // Initial fetch still in flight: refresh is a no-op
+        app.update(Message::RefreshBenchmarks);
+        assert!(!app.pending_benchmark_refresh);
+
+        app.update(Message::BenchmarkDataReceived(
+            Vec::new(),
+            chrono::Utc::now(),
+        ));
+        assert!(app.benchmarks_app.fetched_at.is_some());
+        assert_eq!(app.benchmarks_app.freshness_suffix(), " · updated 1m ago");
+
+        app.update(Message::RefreshBenchmarks);
+        assert!(app.pending_benchmark_refresh);
+        assert_eq!(app.benchmarks_app.freshness_suffix(), " loading...");
+    }
+
+    #[test]
+    fn test_help_filter_clears_on_close() {
+        let mut app = App::new(ProvidersMap::default(), None, None, BenchmarkStore::empty());
+        app.update(Message::ToggleHelp);
+        app.update(Message::HelpFilterInput('q'));
+        app.update(Message::HelpFilterInput('x'));
+        app.update(Message::HelpFilterBackspace);
+        assert_eq!(app.help_filter, "q");
+        app.update(Message::ToggleHelp);
+        assert!(!app.show_help);
+        assert!(app.help_filter.is_empty());
+    }
 }
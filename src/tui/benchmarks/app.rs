@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use ratatui::style::Color;
 use ratatui::widgets::ListState;
 
 use crate::benchmarks::{BenchmarkEntry, BenchmarkStore, ReasoningFilter};
-use crate::formatting::{cmp_opt_f64, parse_date_to_numeric};
+use crate::formatting::{cmp_opt_f64, format_relative_time, parse_date_to_numeric, SearchMatcher};
 use crate::tui::widgets::scroll_offset::ScrollOffset;
 
 /// Page size for page up/down navigation
@@ -30,6 +31,7 @@ pub enum BenchmarkSortColumn {
     PriceInput,
     PriceOutput,
     PriceBlended,
+    Value,
     Name,
     #[default]
     ReleaseDate,
@@ -56,6 +58,7 @@ impl BenchmarkSortColumn {
         Self::PriceInput,
         Self::PriceOutput,
         Self::PriceBlended,
+        Self::Value,
         Self::Name,
     ];
 
@@ -79,6 +82,7 @@ impl BenchmarkSortColumn {
             Self::PriceInput => "Price: Input $/M",
             Self::PriceOutput => "Price: Output $/M",
             Self::PriceBlended => "Price: Blended $/M",
+            Self::Value => "Value: $/Intel Point",
             Self::Name => "Name",
             Self::ReleaseDate => "Release Date",
         }
@@ -104,11 +108,53 @@ impl BenchmarkSortColumn {
             Self::PriceInput => "In $/M",
             Self::PriceOutput => "Out $/M",
             Self::PriceBlended => "Bld $/M",
+            Self::Value => "$/Intel",
             Self::Name => "Name",
             Self::ReleaseDate => "Date",
         }
     }
 
+    /// Name used by `display.benchmarks_sort`, matching `benchmarks list --sort`
+    pub fn config_key(&self) -> &'static str {
+        match self {
+            Self::Intelligence => "intelligence",
+            Self::Coding => "coding",
+            Self::Math => "math",
+            Self::Gpqa => "gpqa",
+            Self::MMLUPro => "mmlu-pro",
+            Self::Hle => "hle",
+            Self::LiveCode => "livecodebench",
+            Self::SciCode => "scicode",
+            Self::IFBench => "ifbench",
+            Self::Lcr => "lcr",
+            Self::Terminal => "terminalbench",
+            Self::Tau2 => "tau2",
+            Self::Speed => "speed",
+            Self::Ttft => "ttft",
+            Self::Ttfat => "ttfat",
+            Self::PriceInput => "price-input",
+            Self::PriceOutput => "price-output",
+            Self::PriceBlended => "price-blended",
+            Self::Value => "value",
+            Self::Name => "name",
+            Self::ReleaseDate => "release-date",
+        }
+    }
+
+    /// Parse `display.benchmarks_sort` (case-insensitive).
+    pub fn from_config_key(key: &str) -> Option<Self> {
+        let key = key.trim().to_ascii_lowercase();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|col| col.config_key() == key)
+    }
+
+    /// Whether a minimum-score threshold applies (scored columns only)
+    pub fn supports_threshold(&self) -> bool {
+        !matches!(self, Self::Name | Self::ReleaseDate)
+    }
+
     /// Whether descending is the default sort direction for this column
     pub fn default_descending(&self) -> bool {
         !matches!(
@@ -119,11 +165,13 @@ impl BenchmarkSortColumn {
                 | Self::PriceInput
                 | Self::PriceOutput
                 | Self::PriceBlended
+                | Self::Value
         )
     }
 
     /// Returns the columns to display in the list based on the active sort.
     /// Always includes Name + the 3 composite indexes, plus the sort column's group.
+    /// Value shows blended price next to it so the ratio reads against both inputs.
     pub fn visible_columns(&self) -> Vec<BenchmarkSortColumn> {
         use BenchmarkSortColumn::*;
 
@@ -138,6 +186,7 @@ impl BenchmarkSortColumn {
             IFBench | Lcr | Tau2 => vec![IFBench, Lcr, Tau2],
             Speed | Ttft | Ttfat => vec![Speed, Ttft, Ttfat],
             PriceInput | PriceOutput | PriceBlended => vec![PriceInput, PriceOutput, PriceBlended],
+            Value => vec![Intelligence, PriceBlended, Value],
             Name => vec![Speed],
             ReleaseDate => vec![ReleaseDate],
         };
@@ -174,6 +223,7 @@ impl BenchmarkSortColumn {
             Self::PriceInput => entry.price_input,
             Self::PriceOutput => entry.price_output,
             Self::PriceBlended => entry.price_blended,
+            Self::Value => entry.price_per_intelligence(),
             Self::Name => Some(0.0),
             Self::ReleaseDate => entry
                 .release_date
@@ -478,6 +528,43 @@ pub enum BottomView {
     Radar,
 }
 
+/// Aggregates over the selected creator's visible entries
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatorStats {
+    pub best_intelligence: Option<f64>,
+    pub avg_coding: Option<f64>,
+    /// Median blended $/M
+    pub median_price: Option<f64>,
+}
+
+impl CreatorStats {
+    fn from_entries<'a>(entries: impl Iterator<Item = &'a BenchmarkEntry>) -> Self {
+        let mut best_intelligence: Option<f64> = None;
+        let mut coding = Vec::new();
+        let mut prices = Vec::new();
+        for entry in entries {
+            if let Some(v) = entry.intelligence_index {
+                best_intelligence = Some(best_intelligence.map_or(v, |best| best.max(v)));
+            }
+            coding.extend(entry.coding_index);
+            prices.extend(entry.price_blended);
+        }
+        let avg_coding =
+            (!coding.is_empty()).then(|| coding.iter().sum::<f64>() / coding.len() as f64);
+        prices.sort_by(|a, b| a.total_cmp(b));
+        let median_price = match prices.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(prices[n / 2]),
+            n => Some((prices[n / 2 - 1] + prices[n / 2]) / 2.0),
+        };
+        Self {
+            best_intelligence,
+            avg_coding,
+            median_price,
+        }
+    }
+}
+
 pub struct BenchmarksApp {
     pub filtered_indices: Vec<usize>,
     pub selected: usize,
@@ -485,6 +572,12 @@ pub struct BenchmarksApp {
     pub focus: BenchmarkFocus,
     pub sort_column: BenchmarkSortColumn,
     pub sort_descending: bool,
+    /// Hide entries scoring below this on the sort column; cleared when the column changes
+    pub min_score: Option<f64>,
+    /// Text typed at the minimum-score prompt
+    pub min_score_input: String,
+    /// Set while a specific creator (not "All") is selected
+    pub creator_stats: Option<CreatorStats>,
     pub search_query: String,
     // Creator sidebar
     pub creator_list_items: Vec<CreatorListItem>,
@@ -504,10 +597,23 @@ pub struct BenchmarksApp {
     pub show_sort_picker: bool,
     pub sort_picker_selected: usize,
     pub loading: bool,
+    /// When the loaded benchmark data arrived from the CDN
+    pub fetched_at: Option<DateTime<Utc>>,
     pub detail_scroll: ScrollOffset,
 }
 
 impl BenchmarksApp {
+    /// Title suffix: loading state, or the age of the loaded data.
+    pub fn freshness_suffix(&self) -> String {
+        if self.loading {
+            " loading...".to_string()
+        } else if let Some(fetched_at) = self.fetched_at {
+            format!(" · updated {}", format_relative_time(&fetched_at))
+        } else {
+            String::new()
+        }
+    }
+
     pub fn new(store: &BenchmarkStore, open_weights_map: &HashMap<String, bool>) -> Self {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
@@ -522,6 +628,9 @@ impl BenchmarksApp {
             focus: BenchmarkFocus::default(),
             sort_column: BenchmarkSortColumn::default(),
             sort_descending: true,
+            min_score: None,
+            min_score_input: String::new(),
+            creator_stats: None,
             search_query: String::new(),
             creator_list_items: Vec::new(),
             selected_creator: 0,
@@ -540,6 +649,7 @@ impl BenchmarksApp {
             show_sort_picker: false,
             sort_picker_selected: 0,
             loading: true,
+            fetched_at: None,
             detail_scroll: ScrollOffset::default(),
         };
 
@@ -598,6 +708,7 @@ impl BenchmarksApp {
         &self,
         entry: &BenchmarkEntry,
         open_weights_map: &HashMap<String, bool>,
+        matcher: &SearchMatcher,
     ) -> bool {
         if !self.source_filter.matches(entry, open_weights_map) {
             return false;
@@ -606,10 +717,7 @@ impl BenchmarksApp {
             return false;
         }
         if !self.search_query.is_empty() {
-            let query_lower = self.search_query.to_lowercase();
-            return entry.name.to_lowercase().contains(&query_lower)
-                || entry.creator.to_lowercase().contains(&query_lower)
-                || entry.slug.to_lowercase().contains(&query_lower);
+            return entry_matches_query(entry, matcher);
         }
         true
     }
@@ -621,12 +729,14 @@ impl BenchmarksApp {
     ) {
         let mut info: HashMap<String, CreatorInfo> = HashMap::new();
         let filtering = self.has_active_filters();
+        let matcher = SearchMatcher::new(&self.search_query);
 
         for entry in store.entries() {
             if entry.creator.is_empty() {
                 continue;
             }
-            let passes = !filtering || self.entry_matches_filters(entry, open_weights_map);
+            let passes =
+                !filtering || self.entry_matches_filters(entry, open_weights_map, &matcher);
             info.entry(entry.creator.clone())
                 .and_modify(|i| {
                     i.count += 1;
@@ -773,7 +883,8 @@ impl BenchmarksApp {
         store: &BenchmarkStore,
         open_weights_map: &HashMap<String, bool>,
     ) {
-        let query_lower = self.search_query.to_lowercase();
+        let searching = !self.search_query.is_empty();
+        let matcher = SearchMatcher::new(&self.search_query);
         let creator_slug = self.selected_creator_slug().map(|s| s.to_owned());
         let source_filter = self.source_filter;
         let reasoning_filter = self.reasoning_filter.clone();
@@ -798,10 +909,8 @@ impl BenchmarksApp {
                     }
                 }
                 // Search filter
-                if !query_lower.is_empty() {
-                    return entry.name.to_lowercase().contains(&query_lower)
-                        || entry.creator.to_lowercase().contains(&query_lower)
-                        || entry.slug.to_lowercase().contains(&query_lower);
+                if searching {
+                    return entry_matches_query(entry, &matcher);
                 }
                 true
             })
@@ -815,6 +924,20 @@ impl BenchmarksApp {
             self.filtered_indices
                 .retain(|&i| col.extract(&entries[i]).is_some());
         }
+        if let Some(min) = self
+            .min_score
+            .filter(|_| self.sort_column.supports_threshold())
+        {
+            let col = self.sort_column;
+            let entries = store.entries();
+            self.filtered_indices
+                .retain(|&i| col.extract(&entries[i]).is_some_and(|v| v >= min));
+        }
+
+        self.creator_stats = creator_slug.is_some().then(|| {
+            let entries = store.entries();
+            CreatorStats::from_entries(self.filtered_indices.iter().map(|&i| &entries[i]))
+        });
 
         self.apply_sort(store);
 
@@ -859,6 +982,9 @@ impl BenchmarksApp {
                 BenchmarkSortColumn::PriceBlended => {
                     cmp_opt_f64(ea.price_blended, eb.price_blended)
                 }
+                BenchmarkSortColumn::Value => {
+                    cmp_opt_f64(ea.price_per_intelligence(), eb.price_per_intelligence())
+                }
                 BenchmarkSortColumn::Name => ea.name.cmp(&eb.name),
                 BenchmarkSortColumn::ReleaseDate => cmp_opt_f64(
                     ea.release_date
@@ -870,14 +996,74 @@ impl BenchmarksApp {
                 ),
             };
 
-            if desc {
-                ord.reverse()
-            } else {
-                ord
-            }
+            let ord = if desc { ord.reverse() } else { ord };
+            // Ties fall back to name, then slug, so re-sorts never reorder rows
+            ord.then_with(|| ea.name.cmp(&eb.name))
+                .then_with(|| ea.slug.cmp(&eb.slug))
         });
     }
 
+    /// Clear search, source/reasoning filters, and the minimum score, restore
+    /// `col`, and select the first entry under "All".
+    pub fn reset_view(
+        &mut self,
+        col: BenchmarkSortColumn,
+        store: &BenchmarkStore,
+        open_weights_map: &HashMap<String, bool>,
+    ) {
+        self.search_query.clear();
+        self.source_filter = SourceFilter::default();
+        self.reasoning_filter = ReasoningFilter::default();
+        self.selected_creator = 0;
+        self.rebuild_after_filter_change(store, open_weights_map);
+        self.set_sort_column(col, store, open_weights_map);
+        self.selected = 0;
+        self.list_state.select(Some(0));
+        self.reset_detail_scroll();
+    }
+
+    /// Start from a configured sort column in its default direction.
+    pub fn set_sort_column(
+        &mut self,
+        col: BenchmarkSortColumn,
+        store: &BenchmarkStore,
+        open_weights_map: &HashMap<String, bool>,
+    ) {
+        self.sort_column = col;
+        self.sort_descending = col.default_descending();
+        self.min_score = None;
+        self.update_filtered(store, open_weights_map);
+    }
+
+    /// Apply the typed threshold; blank input clears it. Returns false (and
+    /// keeps the current threshold) when the input isn't a number.
+    pub fn apply_min_score(
+        &mut self,
+        store: &BenchmarkStore,
+        open_weights_map: &HashMap<String, bool>,
+    ) -> bool {
+        let input = std::mem::take(&mut self.min_score_input);
+        let input = input.trim();
+        self.min_score = if input.is_empty() {
+            None
+        } else {
+            match input.parse::<f64>() {
+                Ok(min) if min.is_finite() => Some(min),
+                _ => return false,
+            }
+        };
+        self.selected = 0;
+        self.update_filtered(store, open_weights_map);
+        true
+    }
+
+    /// Title suffix for an active threshold, e.g. `" ≥50"`.
+    pub fn min_score_label(&self) -> String {
+        self.min_score
+            .map(|min| format!(" \u{2265}{}", min))
+            .unwrap_or_default()
+    }
+
     pub fn toggle_sort_direction(&mut self, store: &BenchmarkStore) {
         self.sort_descending = !self.sort_descending;
         self.apply_sort(store);
@@ -896,6 +1082,7 @@ impl BenchmarksApp {
         } else {
             self.sort_column = col;
             self.sort_descending = col.default_descending();
+            self.min_score = None;
             self.update_filtered(store, open_weights_map);
         }
     }
@@ -931,6 +1118,37 @@ impl BenchmarksApp {
         }
     }
 
+    pub fn select_at(&mut self, index: usize) {
+        if index < self.filtered_indices.len() && index != self.selected {
+            self.selected = index;
+            self.list_state.select(Some(self.selected));
+            self.reset_detail_scroll();
+        }
+    }
+
+    /// Search the "All" list for the store entry at `index` and select it.
+    /// Returns false when the other filters still hide it.
+    pub fn select_entry(
+        &mut self,
+        index: usize,
+        store: &BenchmarkStore,
+        open_weights_map: &HashMap<String, bool>,
+    ) -> bool {
+        let Some(entry) = store.entries().get(index) else {
+            return false;
+        };
+        self.search_query = entry.slug.clone();
+        self.rebuild(store, open_weights_map);
+        self.focus = BenchmarkFocus::List;
+        match self.filtered_indices.iter().position(|&i| i == index) {
+            Some(pos) => {
+                self.select_at(pos);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn select_first(&mut self) {
         if self.selected > 0 {
             self.selected = 0;
@@ -1052,6 +1270,12 @@ impl BenchmarksApp {
         }
     }
 
+    pub fn select_creator_at(&mut self, index: usize) {
+        if index < self.creator_list_items.len() {
+            self.skip_to_selectable(index, true);
+        }
+    }
+
     pub fn select_first_creator(&mut self) {
         self.skip_to_selectable(0, true);
     }
@@ -1171,3 +1395,106 @@ impl BenchmarksApp {
         self.h2h_scroll.decrement(page as u16);
     }
 }
+
+/// Search match on name, slug, and creator (slug or display name).
+fn entry_matches_query(entry: &BenchmarkEntry, matcher: &SearchMatcher) -> bool {
+    matcher.is_match(&entry.name)
+        || matcher.is_match(&entry.creator)
+        || matcher.is_match(&entry.creator_name)
+        || matcher.is_match(&entry.slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creator_stats_aggregate_entries() {
+        let entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "A", "slug": "a", "intelligence_index": 60.0, "coding_index": 40.0, "price_blended": 3.0 },
+            { "name": "B", "slug": "b", "intelligence_index": 72.5, "coding_index": 50.0, "price_blended": 1.0 },
+            { "name": "C", "slug": "c", "price_blended": 2.0 },
+            { "name": "D", "slug": "d", "price_blended": 10.0 },
+        ]))
+        .unwrap();
+        let stats = CreatorStats::from_entries(entries.iter());
+        assert_eq!(stats.best_intelligence, Some(72.5));
+        assert_eq!(stats.avg_coding, Some(45.0));
+        assert_eq!(stats.median_price, Some(2.5));
+
+        let empty = CreatorStats::from_entries(std::iter::empty());
+        assert_eq!(empty.median_price, None);
+        assert_eq!(empty.avg_coding, None);
+    }
+
+    #[test]
+    fn apply_sort_breaks_ties_by_name() {
+        let entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "Beta", "slug": "beta", "release_date": "2025-01-01", "intelligence_index": 50.0 },
+            { "name": "Alpha", "slug": "alpha", "release_date": "2025-01-01", "intelligence_index": 50.0 },
+            { "name": "Gamma", "slug": "gamma", "release_date": "2025-01-01", "intelligence_index": 60.0 },
+        ]))
+        .unwrap();
+        let store = BenchmarkStore::from_entries(entries);
+        let mut app = BenchmarksApp::new(&store, &HashMap::new());
+        app.sort_column = BenchmarkSortColumn::Intelligence;
+        app.sort_descending = true;
+
+        app.filtered_indices = vec![0, 1, 2];
+        app.apply_sort(&store);
+        let first = app.filtered_indices.clone();
+        app.filtered_indices = vec![2, 1, 0];
+        app.apply_sort(&store);
+        assert_eq!(first, vec![2, 1, 0]);
+        assert_eq!(app.filtered_indices, first);
+    }
+
+    #[test]
+    fn value_sort_ranks_cheapest_per_intelligence_point_first() {
+        let entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "Pricey", "slug": "pricey", "intelligence_index": 70.0, "price_blended": 14.0 },
+            { "name": "Cheap", "slug": "cheap", "intelligence_index": 50.0, "price_blended": 1.0 },
+            { "name": "Unpriced", "slug": "unpriced", "intelligence_index": 60.0 },
+        ]))
+        .unwrap();
+        let store = BenchmarkStore::from_entries(entries);
+        let mut app = BenchmarksApp::new(&store, &HashMap::new());
+        app.sort_column = BenchmarkSortColumn::Value;
+        app.sort_descending = BenchmarkSortColumn::Value.default_descending();
+        app.filtered_indices = vec![0, 1];
+        app.apply_sort(&store);
+        assert_eq!(app.filtered_indices, vec![1, 0]);
+
+        assert_eq!(store.entries()[2].price_per_intelligence(), None);
+        let cols = BenchmarkSortColumn::Value.visible_columns();
+        assert!(cols.ends_with(&[
+            BenchmarkSortColumn::PriceBlended,
+            BenchmarkSortColumn::Value
+        ]));
+        assert!(cols.contains(&BenchmarkSortColumn::Intelligence));
+    }
+
+    #[test]
+    fn search_matches_creator_display_name() {
+        let mut entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "OLMo 2", "slug": "olmo-2", "release_date": "2025-05-01", "intelligence_index": 60.0 },
+            { "name": "GPT-5", "slug": "gpt-5", "release_date": "2025-08-01", "intelligence_index": 70.0 },
+        ]))
+        .unwrap();
+        entries[0].creator = "ai2".to_string();
+        entries[0].creator_name = "Allen Institute for AI".to_string();
+        entries[1].creator = "openai".to_string();
+        entries[1].creator_name = "OpenAI".to_string();
+        let store = BenchmarkStore::from_entries(entries);
+
+        let mut app = BenchmarksApp::new(&store, &HashMap::new());
+        app.search_query = "Allen Inst".to_string();
+        app.update_filtered(&store, &HashMap::new());
+        let names: Vec<&str> = app
+            .filtered_indices
+            .iter()
+            .map(|&i| store.entries()[i].name.as_str())
+            .collect();
+        assert_eq!(names, vec!["OLMo 2"]);
+    }
+}
@@ -9,8 +9,9 @@ use ratatui::{
 use super::compare::{draw_h2h_table_generic, draw_scatter};
 use crate::formatting::format_tokens;
 use crate::formatting::truncate;
-use crate::tui::app::App;
-use crate::tui::ui::{caret, centered_rect, centered_rect_fixed, focus_border};
+use crate::formatting::EM_DASH;
+use crate::tui::app::{App, ListHitArea, ListRegion};
+use crate::tui::ui::{caret, centered_rect, centered_rect_fixed, focus_border, highlight_matches};
 use crate::tui::widgets::scrollable_panel::ScrollablePanel;
 
 /// Color palette for selected models in comparison mode.
@@ -115,6 +116,36 @@ fn draw_benchmark_subtab_bar(f: &mut Frame, area: Rect, bench_app: &super::app::
     f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
+fn creator_stats_lines(stats: &super::app::CreatorStats) -> Vec<Line<'static>> {
+    let stat = |label: &'static str, value: Option<String>| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Gray)),
+            match value {
+                Some(v) => Span::raw(v),
+                None => Span::styled(EM_DASH, Style::default().fg(Color::DarkGray)),
+            },
+        ])
+    };
+    vec![
+        Line::from(Span::styled(
+            "\u{2500}\u{2500} Creator",
+            Style::default().fg(Color::DarkGray),
+        )),
+        stat(
+            "Best Intel  ",
+            stats.best_intelligence.map(|v| format!("{:.1}", v)),
+        ),
+        stat(
+            "Avg Code    ",
+            stats.avg_coding.map(|v| format!("{:.1}", v)),
+        ),
+        stat(
+            "Median $/M  ",
+            stats.median_price.map(|v| format!("${:.2}", v)),
+        ),
+    ]
+}
+
 fn draw_benchmark_creators(f: &mut Frame, area: Rect, app: &mut App) {
     use super::app::{
         BenchmarkFocus, CreatorGrouping, CreatorListItem, CreatorRegion, CreatorType,
@@ -168,12 +199,26 @@ fn draw_benchmark_creators(f: &mut Frame, area: Rect, app: &mut App) {
         Span::raw("Type"),
     ]);
 
+    // Selected creator: aggregate stats pinned under the list
+    let stats_lines = bench_app
+        .creator_stats
+        .as_ref()
+        .map(creator_stats_lines)
+        .unwrap_or_default();
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(stats_lines.len() as u16),
+        ])
         .split(inner_area);
 
     f.render_widget(Paragraph::new(filter_line), chunks[0]);
+    if !stats_lines.is_empty() {
+        f.render_widget(Paragraph::new(stats_lines), chunks[2]);
+    }
 
     // Available width for creator items (inner area minus highlight symbol "  " or "> ")
     let item_width = inner_area.width.saturating_sub(2) as usize;
@@ -256,6 +301,12 @@ fn draw_benchmark_creators(f: &mut Frame, area: Rect, app: &mut App) {
 
     let mut state = bench_app.creator_list_state;
     f.render_stateful_widget(list, chunks[1], &mut state);
+    app.list_areas.push(ListHitArea {
+        region: ListRegion::BenchmarkCreators,
+        area: chunks[1],
+        offset: state.offset(),
+        header_rows: 0,
+    });
 }
 
 /// Compact list for compare mode: selection marker + name only, full height.
@@ -273,7 +324,12 @@ fn draw_benchmark_list_compact(f: &mut Frame, area: Rect, app: &mut App) {
     } else {
         "\u{2191}"
     };
-    let sort_indicator = format!(" {}{}", sort_dir, bench_app.sort_column.label());
+    let sort_indicator = format!(
+        " {}{}{}",
+        sort_dir,
+        bench_app.sort_column.label(),
+        bench_app.min_score_label()
+    );
 
     let source_indicator = match bench_app.source_filter {
         super::app::SourceFilter::All => String::new(),
@@ -289,7 +345,7 @@ fn draw_benchmark_list_compact(f: &mut Frame, area: Rect, app: &mut App) {
         }
     };
 
-    let loading_suffix = if bench_app.loading { " loading..." } else { "" };
+    let loading_suffix = bench_app.freshness_suffix();
 
     let title = if bench_app.search_query.is_empty() {
         format!(
@@ -320,6 +376,11 @@ fn draw_benchmark_list_compact(f: &mut Frame, area: Rect, app: &mut App) {
     f.render_widget(outer_block, area);
 
     let caret = caret(is_focused);
+    let search_terms: Vec<String> = if bench_app.search_query.is_empty() {
+        Vec::new()
+    } else {
+        vec![bench_app.search_query.clone()]
+    };
     let entries = store.entries();
 
     // Extra columns: marker(2) + caret(2) + reasoning(3) + source(2) + optional region/type
@@ -391,8 +452,9 @@ fn draw_benchmark_list_compact(f: &mut Frame, area: Rect, app: &mut App) {
                 ));
             }
 
-            row_spans.push(Span::styled(
-                truncate(&entry.display_name, name_width),
+            row_spans.extend(highlight_matches(
+                &truncate(&entry.display_name, name_width),
+                &search_terms,
                 style,
             ));
             ListItem::new(Line::from(row_spans))
@@ -410,6 +472,12 @@ fn draw_benchmark_list_compact(f: &mut Frame, area: Rect, app: &mut App) {
     let mut state = bench_app.list_state;
     state.select(Some(bench_app.selected));
     f.render_stateful_widget(list, inner_area, &mut state);
+    app.list_areas.push(ListHitArea {
+        region: ListRegion::Benchmarks,
+        area: inner_area,
+        offset: state.offset(),
+        header_rows: 0,
+    });
 }
 
 fn draw_benchmark_list(f: &mut Frame, area: Rect, app: &mut App) {
@@ -426,7 +494,12 @@ fn draw_benchmark_list(f: &mut Frame, area: Rect, app: &mut App) {
     } else {
         "\u{2191}"
     };
-    let sort_indicator = format!(" {}{}", sort_dir, bench_app.sort_column.label());
+    let sort_indicator = format!(
+        " {}{}{}",
+        sort_dir,
+        bench_app.sort_column.label(),
+        bench_app.min_score_label()
+    );
 
     let source_indicator = match bench_app.source_filter {
         super::app::SourceFilter::All => String::new(),
@@ -443,7 +516,7 @@ fn draw_benchmark_list(f: &mut Frame, area: Rect, app: &mut App) {
     };
 
     let creator_label = bench_app.selected_creator_name().unwrap_or("Benchmarks");
-    let loading_suffix = if bench_app.loading { " loading..." } else { "" };
+    let loading_suffix = bench_app.freshness_suffix();
 
     let title = if bench_app.search_query.is_empty() {
         format!(
@@ -497,6 +570,11 @@ fn draw_benchmark_list(f: &mut Frame, area: Rect, app: &mut App) {
 
     // Caret prefix for focused panel
     let caret = caret(is_focused);
+    let search_terms: Vec<String> = if bench_app.search_query.is_empty() {
+        Vec::new()
+    } else {
+        vec![bench_app.search_query.clone()]
+    };
 
     let header_style = Style::default()
         .fg(Color::Yellow)
@@ -590,11 +668,14 @@ fn draw_benchmark_list(f: &mut Frame, area: Rect, app: &mut App) {
             ));
         }
 
-        row_spans.extend(
-            visible_cols
-                .iter()
-                .map(|col| benchmark_col_value(entry, *col, style, name_width)),
-        );
+        for col in visible_cols.iter() {
+            let span = benchmark_col_value(entry, *col, style, name_width);
+            if matches!(col, super::app::BenchmarkSortColumn::Name) {
+                row_spans.extend(highlight_matches(&span.content, &search_terms, style));
+            } else {
+                row_spans.push(span);
+            }
+        }
         items.push(ListItem::new(Line::from(row_spans)));
     }
 
@@ -603,6 +684,12 @@ fn draw_benchmark_list(f: &mut Frame, area: Rect, app: &mut App) {
     // Offset by 1 for the header row
     state.select(Some(bench_app.selected + 1));
     f.render_stateful_widget(list, inner_area, &mut state);
+    app.list_areas.push(ListHitArea {
+        region: ListRegion::Benchmarks,
+        area: inner_area,
+        offset: state.offset(),
+        header_rows: 1,
+    });
 }
 
 fn draw_benchmark_detail(f: &mut Frame, area: Rect, app: &App) {
@@ -1010,6 +1097,14 @@ fn fmt_col_price(value: Option<f64>) -> String {
     }
 }
 
+/// Format a $/Intel-point ratio for a list column; ratios are often well under $1
+fn fmt_col_value(value: Option<f64>) -> String {
+    match value {
+        Some(v) => format!("{:>8.3}$", v),
+        None => format!("{:>9}", "\u{2014}"),
+    }
+}
+
 /// Format a price value
 fn fmt_price(value: Option<f64>) -> String {
     match value {
@@ -1062,7 +1157,7 @@ fn benchmark_col_width(col: super::app::BenchmarkSortColumn) -> u16 {
     match col {
         Name => 0, // dynamic
         Speed | Ttft | Ttfat => 7,
-        PriceInput | PriceOutput | PriceBlended => 9,
+        PriceInput | PriceOutput | PriceBlended | Value => 9,
         ReleaseDate => 11,
         _ => 6, // all index/percentage columns
     }
@@ -1095,6 +1190,7 @@ fn benchmark_col_header(
         PriceInput => Span::styled(format!("{:>9}", "In $/M"), style),
         PriceOutput => Span::styled(format!("{:>9}", "Out $/M"), style),
         PriceBlended => Span::styled(format!("{:>9}", "Bld $/M"), style),
+        Value => Span::styled(format!("{:>9}", "$/Intel"), style),
         ReleaseDate => Span::styled(format!("{:>11}", "Released"), style),
     }
 }
@@ -1134,10 +1230,31 @@ fn benchmark_col_value<'a>(
         PriceInput => Span::styled(fmt_col_price(entry.price_input), style),
         PriceOutput => Span::styled(fmt_col_price(entry.price_output), style),
         PriceBlended => Span::styled(fmt_col_price(entry.price_blended), style),
+        Value => Span::styled(fmt_col_value(entry.price_per_intelligence()), style),
         ReleaseDate => Span::styled(fmt_col_date(entry.release_date.as_deref()), style),
     }
 }
 
+/// Unpadded header and cell text for a list column, for copying rows out of
+/// the table. Missing values are empty rather than `—`.
+pub(in crate::tui) fn benchmark_col_text(
+    entry: Option<&crate::benchmarks::BenchmarkEntry>,
+    col: super::app::BenchmarkSortColumn,
+) -> String {
+    use super::app::BenchmarkSortColumn::Name;
+    let text = match (entry, col) {
+        (None, _) => benchmark_col_header(col, Style::default(), 0).content,
+        (Some(entry), Name) => return entry.display_name.clone(),
+        (Some(entry), _) => benchmark_col_value(entry, col, Style::default(), 0).content,
+    };
+    let text = text.trim();
+    if text == "\u{2014}" {
+        String::new()
+    } else {
+        text.to_string()
+    }
+}
+
 fn draw_sort_picker(f: &mut Frame, area: Rect, bench_app: &super::app::BenchmarksApp) {
     use super::app::BenchmarkSortColumn;
 
@@ -0,0 +1,255 @@
+//! Clipboard writes for copy actions, with an OSC 52 fallback for sessions
+//! where the system clipboard is unreachable (SSH, WSL, headless servers).
+//! `display.clipboard_backend` can pin a backend when arboard picks the wrong
+//! one (Wayland vs X11), and `display.clipboard_verify` reads each copy back
+//! for setups where a write reports success but doesn't land.
+
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::sync::{mpsc, OnceLock};
+use std::time::Duration;
+
+use crate::config::ClipboardBackend;
+
+static BACKEND: OnceLock<ClipboardBackend> = OnceLock::new();
+static VERIFY: OnceLock<bool> = OnceLock::new();
+
+/// How long to wait for the clipboard thread to report whether `set_text` worked.
+const CLIPBOARD_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How long the clipboard thread stays alive so other apps can read it on Linux.
+const KEEPALIVE: Duration = Duration::from_secs(2);
+
+/// Where a copy ended up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyOutcome {
+    /// Written to the system clipboard
+    Clipboard,
+    /// Sent to the terminal as an OSC 52 escape sequence
+    Osc52,
+    /// Written to the system clipboard, but reading it back (with
+    /// `clipboard_verify` on) returned something else
+    Unverified,
+    /// Neither worked; the caller should show the text instead
+    Failed,
+}
+
+/// Install the configured backend. Called once at TUI startup.
+pub fn set_backend(backend: ClipboardBackend) {
+    let _ = BACKEND.set(backend);
+}
+
+/// Turn on read-back verification. Called once at TUI startup.
+pub fn set_verify(verify: bool) {
+    let _ = VERIFY.set(verify);
+}
+
+/// Copy `text` to the system clipboard with the configured backend. Blocks
+/// for up to `CLIPBOARD_TIMEOUT` (twice that when verifying), so run it off
+/// the UI thread. `Failed` means the caller should try [`copy_osc52`].
+pub fn copy_system(text: &str) -> CopyOutcome {
+    let backend = BACKEND.get().copied().unwrap_or_default();
+    let copied = match backend {
+        ClipboardBackend::Auto => copy_arboard(text.to_string()),
+        ClipboardBackend::Wayland => copy_command("wl-copy", &[], text),
+        ClipboardBackend::X11 => copy_command("xclip", &["-selection", "clipboard"], text),
+        ClipboardBackend::Osc52 => false,
+    };
+    if !copied {
+        return CopyOutcome::Failed;
+    }
+    let verify = VERIFY.get().copied().unwrap_or(false);
+    if verify && read_back(backend).as_deref() != Some(text) {
+        return CopyOutcome::Unverified;
+    }
+    CopyOutcome::Clipboard
+}
+
+/// Send `text` to the terminal as an OSC 52 escape. This writes to stdout,
+/// so call it from the UI thread between frames.
+pub fn copy_osc52(text: &str) -> CopyOutcome {
+    if osc52_available() && write_osc52(text).is_ok() {
+        CopyOutcome::Osc52
+    } else {
+        CopyOutcome::Failed
+    }
+}
+
+/// Whether copies with `backend` can work here, for `models doctor`: what
+/// will be used, or why nothing will. Nothing is written to the clipboard.
+pub fn diagnose(backend: ClipboardBackend) -> Result<String, String> {
+    let osc52_fallback = |reason: String| {
+        if osc52_available() {
+            Ok(format!("{reason}; falling back to OSC 52"))
+        } else {
+            Err(format!("{reason}; OSC 52 needs a terminal on stdout"))
+        }
+    };
+    let tool = |program: &str| {
+        if on_path(program) {
+            Ok(format!("{program} on PATH"))
+        } else {
+            osc52_fallback(format!("{program} not found on PATH"))
+        }
+    };
+    match backend {
+        ClipboardBackend::Auto => match arboard::Clipboard::new() {
+            Ok(_) => Ok("available".to_string()),
+            Err(err) => osc52_fallback(err.to_string()),
+        },
+        ClipboardBackend::Wayland => tool("wl-copy"),
+        ClipboardBackend::X11 => tool("xclip"),
+        ClipboardBackend::Osc52 if osc52_available() => Ok("OSC 52".to_string()),
+        ClipboardBackend::Osc52 => Err("OSC 52 needs a terminal on stdout".to_string()),
+    }
+}
+
+fn on_path(program: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| dir.join(program).is_file()))
+}
+
+/// Pipe `text` into a clipboard tool. `wl-copy` and `xclip` fork to serve
+/// the selection themselves, so no keepalive thread is needed.
+fn copy_command(program: &str, args: &[&str], text: &str) -> bool {
+    let Ok(mut child) = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return false;
+    };
+    let written = child
+        .stdin
+        .take()
+        .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+    child.wait().is_ok_and(|status| status.success()) && written
+}
+
+/// The clipboard's current text from the same backend that wrote it; `None`
+/// when it can't be read. OSC 52 is write-only.
+fn read_back(backend: ClipboardBackend) -> Option<String> {
+    match backend {
+        ClipboardBackend::Auto => {
+            let (tx, rx) = mpsc::channel();
+            std::thread::spawn(move || {
+                let text = arboard::Clipboard::new().and_then(|mut c| c.get_text());
+                let _ = tx.send(text.ok());
+            });
+            rx.recv_timeout(CLIPBOARD_TIMEOUT).ok().flatten()
+        }
+        ClipboardBackend::Wayland => read_command("wl-paste", &["--no-newline"]),
+        ClipboardBackend::X11 => read_command("xclip", &["-selection", "clipboard", "-o"]),
+        ClipboardBackend::Osc52 => None,
+    }
+}
+
+fn read_command(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8(output.stdout).ok())
+        .flatten()
+}
+
+/// Set the system clipboard from a thread that outlives the call: on Linux the
+/// clipboard is selection-based and needs the source app to stay alive.
+fn copy_arboard(text: String) -> bool {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let Ok(mut clipboard) = arboard::Clipboard::new() else {
+            let _ = tx.send(false);
+            return;
+        };
+        let ok = clipboard.set_text(&text).is_ok();
+        let _ = tx.send(ok);
+        if ok {
+            std::thread::sleep(KEEPALIVE);
+        }
+    });
+    rx.recv_timeout(CLIPBOARD_TIMEOUT).unwrap_or(false)
+}
+
+/// OSC 52 needs a real terminal on stdout; `dumb` and the Linux console
+/// ignore it.
+fn osc52_available() -> bool {
+    std::io::stdout().is_terminal()
+        && !matches!(std::env::var("TERM").as_deref(), Ok("dumb" | "linux"))
+}
+
+fn write_osc52(text: &str) -> std::io::Result<()> {
+    let inside_tmux = std::env::var_os("TMUX").is_some();
+    let mut stdout = std::io::stdout();
+    stdout.write_all(osc52_sequence(text, inside_tmux).as_bytes())?;
+    stdout.flush()
+}
+
+/// `ESC ] 52 ; c ; <base64> BEL`, wrapped in a DCS passthrough under tmux.
+fn osc52_sequence(text: &str, inside_tmux: bool) -> String {
+    let osc = format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()));
+    if inside_tmux {
+        format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+    } else {
+        osc
+    }
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_pads_partial_chunks() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"openai/gpt-4o"), "b3BlbmFpL2dwdC00bw==");
+    }
+
+    #[test]
+    fn missing_clipboard_tool_reports_failure() {
+        assert!(!copy_command("models-no-such-clipboard-tool", &[], "text"));
+        assert_eq!(read_command("models-no-such-clipboard-tool", &[]), None);
+        assert_eq!(read_back(ClipboardBackend::Osc52), None);
+        assert!(!on_path("models-no-such-clipboard-tool"));
+    }
+
+    #[test]
+    fn osc52_sequence_wraps_for_tmux() {
+        assert_eq!(osc52_sequence("foo", false), "\x1b]52;c;Zm9v\x07");
+        assert_eq!(
+            osc52_sequence("foo", true),
+            "\x1bPtmux;\x1b\x1b]52;c;Zm9v\x07\x1b\\"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Cross-tab comparison buffer: `+` in the Models or Benchmarks tab adds the
+//! selected item, `V` shows every buffered item side by side with models.dev
+//! details and benchmark scores merged.
+
+use crate::benchmarks::{find_entry_for_model, find_model_for_entry, BenchmarkEntry};
+use crate::data::{Model, Provider};
+use crate::formatting::EM_DASH;
+
+/// Items the buffer holds; more columns than this don't fit side by side.
+pub const MAX_COMPARISON: usize = 6;
+
+/// A buffered model or benchmark entry, with the other side filled in when
+/// the cross-tab matcher finds it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparisonItem {
+    /// `provider/model` key of the models.dev model
+    pub model_key: Option<String>,
+    /// Slug of the benchmark entry
+    pub benchmark_slug: Option<String>,
+}
+
+impl ComparisonItem {
+    /// A Models tab selection, matched to its benchmark entry.
+    pub fn from_model(entries: &[BenchmarkEntry], provider_id: &str, model_id: &str) -> Self {
+        Self {
+            model_key: Some(format!("{provider_id}/{model_id}")),
+            benchmark_slug: find_entry_for_model(entries, provider_id, model_id)
+                .map(|found| entries[found.index].slug.clone()),
+        }
+    }
+
+    /// A Benchmarks tab selection, matched to its models.dev model.
+    pub fn from_entry(providers: &[(String, Provider)], entry: &BenchmarkEntry) -> Self {
+        Self {
+            model_key: find_model_for_entry(providers, entry)
+                .map(|(provider_id, model_id)| format!("{provider_id}/{model_id}")),
+            benchmark_slug: Some(entry.slug.clone()),
+        }
+    }
+
+    /// Whether both refer to the same model or the same benchmark entry.
+    pub fn same_as(&self, other: &Self) -> bool {
+        let same = |a: &Option<String>, b: &Option<String>| a.is_some() && a == b;
+        same(&self.model_key, &other.model_key) || same(&self.benchmark_slug, &other.benchmark_slug)
+    }
+
+    fn model<'a>(&self, providers: &'a [(String, Provider)]) -> Option<&'a Model> {
+        let (provider_id, model_id) = self.model_key.as_deref()?.split_once('/')?;
+        providers
+            .iter()
+            .find(|(id, _)| id == provider_id)?
+            .1
+            .models
+            .get(model_id)
+    }
+
+    fn entry<'a>(&self, entries: &'a [BenchmarkEntry]) -> Option<&'a BenchmarkEntry> {
+        let slug = self.benchmark_slug.as_deref()?;
+        entries.iter().find(|entry| entry.slug == slug)
+    }
+
+    /// Column heading: the model's `provider/model` key, else the benchmark name.
+    pub fn label(&self, entries: &[BenchmarkEntry]) -> String {
+        self.model_key
+            .clone()
+            .or_else(|| self.entry(entries).map(|entry| entry.display_name.clone()))
+            .unwrap_or_else(|| EM_DASH.to_string())
+    }
+}
+
+/// One row per metric: its label and a cell per item, `—` where an item has
+/// no model or entry behind it or the value is missing.
+pub fn comparison_rows(
+    items: &[ComparisonItem],
+    providers: &[(String, Provider)],
+    entries: &[BenchmarkEntry],
+) -> Vec<(&'static str, Vec<String>)> {
+    let resolved: Vec<_> = items
+        .iter()
+        .map(|item| (item.model(providers), item.entry(entries)))
+        .collect();
+    let model_row = |label: &'static str, cell: fn(&Model) -> String| {
+        let cells = resolved
+            .iter()
+            .map(|(model, _)| model.map_or_else(|| EM_DASH.to_string(), cell))
+            .collect();
+        (label, cells)
+    };
+    let entry_row = |label: &'static str,
+                     value: fn(&BenchmarkEntry) -> Option<f64>,
+                     render: fn(f64) -> String| {
+        let cells = resolved
+            .iter()
+            .map(|(_, entry)| {
+                entry
+                    .and_then(value)
+                    .map_or_else(|| EM_DASH.to_string(), render)
+            })
+            .collect();
+        (label, cells)
+    };
+    let index = |v: f64| format!("{v:.1}");
+    let pct = |v: f64| format!("{:.1}%", v * 100.0);
+
+    vec![
+        model_row("Name", |m| m.name.clone()),
+        model_row("Context", Model::context_str),
+        model_row("Max Output", Model::output_str),
+        model_row("Input", |m| {
+            Model::cost_short(m.cost.as_ref().and_then(|c| c.input))
+        }),
+        model_row("Output", |m| {
+            Model::cost_short(m.cost.as_ref().and_then(|c| c.output))
+        }),
+        model_row("Released", |m| {
+            m.release_date
+                .clone()
+                .unwrap_or_else(|| EM_DASH.to_string())
+        }),
+        model_row("Capabilities", Model::capabilities_str),
+        entry_row("Intelligence", |e| e.intelligence_index, index),
+        entry_row("Coding", |e| e.coding_index, index),
+        entry_row("Math", |e| e.math_index, index),
+        entry_row("GPQA", |e| e.gpqa, pct),
+        entry_row("HLE", |e| e.hle, pct),
+        entry_row("Speed", |e| e.output_tps, |v| format!("{v:.0} tok/s")),
+        entry_row("TTFT", |e| e.ttft, |v| format!("{v:.2}s")),
+        entry_row(
+            "AA Blended $/M",
+            |e| e.price_blended,
+            |v| format!("${v:.2}"),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ProvidersMap;
+
+    #[test]
+    fn items_match_across_tabs_and_merge_rows() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "openai": {
+                "id": "openai",
+                "name": "OpenAI",
+                "models": {
+                    "gpt-4o": { "id": "gpt-4o", "name": "GPT-4o", "limit": { "context": 128000, "output": 16384 } }
+                }
+            }
+        }))
+        .unwrap();
+        let providers: Vec<(String, Provider)> = providers.into_iter().collect();
+        let mut entries: Vec<BenchmarkEntry> = serde_json::from_value(serde_json::json!([
+            { "name": "GPT-4o", "slug": "gpt-4o", "intelligence_index": 40.0 },
+            { "name": "Other", "slug": "other", "coding_index": 30.0 },
+        ]))
+        .unwrap();
+        entries[0].creator = "openai".to_string();
+        let store = crate::benchmarks::BenchmarkStore::from_entries(entries);
+        let entries = store.entries();
+
+        let from_model = ComparisonItem::from_model(entries, "openai", "gpt-4o");
+        let from_entry = ComparisonItem::from_entry(&providers, &entries[0]);
+        assert_eq!(from_model, from_entry);
+        let unmatched = ComparisonItem::from_entry(&providers, &entries[1]);
+        assert!(!unmatched.same_as(&from_model));
+        assert_eq!(unmatched.label(entries), "Other");
+
+        let rows = comparison_rows(&[from_model, unmatched], &providers, entries);
+        let row = |label: &str| rows.iter().find(|(l, _)| *l == label).unwrap().1.clone();
+        assert_eq!(row("Name"), ["GPT-4o", EM_DASH]);
+        assert_eq!(row("Intelligence"), ["40.0", EM_DASH]);
+        assert_eq!(row("Coding"), [EM_DASH, "30.0"]);
+    }
+}
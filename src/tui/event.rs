@@ -1,9 +1,12 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 
-use super::app::{App, Message, Mode};
+use super::app::{App, CountPrefix, Message, Mode};
+use super::keymap;
 use super::models::Focus;
 
 /// Shared navigation actions across all tabs
@@ -42,34 +45,237 @@ fn parse_nav_key(code: KeyCode, modifiers: KeyModifiers) -> Option<NavAction> {
     }
 }
 
-pub fn handle_events(app: &App) -> Result<Option<Message>> {
+/// How long a lone digit or `g` waits for its follow-up key before running its own binding.
+const PREFIX_TIMEOUT: Duration = Duration::from_millis(400);
+
+/// Upper bound on a count prefix, so `99999j` can't stall the event loop.
+const MAX_COUNT: usize = 999;
+
+pub fn handle_events(app: &mut App) -> Result<Vec<Message>> {
     if event::poll(Duration::from_millis(100))? {
-        if let Event::Key(key) = event::read()? {
+        let key = match event::read()? {
+            Event::Key(key) => Some(key),
+            Event::Mouse(mouse) => return Ok(handle_mouse(app, mouse).into_iter().collect()),
+            Event::Resize(..) => {
+                // Returning straight away lets `run_app` redraw at the new size
+                // instead of first running the prefix timeouts below
+                return Ok(Vec::new());
+            }
+            _ => None,
+        };
+        if let Some(key) = key {
             if key.kind != KeyEventKind::Press {
-                return Ok(None);
+                return Ok(Vec::new());
             }
 
-            // When help is showing, handle scroll and dismiss keys
+            // Only quitting is meaningful until provider data has loaded
+            if app.providers_loading.is_some() {
+                let quit = matches!(key.code, KeyCode::Char('q'))
+                    || (key.code == KeyCode::Char('c')
+                        && key.modifiers.contains(KeyModifiers::CONTROL));
+                return Ok(quit.then_some(Message::Quit).into_iter().collect());
+            }
+
+            // When help is showing, typing filters it; arrows scroll, Esc
+            // clears the filter before closing
             if app.show_help {
                 let msg = match key.code {
+                    KeyCode::Esc if !app.help_filter.is_empty() => Some(Message::ClearHelpFilter),
                     KeyCode::Char('?') | KeyCode::Esc => Some(Message::ToggleHelp),
-                    KeyCode::Char('j') | KeyCode::Down => Some(Message::ScrollHelpDown),
-                    KeyCode::Char('k') | KeyCode::Up => Some(Message::ScrollHelpUp),
+                    KeyCode::Down => Some(Message::ScrollHelpDown),
+                    KeyCode::Up => Some(Message::ScrollHelpUp),
+                    KeyCode::Backspace => Some(Message::HelpFilterBackspace),
+                    KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        Some(Message::HelpFilterInput(c))
+                    }
                     _ => None,
                 };
-                return Ok(msg);
+                return Ok(msg.into_iter().collect());
             }
 
-            let msg = match app.mode {
-                Mode::Normal => handle_normal_mode(app, key.code, key.modifiers),
-                Mode::Search => handle_search_mode(key.code),
+            if app.show_status_log {
+                let msg = match key.code {
+                    KeyCode::Char('L') | KeyCode::Esc => Some(Message::ToggleStatusLog),
+                    KeyCode::Char('j') | KeyCode::Down => Some(Message::ScrollStatusLogDown),
+                    KeyCode::Char('k') | KeyCode::Up => Some(Message::ScrollStatusLogUp),
+                    _ => None,
+                };
+                return Ok(msg.into_iter().collect());
+            }
+
+            if app.show_comparison {
+                let msg = match key.code {
+                    KeyCode::Char('V') | KeyCode::Esc => Some(Message::ToggleComparison),
+                    KeyCode::Char('j') | KeyCode::Down => Some(Message::ScrollComparisonDown),
+                    KeyCode::Char('k') | KeyCode::Up => Some(Message::ScrollComparisonUp),
+                    KeyCode::Char('c') => Some(Message::ClearComparison),
+                    _ => None,
+                };
+                return Ok(msg.into_iter().collect());
+            }
+
+            let msgs = match app.mode {
+                Mode::Normal => handle_counted_normal_mode(app, key.code, key.modifiers),
+                Mode::Search => handle_search_mode(key.code).into_iter().collect(),
+                Mode::MinScore => handle_min_score_mode(key.code).into_iter().collect(),
+                Mode::Palette => handle_palette_mode(key.code, key.modifiers)
+                    .into_iter()
+                    .collect(),
             };
 
-            return Ok(msg);
+            return Ok(msgs);
+        }
+    }
+
+    // No key arrived: a `g` or lone digit that timed out runs its own binding
+    if app
+        .pending_g
+        .is_some_and(|started| started.elapsed() >= PREFIX_TIMEOUT)
+    {
+        app.pending_g = None;
+        return Ok(vec![Message::SelectFirstProvider]);
+    }
+    if app
+        .count_prefix
+        .as_ref()
+        .is_some_and(|p| p.started.elapsed() >= PREFIX_TIMEOUT)
+    {
+        return Ok(flush_count_prefix(app).into_iter().collect());
+    }
+
+    Ok(Vec::new())
+}
+
+/// Map a click or wheel notch to the list under the cursor.
+fn handle_mouse(app: &mut App, mouse: MouseEvent) -> Option<Message> {
+    if app.providers_loading.is_some()
+        || app.show_help
+        || app.show_status_log
+        || app.show_comparison
+        || app.mode != Mode::Normal
+        || has_modal(app)
+    {
+        return None;
+    }
+    let hit = app
+        .list_areas
+        .iter()
+        .find(|hit| hit.contains(mouse.column, mouse.row))?;
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let index = hit.row_at(mouse.column, mouse.row)?;
+            Some(Message::ClickListRow(hit.region, index))
+        }
+        MouseEventKind::ScrollDown => Some(hit.region.step_message(true)),
+        MouseEventKind::ScrollUp => Some(hit.region.step_message(false)),
+        _ => None,
+    }
+}
+
+/// Normal-mode dispatch with vim-style count prefixes (`5j`, `10k`, `3 Ctrl+d`).
+///
+/// Digits are also filter keys on every tab, so a digit is buffered instead of
+/// dispatched. A following up/down/page motion is repeated `count` times; any
+/// other key (or `PREFIX_TIMEOUT` with no key) releases a lone buffered digit to
+/// its normal binding first. Multi-digit counts without a motion are dropped.
+fn handle_counted_normal_mode(
+    app: &mut App,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+) -> Vec<Message> {
+    if has_modal(app) {
+        return handle_normal_mode(app, code, modifiers)
+            .into_iter()
+            .collect();
+    }
+
+    // `g<letter>` jumps the provider list; `gg` (or `g` + timeout) keeps the old "first" behavior
+    if app.pending_g.take().is_some() {
+        return match code {
+            KeyCode::Char('g') => vec![Message::SelectFirstProvider],
+            KeyCode::Char(c) if c.is_ascii_alphanumeric() && modifiers.is_empty() => {
+                vec![Message::JumpToProviderLetter(c)]
+            }
+            _ => {
+                let mut msgs = vec![Message::SelectFirstProvider];
+                msgs.extend(handle_counted_normal_mode(app, code, modifiers));
+                msgs
+            }
+        };
+    }
+    if code == KeyCode::Char('g')
+        && modifiers.is_empty()
+        && app.count_prefix.is_none()
+        && app.current_tab == super::app::Tab::Models
+        && app.models_app.focus == Focus::Providers
+    {
+        app.pending_g = Some(Instant::now());
+        return Vec::new();
+    }
+
+    if let KeyCode::Char(d @ '0'..='9') = code {
+        let starts_count = d != '0' || app.count_prefix.is_some();
+        if starts_count && modifiers.is_empty() {
+            let prefix = app.count_prefix.get_or_insert_with(CountPrefix::default);
+            prefix.digits.push(d);
+            return Vec::new();
+        }
+    }
+
+    let Some(prefix) = app.count_prefix.take() else {
+        return handle_normal_mode(app, code, modifiers)
+            .into_iter()
+            .collect();
+    };
+
+    let countable = matches!(
+        parse_nav_key(code, modifiers),
+        Some(NavAction::Down | NavAction::Up | NavAction::PageDown | NavAction::PageUp)
+    );
+    if countable {
+        let count = prefix.count().clamp(1, MAX_COUNT);
+        return (0..count)
+            .filter_map(|_| handle_normal_mode(app, code, modifiers))
+            .collect();
+    }
+
+    let mut msgs = Vec::new();
+    if let Some(digit_msg) = prefix.lone_digit_message(app) {
+        msgs.push(digit_msg);
+    }
+    msgs.extend(handle_normal_mode(app, code, modifiers));
+    msgs
+}
+
+/// Release a buffered count prefix without a following key.
+fn flush_count_prefix(app: &mut App) -> Option<Message> {
+    let prefix = app.count_prefix.take()?;
+    prefix.lone_digit_message(app)
+}
+
+impl CountPrefix {
+    fn count(&self) -> usize {
+        self.digits.parse().unwrap_or(MAX_COUNT)
+    }
+
+    /// The digit's own binding, if exactly one digit was typed.
+    fn lone_digit_message(&self, app: &App) -> Option<Message> {
+        let mut chars = self.digits.chars();
+        match (chars.next(), chars.next()) {
+            (Some(d), None) => handle_normal_mode(app, KeyCode::Char(d), KeyModifiers::NONE),
+            _ => None,
         }
     }
+}
 
-    Ok(None)
+/// True when a picker modal owns the keyboard on the current tab.
+fn has_modal(app: &App) -> bool {
+    match app.current_tab {
+        super::app::Tab::Agents => app.agents_app.as_ref().is_some_and(|a| a.show_picker),
+        super::app::Tab::Status => app.status_app.as_ref().is_some_and(|a| a.show_picker),
+        super::app::Tab::Benchmarks => app.benchmarks_app.show_sort_picker,
+        super::app::Tab::Models => false,
+    }
 }
 
 fn handle_normal_mode(app: &App, code: KeyCode, modifiers: KeyModifiers) -> Option<Message> {
@@ -95,15 +301,8 @@ fn handle_normal_mode(app: &App, code: KeyCode, modifiers: KeyModifiers) -> Opti
     }
 
     // Global keys (work on any tab)
-    match code {
-        KeyCode::Char('q') => return Some(Message::Quit),
-        KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
-            return Some(Message::Quit)
-        }
-        KeyCode::Char('[') => return Some(Message::PrevTab),
-        KeyCode::Char(']') => return Some(Message::NextTab),
-        KeyCode::Char('?') => return Some(Message::ToggleHelp),
-        _ => {}
+    if let Some(msg) = keymap::lookup(keymap::GLOBAL, app, code, modifiers) {
+        return Some(msg);
     }
 
     // Tab-specific keys
@@ -158,22 +357,7 @@ fn handle_models_keys(app: &App, code: KeyCode, modifiers: KeyModifiers) -> Opti
     if let Some(action) = parse_nav_key(code, modifiers) {
         return resolve_models_nav(app, action);
     }
-    match code {
-        KeyCode::Char('c') => Some(Message::CopyFull),
-        KeyCode::Char('C') => Some(Message::CopyModelId),
-        KeyCode::Char('D') => Some(Message::CopyProviderDoc),
-        KeyCode::Char('A') => Some(Message::CopyProviderApi),
-        KeyCode::Char('o') => Some(Message::OpenProviderDoc),
-        KeyCode::Char('s') => Some(Message::CycleSort),
-        KeyCode::Char('S') => Some(Message::ToggleSortDir),
-        KeyCode::Char('1') => Some(Message::ToggleReasoning),
-        KeyCode::Char('2') => Some(Message::ToggleTools),
-        KeyCode::Char('3') => Some(Message::ToggleOpenWeights),
-        KeyCode::Char('4') => Some(Message::ToggleFree),
-        KeyCode::Char('5') => Some(Message::CycleProviderCategory),
-        KeyCode::Char('6') => Some(Message::ToggleGrouping),
-        _ => None,
-    }
+    keymap::lookup(keymap::tab_sections(app.current_tab), app, code, modifiers)
 }
 
 fn resolve_agents_nav(app: &App, action: NavAction) -> Option<Message> {
@@ -239,19 +423,7 @@ fn handle_agents_keys(app: &App, code: KeyCode, modifiers: KeyModifiers) -> Opti
     if let Some(action) = parse_nav_key(code, modifiers) {
         return resolve_agents_nav(app, action);
     }
-    match code {
-        KeyCode::Char('o') => Some(Message::OpenAgentDocs),
-        KeyCode::Char('r') => Some(Message::OpenAgentRepo),
-        KeyCode::Char('c') => Some(Message::CopyAgentName),
-        KeyCode::Char('1') => Some(Message::ToggleInstalledFilter),
-        KeyCode::Char('2') => Some(Message::ToggleCliFilter),
-        KeyCode::Char('3') => Some(Message::ToggleOpenSourceFilter),
-        KeyCode::Char('a') => Some(Message::OpenPicker),
-        KeyCode::Char('n') => Some(Message::NextSearchMatch),
-        KeyCode::Char('N') => Some(Message::PrevSearchMatch),
-        KeyCode::Char('s') => Some(Message::CycleAgentSort),
-        _ => None,
-    }
+    keymap::lookup(keymap::tab_sections(app.current_tab), app, code, modifiers)
 }
 
 fn handle_sort_picker_keys(code: KeyCode) -> Option<Message> {
@@ -329,39 +501,7 @@ fn handle_benchmarks_keys(app: &App, code: KeyCode, modifiers: KeyModifiers) ->
     if let Some(action) = parse_nav_key(code, modifiers) {
         return resolve_benchmarks_nav(app, action);
     }
-    match code {
-        KeyCode::Char('1') => Some(Message::QuickSortIntelligence),
-        KeyCode::Char('2') => Some(Message::QuickSortDate),
-        KeyCode::Char('3') => Some(Message::QuickSortSpeed),
-        KeyCode::Char('4') => Some(Message::CycleBenchmarkSource),
-        KeyCode::Char('5') => Some(Message::ToggleRegionGrouping),
-        KeyCode::Char('6') => Some(Message::ToggleTypeGrouping),
-        KeyCode::Char('7') => Some(Message::CycleReasoningFilter),
-        KeyCode::Char('s') => Some(Message::OpenSortPicker),
-        KeyCode::Char('S') => Some(Message::ToggleBenchmarkSortDir),
-        KeyCode::Char('c') if !app.selections.is_empty() => Some(Message::ClearBenchmarkSelections),
-        KeyCode::Char('o') => Some(Message::OpenBenchmarkUrl),
-        KeyCode::Char(' ') => Some(Message::ToggleBenchmarkSelection),
-        KeyCode::Char('v') if app.selections.len() >= 2 => Some(Message::CycleBenchmarkView),
-        KeyCode::Char('x')
-            if app.benchmarks_app.bottom_view == super::benchmarks::BottomView::Scatter =>
-        {
-            Some(Message::CycleScatterX)
-        }
-        KeyCode::Char('y')
-            if app.benchmarks_app.bottom_view == super::benchmarks::BottomView::Scatter =>
-        {
-            Some(Message::CycleScatterY)
-        }
-        KeyCode::Char('a')
-            if app.benchmarks_app.bottom_view == super::benchmarks::BottomView::Radar =>
-        {
-            Some(Message::CycleRadarPreset)
-        }
-        KeyCode::Char('d') if app.selections.len() >= 2 => Some(Message::ToggleDetailOverlay),
-        KeyCode::Char('t') if app.selections.len() >= 2 => Some(Message::ToggleComparePanel),
-        _ => None,
-    }
+    keymap::lookup(keymap::tab_sections(app.current_tab), app, code, modifiers)
 }
 
 fn handle_picker_keys(code: KeyCode) -> Option<Message> {
@@ -449,19 +589,103 @@ fn handle_status_keys(app: &App, code: KeyCode, modifiers: KeyModifiers) -> Opti
     if let Some(action) = parse_nav_key(code, modifiers) {
         return resolve_status_nav(app, action);
     }
-    match code {
-        KeyCode::Char('o') => Some(Message::OpenStatusPage),
-        KeyCode::Char('r') => Some(Message::RefreshStatus),
-        KeyCode::Char('a') => Some(Message::OpenStatusPicker),
-        _ => None,
-    }
+    keymap::lookup(keymap::tab_sections(app.current_tab), app, code, modifiers)
 }
 
 fn handle_search_mode(code: KeyCode) -> Option<Message> {
     match code {
         KeyCode::Esc | KeyCode::Enter => Some(Message::ExitSearch),
         KeyCode::Backspace => Some(Message::SearchBackspace),
+        KeyCode::Up => Some(Message::SearchHistoryPrev),
+        KeyCode::Down => Some(Message::SearchHistoryNext),
         KeyCode::Char(c) => Some(Message::SearchInput(c)),
         _ => None,
     }
 }
+
+fn handle_palette_mode(code: KeyCode, modifiers: KeyModifiers) -> Option<Message> {
+    let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+    match code {
+        KeyCode::Enter => Some(Message::PaletteConfirm),
+        KeyCode::Esc => Some(Message::ClosePalette),
+        KeyCode::Backspace => Some(Message::PaletteBackspace),
+        KeyCode::Down => Some(Message::PaletteNext),
+        KeyCode::Up => Some(Message::PalettePrev),
+        KeyCode::Char('n') if ctrl => Some(Message::PaletteNext),
+        KeyCode::Char('p') if ctrl => Some(Message::PalettePrev),
+        KeyCode::Char(c) if !ctrl => Some(Message::PaletteInput(c)),
+        _ => None,
+    }
+}
+
+fn handle_min_score_mode(code: KeyCode) -> Option<Message> {
+    match code {
+        KeyCode::Enter => Some(Message::ApplyMinScore),
+        KeyCode::Esc => Some(Message::CancelMinScore),
+        KeyCode::Backspace => Some(Message::MinScoreBackspace),
+        KeyCode::Char(c) => Some(Message::MinScoreInput(c)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::benchmarks::BenchmarkStore;
+    use std::collections::HashMap;
+
+    fn models_app() -> App {
+        let mut app = App::new(HashMap::new(), None, None, BenchmarkStore::empty());
+        app.models_app.focus = Focus::Models;
+        app
+    }
+
+    fn press(app: &mut App, c: char) -> Vec<Message> {
+        handle_counted_normal_mode(app, KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn count_prefix_repeats_motion() {
+        let mut app = models_app();
+        assert!(press(&mut app, '1').is_empty());
+        assert!(press(&mut app, '2').is_empty());
+        let msgs = press(&mut app, 'j');
+        assert_eq!(msgs.len(), 12);
+        assert!(msgs.iter().all(|m| matches!(m, Message::NextModel)));
+        assert!(app.count_prefix.is_none());
+    }
+
+    #[test]
+    fn lone_digit_falls_back_to_its_binding() {
+        let mut app = models_app();
+        press(&mut app, '1');
+        let msgs = press(&mut app, 's');
+        assert!(matches!(
+            msgs.as_slice(),
+            [Message::ToggleReasoning, Message::CycleSort]
+        ));
+
+        press(&mut app, '1');
+        assert!(matches!(
+            flush_count_prefix(&mut app),
+            Some(Message::ToggleReasoning)
+        ));
+    }
+
+    #[test]
+    fn g_prefix_jumps_to_provider_letter_in_provider_focus() {
+        let mut app = models_app();
+        app.models_app.focus = Focus::Providers;
+        assert!(press(&mut app, 'g').is_empty());
+        assert!(matches!(
+            press(&mut app, 'o').as_slice(),
+            [Message::JumpToProviderLetter('o')]
+        ));
+
+        press(&mut app, 'g');
+        assert!(matches!(
+            press(&mut app, 'g').as_slice(),
+            [Message::SelectFirstProvider]
+        ));
+    }
+}
@@ -0,0 +1,111 @@
+//! Named glyphs for shared UI chrome, with an ASCII set for terminals that
+//! mangle Unicode (`--ascii` or `display.ascii`).
+
+/// Glyphs referenced by name from draw code instead of string literals.
+pub struct Glyphs {
+    /// Filled status dot (operational, up to date, data source)
+    pub dot: &'static str,
+    /// Half-filled dot (degraded, loading)
+    pub half_dot: &'static str,
+    /// Hollow dot (not tracked)
+    pub hollow_dot: &'static str,
+    /// Success mark (env var set)
+    pub check: &'static str,
+    /// Failure / outage mark
+    pub cross: &'static str,
+    /// Maintenance mark
+    pub diamond: &'static str,
+    /// Horizontal rule segment for section headers
+    pub rule: &'static str,
+    pub ellipsis: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+    pub left: &'static str,
+    pub right: &'static str,
+    /// "Then" arrow in cycle descriptions ("name → date")
+    pub then: &'static str,
+    /// Two-way arrow in toggle descriptions ("Models ↔ Creators")
+    pub both_ways: &'static str,
+    pub at_least: &'static str,
+    /// Separator between parts of a title or label ("Providers · by count")
+    pub separator: &'static str,
+    /// Loading-screen spinner frames
+    pub spinner: &'static [&'static str],
+}
+
+pub const UNICODE: Glyphs = Glyphs {
+    dot: "\u{25cf}",
+    half_dot: "\u{25d0}",
+    hollow_dot: "\u{25cb}",
+    check: "\u{2713}",
+    cross: "\u{2717}",
+    diamond: "\u{25c6}",
+    rule: "\u{2500}",
+    ellipsis: "\u{2026}",
+    up: "\u{2191}",
+    down: "\u{2193}",
+    left: "\u{2190}",
+    right: "\u{2192}",
+    then: "\u{2192}",
+    both_ways: "\u{2194}",
+    at_least: "\u{2265}",
+    separator: "\u{b7}",
+    spinner: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+};
+
+pub const ASCII: Glyphs = Glyphs {
+    dot: "*",
+    half_dot: "~",
+    hollow_dot: "o",
+    check: "+",
+    cross: "x",
+    diamond: "+",
+    rule: "-",
+    ellipsis: "...",
+    up: "^",
+    down: "v",
+    left: "<",
+    right: ">",
+    then: "->",
+    both_ways: "<->",
+    at_least: ">=",
+    separator: "-",
+    spinner: &["|", "/", "-", "\\"],
+};
+
+/// The glyph set chosen at startup.
+pub fn glyphs() -> &'static Glyphs {
+    if crate::formatting::ascii() {
+        &ASCII
+    } else {
+        &UNICODE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_set_is_pure_ascii() {
+        let g = &ASCII;
+        let fields = [
+            g.dot,
+            g.half_dot,
+            g.hollow_dot,
+            g.check,
+            g.cross,
+            g.diamond,
+            g.rule,
+            g.ellipsis,
+            g.up,
+            g.down,
+            g.left,
+            g.right,
+            g.then,
+            g.both_ways,
+            g.at_least,
+        ];
+        assert!(fields.iter().chain(g.spinner).all(|s| s.is_ascii()));
+    }
+}
@@ -0,0 +1,533 @@
+//! Single-key bindings per tab, as data: `event.rs` dispatches through these
+//! tables and the help popup renders them, so a key can't be bound without
+//! showing up in help (or listed in help without being bound).
+//!
+//! Navigation (`parse_nav_key`), modal pickers, and text-entry modes stay
+//! hand-written in `event.rs`: they resolve against focus rather than a fixed
+//! message.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use super::app::{App, Message, Tab};
+use super::benchmarks::BottomView;
+use super::models::{Focus, PanelSplit};
+
+/// One key and the message it sends.
+pub struct Binding {
+    pub code: KeyCode,
+    /// Requires Ctrl; plain bindings match regardless of modifiers
+    pub ctrl: bool,
+    /// Key column in help; `None` for an alias already covered by the row above
+    pub keys: Option<&'static str>,
+    /// Help text; `{then}` and `{both}` become the current glyph set's arrows
+    pub desc: &'static str,
+    /// Only bound while this holds (e.g. a view is showing)
+    pub when: Option<fn(&App) -> bool>,
+    pub message: fn() -> Message,
+}
+
+impl Binding {
+    const fn new(
+        code: KeyCode,
+        keys: &'static str,
+        desc: &'static str,
+        message: fn() -> Message,
+    ) -> Self {
+        Self {
+            code,
+            ctrl: false,
+            keys: Some(keys),
+            desc,
+            when: None,
+            message,
+        }
+    }
+
+    const fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    const fn when(mut self, when: fn(&App) -> bool) -> Self {
+        self.when = Some(when);
+        self
+    }
+
+    /// Bound, but not listed separately in help.
+    const fn alias(mut self) -> Self {
+        self.keys = None;
+        self
+    }
+
+    fn matches(&self, app: &App, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        self.code == code
+            && (!self.ctrl || modifiers.contains(KeyModifiers::CONTROL))
+            && self.when.is_none_or(|when| when(app))
+    }
+}
+
+/// A titled group of bindings, as shown in the help popup.
+pub struct Section {
+    pub title: &'static str,
+    pub bindings: &'static [Binding],
+    /// Help rows that aren't a single binding (prefixes, nav keys in context)
+    pub notes: &'static [(&'static str, &'static str)],
+}
+
+const fn key(c: char, keys: &'static str, desc: &'static str, message: fn() -> Message) -> Binding {
+    Binding::new(KeyCode::Char(c), keys, desc, message)
+}
+
+/// Keys that work on every tab, checked before the tab's own.
+pub const GLOBAL: &[Section] = &[
+    Section {
+        title: "Tabs",
+        bindings: &[
+            key('[', "[", "Previous tab", || Message::PrevTab),
+            key(']', "]", "Next tab", || Message::NextTab),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Other",
+        bindings: &[
+            key(':', ": / Ctrl+P", "Jump to a provider or model", || {
+                Message::OpenPalette
+            }),
+            key('p', "Ctrl+P", "Jump to a provider or model", || {
+                Message::OpenPalette
+            })
+            .ctrl()
+            .alias(),
+            key('L', "L", "Show recent status messages", || {
+                Message::ToggleStatusLog
+            }),
+            key(
+                'V',
+                "V",
+                "Show the comparison (+ adds in Models/Benchmarks)",
+                || Message::ToggleComparison,
+            ),
+            key('F', "F", "Toggle borderless detail panels", || {
+                Message::ToggleFlatDetails
+            }),
+            key(
+                'r',
+                "Ctrl+R",
+                "Reset this tab's search, filters, and sort",
+                || Message::ResetView,
+            )
+            .ctrl(),
+            key('q', "q", "Quit", || Message::Quit),
+            key('c', "Ctrl+C", "Quit", || Message::Quit).ctrl().alias(),
+            key('?', "?", "Toggle this help", || Message::ToggleHelp),
+        ],
+        notes: &[],
+    },
+];
+
+const MODELS: &[Section] = &[
+    Section {
+        title: "Providers",
+        bindings: &[key(
+            'P',
+            "P",
+            "Sort providers by name / model count",
+            || Message::CycleProviderSort,
+        )],
+        notes: &[("g<letter>", "Jump to provider (repeat to cycle)")],
+    },
+    Section {
+        title: "Filters & Sort",
+        bindings: &[
+            key(
+                's',
+                "s",
+                "Cycle sort (name {then} date {then} cost {then} context {then} output)",
+                || Message::CycleSort,
+            ),
+            key('S', "S", "Toggle sort direction", || Message::ToggleSortDir),
+            key('1', "1", "Toggle reasoning models filter", || {
+                Message::ToggleReasoning
+            }),
+            key('2', "2", "Toggle tools filter", || Message::ToggleTools),
+            key('3', "3", "Toggle open weights filter", || {
+                Message::ToggleOpenWeights
+            }),
+            key('4', "4", "Toggle free models filter", || {
+                Message::ToggleFree
+            }),
+            key('5', "5", "Cycle provider category filter", || {
+                Message::CycleProviderCategory
+            }),
+            key('6', "6", "Toggle category grouping", || {
+                Message::ToggleGrouping
+            }),
+            key(
+                'm',
+                "m",
+                "Group by output (text, image, audio, video, embeddings)",
+                || Message::ToggleOutputGrouping,
+            ),
+            key(
+                '7',
+                "7",
+                "Cycle release window (30d {then} 90d {then} 1y {then} off)",
+                || Message::CycleReleaseWindow,
+            ),
+            key(
+                '8',
+                "8",
+                "Dedupe models across providers (All list)",
+                || Message::ToggleDedupe,
+            ),
+            key(
+                '9',
+                "9",
+                "Cycle min knowledge cutoff (6mo {then} 1y {then} 2y {then} off)",
+                || Message::CycleKnowledgeWindow,
+            ),
+            key('0', "0", "Show/hide deprecated models", || {
+                Message::ToggleDeprecated
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Copy & Open",
+        bindings: &[
+            key('c', "c", "Copy model (display.copy_template)", || {
+                Message::CopyFull
+            }),
+            key('C', "C", "Copy model only", || Message::CopyModelId),
+            key('o', "o", "Open provider docs in browser", || {
+                Message::OpenProviderDoc
+            }),
+            key('p', "p", "Open provider homepage/pricing", || {
+                Message::OpenProviderHomepage
+            }),
+            key('D', "D", "Copy provider docs URL", || {
+                Message::CopyProviderDoc
+            }),
+            key('A', "A", "Copy provider API URL", || {
+                Message::CopyProviderApi
+            }),
+            key('E', "E", "Copy OpenAI-compatible config", || {
+                Message::CopyModelConfig
+            }),
+            key('b', "b", "Show model in Benchmarks tab", || {
+                Message::OpenModelBenchmarks
+            }),
+            key('+', "+", "Add model to the comparison (V shows it)", || {
+                Message::AddToComparison
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Selection",
+        bindings: &[
+            key(' ', "Space", "Check/uncheck model (model list)", || {
+                Message::ToggleModelSelection
+            })
+            .when(|app| app.models_app.focus == Focus::Models),
+            key('y', "y", "Copy all checked models", || {
+                Message::CopySelectedModels
+            }),
+            key('f', "f", "Star/unstar model (Favorites list)", || {
+                Message::ToggleFavorite
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Provider Card",
+        bindings: &[
+            key('w', "w", "Toggle wrap / horizontal scroll", || {
+                Message::ToggleDetailWrap
+            }),
+            key('<', "</>", "Scroll left/right (unwrapped)", || {
+                Message::ScrollDetailLeft
+            }),
+            key('>', ">", "Scroll right (unwrapped)", || {
+                Message::ScrollDetailRight
+            })
+            .alias(),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Layout",
+        bindings: &[
+            key('{', "{/}", "Narrow/widen provider column", || {
+                Message::ResizeProviderPanel(-PanelSplit::STEP)
+            }),
+            key('}', "}", "Widen provider column", || {
+                Message::ResizeProviderPanel(PanelSplit::STEP)
+            })
+            .alias(),
+            key('(', "(/)", "Narrow/widen detail column", || {
+                Message::ResizeDetailPanel(-PanelSplit::STEP)
+            }),
+            key(')', ")", "Widen detail column", || {
+                Message::ResizeDetailPanel(PanelSplit::STEP)
+            })
+            .alias(),
+        ],
+        notes: &[],
+    },
+];
+
+const AGENTS: &[Section] = &[
+    Section {
+        title: "Filters & Sort",
+        bindings: &[
+            key(
+                's',
+                "s",
+                "Cycle sort (name {then} updated {then} stars {then} status)",
+                || Message::CycleAgentSort,
+            ),
+            key('1', "1", "Toggle installed filter", || {
+                Message::ToggleInstalledFilter
+            }),
+            key('2', "2", "Toggle CLI filter", || Message::ToggleCliFilter),
+            key('3', "3", "Toggle open source filter", || {
+                Message::ToggleOpenSourceFilter
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Actions",
+        bindings: &[
+            key('o', "o", "Open docs in browser", || Message::OpenAgentDocs),
+            key('r', "r", "Open GitHub repo in browser", || {
+                Message::OpenAgentRepo
+            }),
+            key('c', "c", "Copy agent name", || Message::CopyAgentName),
+            key('a', "a", "Add/remove tracked agents", || {
+                Message::OpenPicker
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Search Navigation",
+        bindings: &[
+            key('n', "n", "Next search match", || Message::NextSearchMatch),
+            key('N', "N", "Previous search match", || {
+                Message::PrevSearchMatch
+            }),
+        ],
+        notes: &[],
+    },
+];
+
+fn comparing(app: &App) -> bool {
+    app.selections.len() >= 2
+}
+
+const BENCHMARKS: &[Section] = &[
+    Section {
+        title: "Quick Sort (press again to flip direction)",
+        bindings: &[
+            key('1', "1", "Sort by Intelligence index", || {
+                Message::QuickSortIntelligence
+            }),
+            key('2', "2", "Sort by Release date", || Message::QuickSortDate),
+            key('3', "3", "Sort by Speed (tok/s)", || {
+                Message::QuickSortSpeed
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Filters",
+        bindings: &[
+            key(
+                '4',
+                "4",
+                "Cycle open-weights filter (All/Open/Closed)",
+                || Message::CycleBenchmarkSource,
+            ),
+            key(
+                '5',
+                "5",
+                "Cycle region filter (US/China/Europe/...)",
+                || Message::ToggleRegionGrouping,
+            ),
+            key(
+                '6',
+                "6",
+                "Cycle type filter (Startup/Big Tech/Research)",
+                || Message::ToggleTypeGrouping,
+            ),
+            key(
+                '7',
+                "7",
+                "Cycle reasoning filter (All/Reasoning/Non-reasoning)",
+                || Message::CycleReasoningFilter,
+            ),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Sort (full cycle)",
+        bindings: &[
+            key('s', "s", "Open sort picker", || Message::OpenSortPicker),
+            key('S', "S", "Toggle sort direction", || {
+                Message::ToggleBenchmarkSortDir
+            }),
+            key('m', "m", "Minimum score for the sort column", || {
+                Message::EnterMinScore
+            })
+            .when(|app| app.benchmarks_app.sort_column.supports_threshold()),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Actions",
+        bindings: &[
+            key('o', "o", "Open Artificial Analysis page", || {
+                Message::OpenBenchmarkUrl
+            }),
+            key('M', "M", "Copy scores as a markdown table", || {
+                Message::CopyBenchmarkMarkdown
+            }),
+            key('Y', "Y", "Copy visible rows as CSV", || {
+                Message::CopyBenchmarkCsv
+            }),
+            Binding::new(
+                KeyCode::Enter,
+                "Enter",
+                "Show matching model in Models tab",
+                || Message::OpenBenchmarkModel,
+            ),
+            key('r', "r", "Re-fetch benchmark data", || {
+                Message::RefreshBenchmarks
+            }),
+            key('+', "+", "Add model to the comparison (V shows it)", || {
+                Message::AddToComparison
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Compare",
+        bindings: &[
+            key(' ', "Space", "Toggle model for comparison (max 8)", || {
+                Message::ToggleBenchmarkSelection
+            }),
+            key('c', "c", "Clear all selections", || {
+                Message::ClearBenchmarkSelections
+            })
+            .when(|app| !app.selections.is_empty()),
+            key(
+                'v',
+                "v",
+                "Cycle view: H2H {then} Scatter {then} Radar",
+                || Message::CycleBenchmarkView,
+            )
+            .when(comparing),
+            key('d', "d", "Show detail overlay (H2H view)", || {
+                Message::ToggleDetailOverlay
+            })
+            .when(comparing),
+            key('x', "x", "Cycle scatter X-axis", || Message::CycleScatterX)
+                .when(|app| app.benchmarks_app.bottom_view == BottomView::Scatter),
+            key('y', "y", "Cycle scatter Y-axis", || Message::CycleScatterY)
+                .when(|app| app.benchmarks_app.bottom_view == BottomView::Scatter),
+            key('a', "a", "Cycle radar preset", || Message::CycleRadarPreset)
+                .when(|app| app.benchmarks_app.bottom_view == BottomView::Radar),
+            key(
+                't',
+                "t",
+                "Toggle left panel: Models {both} Creators",
+                || Message::ToggleComparePanel,
+            )
+            .when(comparing),
+        ],
+        notes: &[
+            ("j/k", "Scroll H2H table (when Compare focused)"),
+            ("h/l", "Switch focus: List {both} Compare"),
+        ],
+    },
+];
+
+const STATUS: &[Section] = &[
+    Section {
+        title: "Actions",
+        bindings: &[
+            key('o', "o", "Open provider status page", || {
+                Message::OpenStatusPage
+            }),
+            key('r', "r", "Refresh provider status", || {
+                Message::RefreshStatus
+            }),
+            key('a', "a", "Add/remove tracked providers", || {
+                Message::OpenStatusPicker
+            }),
+        ],
+        notes: &[],
+    },
+    Section {
+        title: "Status view",
+        bindings: &[],
+        notes: &[
+            ("Tab/h/l", "Switch list/details focus"),
+            ("/", "Search providers"),
+        ],
+    },
+];
+
+/// The tab's own bindings, in help order.
+pub fn tab_sections(tab: Tab) -> &'static [Section] {
+    match tab {
+        Tab::Models => MODELS,
+        Tab::Agents => AGENTS,
+        Tab::Benchmarks => BENCHMARKS,
+        Tab::Status => STATUS,
+    }
+}
+
+/// The first binding in `sections` that `code` triggers right now.
+pub fn lookup(
+    sections: &[Section],
+    app: &App,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+) -> Option<Message> {
+    sections
+        .iter()
+        .flat_map(|section| section.bindings)
+        .find(|binding| binding.matches(app, code, modifiers))
+        .map(|binding| (binding.message)())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_are_bound_once_per_table() {
+        let tables = [GLOBAL, MODELS, AGENTS, BENCHMARKS, STATUS];
+        for sections in tables {
+            let bindings: Vec<&Binding> = sections
+                .iter()
+                .flat_map(|section| section.bindings)
+                .collect();
+            for (i, a) in bindings.iter().enumerate() {
+                for b in &bindings[i + 1..] {
+                    assert!(
+                        a.code != b.code || a.ctrl != b.ctrl,
+                        "{:?} bound twice ({} / {})",
+                        a.code,
+                        a.desc,
+                        b.desc
+                    );
+                }
+            }
+        }
+    }
+}
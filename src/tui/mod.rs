@@ -12,9 +12,14 @@ use tokio::sync::mpsc;
 pub mod agents;
 pub mod app;
 pub mod benchmarks;
+pub mod clipboard;
+pub mod comparison;
 pub mod event;
+pub mod glyphs;
+pub mod keymap;
 pub mod markdown;
 pub mod models;
+pub mod palette;
 pub mod status;
 pub mod ui;
 pub mod widgets;
@@ -22,26 +27,80 @@ pub mod widgets;
 use crate::agents::{
     load_agents, AsyncGitHubClient, ConditionalFetchResult, GitHubCache, GitHubData,
 };
-use crate::benchmarks::{BenchmarkFetchResult, BenchmarkFetcher, BenchmarkStore};
+use crate::api::DataSource;
+use crate::benchmarks::{BenchmarkDiff, BenchmarkFetchResult, BenchmarkFetcher, BenchmarkStore};
 use crate::config::Config;
 use crate::data::ProvidersMap;
 use crate::status::{StatusFetchResult, StatusFetcher};
+use clipboard::CopyOutcome;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-/// Copy text to clipboard, keeping it alive on Linux.
-/// On Linux, the clipboard is selection-based and needs the source app to stay alive.
-/// We spawn a thread to hold the clipboard for a few seconds.
-fn copy_to_clipboard(text: String) {
-    std::thread::spawn(move || {
-        if let Ok(mut clipboard) = arboard::Clipboard::new() {
-            let _ = clipboard.set_text(&text);
-            // Keep clipboard alive for other apps to read on Linux
-            std::thread::sleep(std::time::Duration::from_secs(2));
-        }
+/// A finished system clipboard write: the text, the status to show on
+/// success, and the outcome.
+type CopyResult = (String, String, CopyOutcome);
+
+/// Copy `text` on the blocking pool so a slow clipboard can't stall the UI.
+/// The result arrives on `copy_rx`, where a failure falls back to OSC 52 and
+/// `Message::CopyFinished` reports it, with `copied` shown on success.
+fn spawn_copy(tx: &mpsc::Sender<CopyResult>, text: String, copied: String) {
+    let tx = tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let outcome = clipboard::copy_system(&text);
+        let _ = tx.blocking_send((text, copied, outcome));
     });
 }
 
+/// Browser opens allowed within `OPEN_BURST_WINDOW` before asking for confirmation
+const OPEN_BURST_LIMIT: usize = 3;
+const OPEN_BURST_WINDOW: Duration = Duration::from_secs(3);
+
+/// Debounce for `Open*` actions so a held or mashed key can't flood the
+/// browser with tabs. Past `OPEN_BURST_LIMIT` opens in `OPEN_BURST_WINDOW`,
+/// an open is held back until the key is pressed again within the window.
+#[derive(Default)]
+struct OpenGuard {
+    recent: Vec<Instant>,
+    awaiting_confirm: Option<Instant>,
+}
+
+impl OpenGuard {
+    /// Whether an open at `now` may go ahead; records it if so.
+    fn allow(&mut self, now: Instant) -> bool {
+        self.recent
+            .retain(|&t| now.duration_since(t) < OPEN_BURST_WINDOW);
+        if self.recent.len() >= OPEN_BURST_LIMIT {
+            let confirmed = self
+                .awaiting_confirm
+                .take()
+                .is_some_and(|t| now.duration_since(t) < OPEN_BURST_WINDOW);
+            if !confirmed {
+                self.awaiting_confirm = Some(now);
+                return false;
+            }
+            self.recent.clear();
+        }
+        self.recent.push(now);
+        true
+    }
+}
+
+/// Open `url` in the browser unless `guard` holds it back, and say which
+/// happened in the status bar.
+fn open_with_status(app: &mut app::App, guard: &mut OpenGuard, url: &str) {
+    if guard.allow(Instant::now()) {
+        let _ = open::that_in_background(url);
+        app.set_status(format!("Opened: {}", url));
+    } else {
+        app.set_status(format!(
+            "Opened {} pages in a few seconds {} press again to open {}",
+            OPEN_BURST_LIMIT,
+            crate::formatting::EM_DASH,
+            url
+        ));
+    }
+}
+
 /// Result of a GitHub fetch operation for an agent.
 #[derive(Debug)]
 pub enum FetchResult {
@@ -65,14 +124,25 @@ struct RuntimeHandles {
     client: AsyncGitHubClient,
     disk_cache: Arc<RwLock<GitHubCache>>,
     bench_rx: mpsc::Receiver<BenchmarkFetchResult>,
+    bench_tx: mpsc::Sender<BenchmarkFetchResult>,
+    providers_rx: mpsc::Receiver<Result<(ProvidersMap, DataSource)>>,
+    copy_rx: mpsc::Receiver<CopyResult>,
+    copy_tx: mpsc::Sender<CopyResult>,
     status: StatusRuntime,
 }
-pub async fn run(providers: ProvidersMap) -> Result<()> {
+pub async fn run() -> Result<()> {
     use crate::agents::FetchStatus;
 
     // Load remaining data
     let agents_file = load_agents().ok();
-    let config = Config::load().ok();
+    // A broken config.toml runs the session on defaults without saving over it
+    let (config, config_error) = Config::load_or_default();
+    let (overrides, bad_overrides) =
+        crate::provider_category::parse_overrides(&config.provider_categories);
+    crate::provider_category::set_overrides(overrides);
+    clipboard::set_backend(config.display.clipboard_backend);
+    clipboard::set_verify(config.display.clipboard_verify);
+    let config = Some(config);
 
     // Benchmark data fetched from CDN in background; starts empty until loaded.
     let benchmark_store = BenchmarkStore::empty();
@@ -80,8 +150,32 @@ pub async fn run(providers: ProvidersMap) -> Result<()> {
     // Load disk cache for GitHub data (load before wrapping to avoid blocking in async)
     let disk_cache = GitHubCache::load();
 
-    // Create app BEFORE entering alternate screen
-    let mut app = app::App::new(providers, agents_file.as_ref(), config, benchmark_store);
+    // Create app BEFORE entering alternate screen. Providers start empty and
+    // arrive from the background fetch below; until then a loading screen is drawn.
+    let mut app = app::App::new(
+        ProvidersMap::new(),
+        agents_file.as_ref(),
+        config,
+        benchmark_store,
+    );
+    app.providers_loading = Some(Instant::now());
+    if let Some(err) = config_error {
+        app.set_status(format!("{err:#}; using defaults, changes won't be saved"));
+    } else if !bad_overrides.is_empty() {
+        app.set_status(format!(
+            "provider_categories: unknown category in {}",
+            bad_overrides.join(", ")
+        ));
+    }
+
+    // The models.dev client is blocking, so fetch on the blocking pool
+    let (providers_tx, providers_rx) = mpsc::channel(1);
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(crate::api::fetch_providers_with_source)
+            .await
+            .unwrap_or_else(|e| Err(anyhow::anyhow!("Provider fetch task failed: {}", e)));
+        let _ = providers_tx.send(result).await;
+    });
 
     // Install panic hook to restore terminal on crash
     let original_hook = std::panic::take_hook();
@@ -159,19 +253,17 @@ pub async fn run(providers: ProvidersMap) -> Result<()> {
 
     // Spawn background benchmark fetch from CDN
     let (bench_tx, bench_rx) = mpsc::channel(1);
-    tokio::spawn(async move {
-        let fetcher = BenchmarkFetcher::new();
-        let result = fetcher.fetch().await;
-        let _ = bench_tx.send(result).await;
-    });
+    spawn_benchmark_fetch(bench_tx.clone(), app.config.cache.benchmarks_ttl(), false);
 
     let (status_tx, status_rx) = mpsc::channel(4);
-    let status_client = reqwest::Client::builder()
-        .user_agent("models-tui")
-        .connect_timeout(Duration::from_secs(5))
-        .build()
-        .expect("Failed to build HTTP client");
-    if let Some(ref status_app) = app.status_app {
+    let status_client = crate::http::client("models-tui");
+    let offline = crate::offline::is_offline();
+    if offline {
+        // Status checks have no cache; show the tab empty rather than loading forever
+        if let Some(ref mut status_app) = app.status_app {
+            status_app.loading = false;
+        }
+    } else if let Some(ref status_app) = app.status_app {
         let seeds = status_app.fetch_seeds();
         let tx = status_tx.clone();
         let fetcher = StatusFetcher::with_client(status_client.clone());
@@ -188,12 +280,17 @@ pub async fn run(providers: ProvidersMap) -> Result<()> {
         last_fetch_time: None,
         fetch_generation: 0,
     };
+    let (copy_tx, copy_rx) = mpsc::channel(4);
     let runtime_handles = RuntimeHandles {
         github_rx: rx,
         github_tx: tx,
         client,
         disk_cache: disk_cache.clone(),
         bench_rx,
+        bench_tx,
+        providers_rx,
+        copy_rx,
+        copy_tx,
         status: status_runtime,
     };
     let result = run_app(&mut terminal, &mut app, runtime_handles);
@@ -222,19 +319,31 @@ pub async fn run(providers: ProvidersMap) -> Result<()> {
     result
 }
 
+/// Load benchmark data in the background (disk cache within `ttl`, else the CDN);
+/// the result arrives on `bench_rx`. `force` bypasses the cache.
+fn spawn_benchmark_fetch(tx: mpsc::Sender<BenchmarkFetchResult>, ttl: Duration, force: bool) {
+    tokio::spawn(async move {
+        let fetcher = BenchmarkFetcher::new();
+        let result = fetcher.fetch_with_cache(ttl, force).await;
+        let _ = tx.send(result).await;
+    });
+}
+
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut app::App,
     mut runtime: RuntimeHandles,
 ) -> Result<()> {
     let mut last_status_time: Option<std::time::Instant> = None;
+    let mut open_guard = OpenGuard::default();
+    let status_timeout = Duration::from_millis(app.config.display.status_timeout_ms);
 
     loop {
         terminal.draw(|f| ui::draw(f, app))?;
 
-        // Clear status after 2 seconds
+        // Clear status after `display.status_timeout_ms` (0 = on the next action)
         if let Some(time) = last_status_time {
-            if time.elapsed() > std::time::Duration::from_secs(2) {
+            if !status_timeout.is_zero() && time.elapsed() > status_timeout {
                 app.clear_status();
                 last_status_time = None;
             }
@@ -283,11 +392,46 @@ fn run_app(
             }
         }
 
+        // Check for the initial provider data (non-blocking). A failed fetch with
+        // no cache to fall back on ends the session with the fetch error.
+        if let Ok(result) = runtime.providers_rx.try_recv() {
+            let (providers, data_source) = result?;
+            app.update(app::Message::ProvidersReceived(providers, data_source));
+        }
+
+        // Report finished copies; OSC 52 writes to stdout, so it runs here
+        // between frames rather than on the copy thread
+        while let Ok((text, copied, outcome)) = runtime.copy_rx.try_recv() {
+            let outcome = match outcome {
+                CopyOutcome::Failed => clipboard::copy_osc52(&text),
+                outcome => outcome,
+            };
+            app.update(app::Message::CopyFinished(text, copied, outcome));
+            last_status_time = Some(Instant::now());
+        }
+
         // Check for benchmark data updates (non-blocking)
         if let Ok(result) = runtime.bench_rx.try_recv() {
             match result {
                 BenchmarkFetchResult::Fresh(entries) => {
-                    app.update(app::Message::BenchmarkDataReceived(entries));
+                    let summary =
+                        BenchmarkDiff::since_previous(&entries).and_then(|diff| diff.summary());
+                    app.update(app::Message::BenchmarkDataReceived(
+                        entries,
+                        chrono::Utc::now(),
+                    ));
+                    if let Some(summary) = summary {
+                        app.update(app::Message::BenchmarkChanges(summary));
+                    }
+                }
+                BenchmarkFetchResult::Cached(entries, fetched_at) => {
+                    app.update(app::Message::BenchmarkDataReceived(entries, fetched_at));
+                }
+                BenchmarkFetchResult::TimedOut => {
+                    app.update(app::Message::BenchmarkFetchTimedOut);
+                }
+                BenchmarkFetchResult::CertificateRejected => {
+                    app.update(app::Message::BenchmarkFetchCertificateRejected);
                 }
                 BenchmarkFetchResult::Error => {
                     app.update(app::Message::BenchmarkFetchFailed);
@@ -295,6 +439,15 @@ fn run_app(
             }
         }
 
+        if app.pending_benchmark_refresh {
+            app.pending_benchmark_refresh = false;
+            spawn_benchmark_fetch(
+                runtime.bench_tx.clone(),
+                app.config.cache.benchmarks_ttl(),
+                true,
+            );
+        }
+
         if app.pending_status_refresh {
             app.pending_status_refresh = false;
             let force = app.force_status_refresh;
@@ -307,7 +460,13 @@ fn run_app(
                 .status
                 .last_fetch_time
                 .is_some_and(|t| t.elapsed() < Duration::from_secs(2));
-            if force || (stale && !recent) {
+            if crate::offline::is_offline() {
+                if let Some(ref mut status_app) = app.status_app {
+                    status_app.loading = false;
+                }
+                app.set_status("Status checks unavailable offline".to_string());
+                last_status_time = Some(Instant::now());
+            } else if force || (stale && !recent) {
                 if let Some(ref status_app) = app.status_app {
                     runtime.status.fetch_generation += 1;
                     let gen = runtime.status.fetch_generation;
@@ -332,102 +491,148 @@ fn run_app(
             }
         }
 
-        if let Some(msg) = event::handle_events(app)? {
+        let msgs = event::handle_events(app)?;
+        if status_timeout.is_zero() && !msgs.is_empty() {
+            app.clear_status();
+            last_status_time = None;
+        }
+        for msg in msgs {
             // Handle clipboard operations and set status with timer
             match &msg {
                 app::Message::CopyFull => {
                     if let Some(text) = app.get_copy_full() {
-                        copy_to_clipboard(text.clone());
-                        app.set_status(format!("Copied: {}", text));
-                        last_status_time = Some(std::time::Instant::now());
+                        spawn_copy(&runtime.copy_tx, text.clone(), format!("Copied: {}", text));
                     }
                 }
                 app::Message::CopyModelId => {
                     if let Some(text) = app.get_copy_model_id() {
-                        copy_to_clipboard(text.clone());
-                        app.set_status(format!("Copied: {}", text));
-                        last_status_time = Some(std::time::Instant::now());
+                        spawn_copy(&runtime.copy_tx, text.clone(), format!("Copied: {}", text));
+                    }
+                }
+                app::Message::CopySelectedModels => {
+                    if let Some(text) = app.get_copy_selected() {
+                        let count = app.model_selections.len();
+                        spawn_copy(&runtime.copy_tx, text, format!("Copied {} models", count));
+                    }
+                }
+                app::Message::CopyModelConfig => {
+                    if let Some(text) = app.get_copy_config() {
+                        let status = if app.get_provider_api().is_some() {
+                            "Copied model config".to_string()
+                        } else {
+                            "Copied model config (provider has no API URL)".to_string()
+                        };
+                        spawn_copy(&runtime.copy_tx, text, status);
                     }
                 }
                 app::Message::CopyProviderDoc => {
                     if let Some(text) = app.get_provider_doc() {
-                        copy_to_clipboard(text.clone());
-                        app.set_status(format!("Copied: {}", text));
-                        last_status_time = Some(std::time::Instant::now());
+                        spawn_copy(&runtime.copy_tx, text.clone(), format!("Copied: {}", text));
                     }
                 }
                 app::Message::CopyProviderApi => {
                     if let Some(text) = app.get_provider_api() {
-                        copy_to_clipboard(text.clone());
-                        app.set_status(format!("Copied: {}", text));
-                        last_status_time = Some(std::time::Instant::now());
+                        spawn_copy(&runtime.copy_tx, text.clone(), format!("Copied: {}", text));
                     }
                 }
                 app::Message::OpenProviderDoc => {
                     if let Some(url) = app.get_provider_doc() {
-                        let _ = open::that_in_background(&url);
-                        app.set_status(format!("Opened: {}", url));
+                        open_with_status(app, &mut open_guard, &url);
+                        last_status_time = Some(std::time::Instant::now());
+                    }
+                }
+                app::Message::OpenProviderHomepage => {
+                    if let Some(url) = app.get_provider_homepage() {
+                        open_with_status(app, &mut open_guard, &url);
                         last_status_time = Some(std::time::Instant::now());
                     }
                 }
                 app::Message::OpenAgentDocs => {
-                    if let Some(ref agents_app) = app.agents_app {
-                        if let Some(entry) = agents_app.current_entry() {
-                            if let Some(ref url) = entry.agent.docs {
-                                let _ = open::that_in_background(url);
-                                app.set_status(format!("Opened: {}", url));
-                                last_status_time = Some(std::time::Instant::now());
-                            } else if let Some(ref url) = entry.agent.homepage {
-                                let _ = open::that_in_background(url);
-                                app.set_status(format!("Opened: {}", url));
-                                last_status_time = Some(std::time::Instant::now());
-                            }
-                        }
+                    let url = app
+                        .agents_app
+                        .as_ref()
+                        .and_then(|a| a.current_entry())
+                        .and_then(|entry| {
+                            entry.agent.docs.clone().or(entry.agent.homepage.clone())
+                        });
+                    if let Some(url) = url {
+                        open_with_status(app, &mut open_guard, &url);
+                        last_status_time = Some(std::time::Instant::now());
                     }
                 }
                 app::Message::OpenAgentRepo => {
-                    if let Some(ref agents_app) = app.agents_app {
-                        if let Some(entry) = agents_app.current_entry() {
-                            let url = format!("https://github.com/{}", entry.agent.repo);
-                            let _ = open::that_in_background(&url);
-                            app.set_status(format!("Opened: {}", url));
-                            last_status_time = Some(std::time::Instant::now());
-                        }
+                    let url = app
+                        .agents_app
+                        .as_ref()
+                        .and_then(|a| a.current_entry())
+                        .map(|entry| format!("https://github.com/{}", entry.agent.repo));
+                    if let Some(url) = url {
+                        open_with_status(app, &mut open_guard, &url);
+                        last_status_time = Some(std::time::Instant::now());
                     }
                 }
                 app::Message::CopyAgentName => {
-                    if let Some(ref agents_app) = app.agents_app {
-                        if let Some(entry) = agents_app.current_entry() {
-                            copy_to_clipboard(entry.agent.name.clone());
-                            app.set_status(format!("Copied: {}", entry.agent.name));
-                            last_status_time = Some(std::time::Instant::now());
-                        }
+                    let name = app
+                        .agents_app
+                        .as_ref()
+                        .and_then(|a| a.current_entry())
+                        .map(|entry| entry.agent.name.clone());
+                    if let Some(name) = name {
+                        spawn_copy(&runtime.copy_tx, name.clone(), format!("Copied: {}", name));
                     }
                 }
                 app::Message::CopyBenchmarkName => {
-                    if let Some(entry) = app.benchmarks_app.current_entry(&app.benchmark_store) {
-                        copy_to_clipboard(entry.name.clone());
-                        app.set_status(format!("Copied: {}", entry.name));
-                        last_status_time = Some(std::time::Instant::now());
+                    let name = app
+                        .benchmarks_app
+                        .current_entry(&app.benchmark_store)
+                        .map(|entry| entry.name.clone());
+                    if let Some(name) = name {
+                        spawn_copy(&runtime.copy_tx, name.clone(), format!("Copied: {}", name));
+                    }
+                }
+                app::Message::CopyBenchmarkMarkdown => {
+                    if let Some(text) = app.get_benchmark_markdown() {
+                        spawn_copy(
+                            &runtime.copy_tx,
+                            text,
+                            "Copied benchmark scores as markdown".to_string(),
+                        );
+                    }
+                }
+                app::Message::CopyBenchmarkCsv => {
+                    if let Some(text) = app.get_benchmarks_csv() {
+                        let rows = app.benchmarks_app.filtered_indices.len();
+                        spawn_copy(&runtime.copy_tx, text, format!("Copied {rows} rows as CSV"));
                     }
                 }
                 app::Message::OpenBenchmarkUrl => {
-                    if let Some(entry) = app.benchmarks_app.current_entry(&app.benchmark_store) {
-                        let url = format!("https://artificialanalysis.ai/models/{}", entry.slug);
-                        let _ = open::that_in_background(&url);
-                        app.set_status(format!("Opened: {}", url));
+                    let url = app
+                        .benchmarks_app
+                        .current_entry(&app.benchmark_store)
+                        .map(|entry| {
+                            format!("https://artificialanalysis.ai/models/{}", entry.slug)
+                        });
+                    if let Some(url) = url {
+                        open_with_status(app, &mut open_guard, &url);
                         last_status_time = Some(std::time::Instant::now());
                     }
                 }
                 app::Message::OpenStatusPage => {
-                    if let Some(entry) = app.status_app.as_ref().and_then(|a| a.current_entry()) {
-                        if let Some(url) = entry.best_open_url() {
-                            let _ = open::that_in_background(url);
-                            app.set_status(format!("Opened: {}", url));
-                            last_status_time = Some(std::time::Instant::now());
-                        }
+                    let url = app
+                        .status_app
+                        .as_ref()
+                        .and_then(|a| a.current_entry())
+                        .and_then(|entry| entry.best_open_url())
+                        .map(str::to_string);
+                    if let Some(url) = url {
+                        open_with_status(app, &mut open_guard, &url);
+                        last_status_time = Some(std::time::Instant::now());
                     }
                 }
+                app::Message::RefreshBenchmarks if !app.benchmarks_app.loading => {
+                    app.set_status("Refreshing benchmark data…".to_string());
+                    last_status_time = Some(std::time::Instant::now());
+                }
                 app::Message::RefreshStatus => {
                     app.set_status("Refreshing provider status…".to_string());
                     last_status_time = Some(std::time::Instant::now());
@@ -512,8 +717,31 @@ fn run_app(
             }
 
             if !app.update(msg) {
+                app.save_session_config();
                 return Ok(());
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_guard_asks_again_after_a_burst() {
+        let mut guard = OpenGuard::default();
+        let start = Instant::now();
+        for i in 0..OPEN_BURST_LIMIT {
+            assert!(guard.allow(start + Duration::from_millis(i as u64 * 100)));
+        }
+        let held = start + Duration::from_millis(500);
+        assert!(!guard.allow(held));
+        // Pressing again inside the window confirms
+        assert!(guard.allow(held + Duration::from_millis(200)));
+
+        // After the window passes, opens flow freely again
+        let later = held + OPEN_BURST_WINDOW * 2;
+        assert!(guard.allow(later));
+    }
+}
@@ -1,6 +1,13 @@
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
+
 use ratatui::widgets::ListState;
+use regex::Regex;
 
-use crate::data::{Model, Provider};
+use crate::data::{Limits, Model, OutputCategory, Provider, CAPABILITY_KEYWORDS};
+use crate::formatting::{
+    cmp_opt_f64, knowledge_on_or_after, release_date_in_range, slash_pattern, SearchMatcher,
+};
 use crate::provider_category::{provider_category, ProviderCategory};
 use crate::tui::widgets::scroll_offset::ScrollOffset;
 
@@ -14,6 +21,38 @@ pub enum Focus {
     Details,
 }
 
+/// Order of providers in the Providers panel (within each category when grouped).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProviderSort {
+    #[default]
+    Name,
+    /// Most models first; ties stay alphabetical
+    ModelCount,
+}
+
+impl ProviderSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Name => Self::ModelCount,
+            Self::ModelCount => Self::Name,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::ModelCount => "models",
+        }
+    }
+
+    /// Reorder `(provider index, model count)` rows that arrive alphabetical.
+    fn apply(self, items: &mut [(usize, usize)]) {
+        if self == Self::ModelCount {
+            items.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SortOrder {
     Default,
@@ -21,6 +60,8 @@ pub enum SortOrder {
     ReleaseDate,
     Cost,
     Context,
+    /// Max output tokens (`limit.output`)
+    OutputLimit,
 }
 
 impl SortOrder {
@@ -29,24 +70,192 @@ impl SortOrder {
             SortOrder::Default => SortOrder::ReleaseDate,
             SortOrder::ReleaseDate => SortOrder::Cost,
             SortOrder::Cost => SortOrder::Context,
-            SortOrder::Context => SortOrder::Default,
+            SortOrder::Context => SortOrder::OutputLimit,
+            SortOrder::OutputLimit => SortOrder::Default,
         }
     }
+
+    /// Parse `display.models_sort` (case-insensitive).
+    pub fn from_config_key(key: &str) -> Option<Self> {
+        match key.trim().to_ascii_lowercase().as_str() {
+            "default" => Some(SortOrder::Default),
+            "date" => Some(SortOrder::ReleaseDate),
+            "cost" => Some(SortOrder::Cost),
+            "context" => Some(SortOrder::Context),
+            "output" => Some(SortOrder::OutputLimit),
+            _ => None,
+        }
+    }
+}
+
+/// Models tab column widths in percent. The model list takes the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanelSplit {
+    pub providers: u16,
+    pub details: u16,
+}
+
+impl Default for PanelSplit {
+    fn default() -> Self {
+        Self {
+            providers: 20,
+            details: 35,
+        }
+    }
+}
+
+impl PanelSplit {
+    /// Bounds for each resizable column
+    const MIN: u16 = 15;
+    const MAX: u16 = 60;
+    /// The model list never shrinks below this
+    const MIN_MODELS: u16 = 25;
+    pub const STEP: i16 = 5;
+
+    /// Clamp both columns to `MIN..=MAX`, then shrink `details` if the
+    /// model list would drop below `MIN_MODELS`.
+    pub fn new(providers: u16, details: u16) -> Self {
+        let providers = providers.clamp(Self::MIN, Self::MAX);
+        let details = details
+            .clamp(Self::MIN, Self::MAX)
+            .min(100 - Self::MIN_MODELS - providers);
+        Self { providers, details }
+    }
+
+    pub fn resize_providers(self, delta: i16) -> Self {
+        let providers = self.providers.saturating_add_signed(delta);
+        let providers = providers.min(100 - Self::MIN_MODELS - self.details);
+        Self::new(providers, self.details)
+    }
+
+    pub fn resize_details(self, delta: i16) -> Self {
+        let details = self.details.saturating_add_signed(delta);
+        Self::new(self.providers, details)
+    }
+
+    pub fn models(self) -> u16 {
+        100 - self.providers - self.details
+    }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+/// Release windows (in days) cycled by the release-date filter key.
+const RELEASE_WINDOWS: [i64; 3] = [30, 90, 365];
+
+/// Knowledge-cutoff windows (in months) cycled by the knowledge filter key.
+const KNOWLEDGE_WINDOWS: [u32; 3] = [6, 12, 24];
+
+#[derive(Debug, Clone, Default)]
 pub struct Filters {
     pub reasoning: bool,
     pub tools: bool,
     pub open_weights: bool,
     pub free: bool,
+    /// Inclusive lower bound on `release_date` (YYYY-MM-DD)
+    pub released_after: Option<String>,
+    /// Inclusive upper bound on `release_date` (YYYY-MM-DD)
+    pub released_before: Option<String>,
+    /// Inclusive lower bound on the `knowledge` cutoff (YYYY-MM)
+    pub min_knowledge: Option<String>,
+    /// List models whose `status` is "deprecated" (hidden by default)
+    pub show_deprecated: bool,
+}
+
+/// A search query split into capability keywords and free-text terms.
+/// A `CAPABILITY_KEYWORDS` entry appearing as a whole token is applied as an
+/// implicit filter instead of being matched against model names. A leading
+/// `^` switches the terms from substring to prefix matching, and a
+/// `/pattern/` query is a single regex instead.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedQuery {
+    pub capabilities: Vec<&'static str>,
+    pub terms: Vec<String>,
+    pub prefix: bool,
+    pub regex: Option<Regex>,
+}
+
+impl ParsedQuery {
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = Self::default();
+        // A pattern that doesn't compile is searched as literal text
+        let query = match slash_pattern(query) {
+            Some(pattern) => match SearchMatcher::new(query) {
+                SearchMatcher::Regex(regex) => {
+                    parsed.regex = Some(regex);
+                    return parsed;
+                }
+                SearchMatcher::Substring(_) => pattern,
+            },
+            None => query,
+        };
+        let query = match query.trim_start().strip_prefix('^') {
+            Some(rest) => {
+                parsed.prefix = true;
+                rest
+            }
+            None => query,
+        };
+        for token in query.split_whitespace() {
+            let lower = token.to_lowercase();
+            match CAPABILITY_KEYWORDS.iter().find(|k| **k == lower) {
+                Some(keyword) => {
+                    if !parsed.capabilities.contains(keyword) {
+                        parsed.capabilities.push(keyword);
+                    }
+                }
+                None => parsed.terms.push(lower),
+            }
+        }
+        parsed
+    }
+
+    fn model_has_capabilities(&self, model: &Model) -> bool {
+        self.capabilities
+            .iter()
+            .all(|cap| model.has_capability(cap))
+    }
+
+    /// Every free-text term must appear in (in prefix mode, start) the model
+    /// id, name, or (when given) the provider id.
+    fn matches(&self, model_id: &str, model: &Model, provider_id: Option<&str>) -> bool {
+        if !self.model_has_capabilities(model) {
+            return false;
+        }
+        if let Some(regex) = &self.regex {
+            return regex.is_match(model_id)
+                || regex.is_match(&model.name)
+                || provider_id.is_some_and(|p| regex.is_match(p));
+        }
+        let model_id = model_id.to_lowercase();
+        let name = model.name.to_lowercase();
+        let provider_id = provider_id.map(str::to_lowercase);
+        let hit = |text: &str, term: &str| {
+            if self.prefix {
+                text.starts_with(term)
+            } else {
+                text.contains(term)
+            }
+        };
+        self.terms.iter().all(|term| {
+            hit(&model_id, term)
+                || hit(&name, term)
+                || provider_id.as_ref().is_some_and(|p| hit(p, term))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProviderListItem {
     All,
+    /// Recently viewed models across providers (match count)
+    Recent(usize),
+    /// Starred models across providers (match count)
+    Favorites(usize),
     CategoryHeader(ProviderCategory),
     Provider(usize, usize), // (index into providers, match count)
+    /// Separator above the output groups
+    OutputHeader,
+    /// Models across providers with this output (match count)
+    Output(OutputCategory, usize),
 }
 
 #[derive(Debug, Clone)]
@@ -54,6 +263,82 @@ pub struct ModelEntry {
     pub id: String,
     pub model: Model,
     pub provider_id: String,
+    /// Every provider listing this model, cheapest first, when the entry
+    /// collapses duplicates (dedupe mode); empty otherwise
+    pub offers: Vec<ProviderOffer>,
+}
+
+/// One provider's listing of a deduplicated model.
+#[derive(Debug, Clone)]
+pub struct ProviderOffer {
+    pub provider_id: String,
+    pub model_id: String,
+    pub input_cost: Option<f64>,
+    pub output_cost: Option<f64>,
+}
+
+impl ModelEntry {
+    fn new(provider_id: &str, model_id: &str, model: &Model) -> Self {
+        Self {
+            id: model_id.to_string(),
+            model: model.clone(),
+            provider_id: provider_id.to_string(),
+            offers: Vec::new(),
+        }
+    }
+
+    /// Dedupe key: the same family and display name means the same model.
+    fn dedupe_key(&self) -> (String, String) {
+        (
+            self.model.family.as_deref().unwrap_or("").to_lowercase(),
+            self.model.name.to_lowercase(),
+        )
+    }
+
+    fn input_cost(&self) -> Option<f64> {
+        self.model.cost.as_ref().and_then(|c| c.input)
+    }
+}
+
+/// Collapse entries sharing family+name into one row per model. The row
+/// keeps the cheapest provider's listing (unpriced last) and records every
+/// provider's prices in `offers`. First-seen order is kept.
+fn dedupe_entries(entries: Vec<ModelEntry>) -> Vec<ModelEntry> {
+    let mut groups: Vec<Vec<ModelEntry>> = Vec::new();
+    let mut index: HashMap<(String, String), usize> = HashMap::new();
+    for entry in entries {
+        match index.entry(entry.dedupe_key()) {
+            Entry::Occupied(slot) => groups[*slot.get()].push(entry),
+            Entry::Vacant(slot) => {
+                slot.insert(groups.len());
+                groups.push(vec![entry]);
+            }
+        }
+    }
+    groups
+        .into_iter()
+        .map(|mut group| {
+            if group.len() == 1 {
+                return group.remove(0);
+            }
+            group.sort_by(|a, b| {
+                cmp_opt_f64(a.input_cost(), b.input_cost())
+                    .then_with(|| a.provider_id.cmp(&b.provider_id))
+            });
+            let offers = group
+                .iter()
+                .map(|entry| ProviderOffer {
+                    provider_id: entry.provider_id.clone(),
+                    model_id: entry.id.clone(),
+                    input_cost: entry.input_cost(),
+                    output_cost: entry.model.cost.as_ref().and_then(|c| c.output),
+                })
+                .collect();
+            let mut cheapest = group.swap_remove(0);
+            cheapest.offers = offers;
+            cheapest
+        })
+        .collect()
 }
 
 pub struct ModelsApp {
@@ -65,12 +350,27 @@ pub struct ModelsApp {
     pub sort_order: SortOrder,
     pub sort_ascending: bool,
     pub filters: Filters,
+    /// Active preset from `RELEASE_WINDOWS`, if the release filter came from the key toggle
+    pub release_window_days: Option<i64>,
+    /// Active preset from `KNOWLEDGE_WINDOWS`
+    pub knowledge_window_months: Option<u32>,
     pub search_query: String,
     pub provider_category_filter: ProviderCategory,
     pub group_by_category: bool,
+    /// List output groups (text, image, audio, ...) in place of providers
+    pub group_by_output: bool,
+    pub provider_sort: ProviderSort,
     pub provider_list_items: Vec<ProviderListItem>,
     filtered_models: Vec<ModelEntry>,
     pub detail_scroll: ScrollOffset,
+    /// Recently viewed `provider/model` keys, most recent first
+    pub recent_models: Vec<String>,
+    /// Starred `provider/model` keys
+    pub favorite_models: BTreeSet<String>,
+    /// Collapse the same model offered by several providers in the "All" list
+    pub dedupe: bool,
+    /// Deprecated models the current list would show if `show_deprecated` were on
+    pub hidden_deprecated: usize,
 }
 
 impl ModelsApp {
@@ -89,12 +389,20 @@ impl ModelsApp {
             sort_order: SortOrder::ReleaseDate,
             sort_ascending: false,
             filters: Filters::default(),
+            release_window_days: None,
+            knowledge_window_months: None,
             search_query: String::new(),
             provider_category_filter: ProviderCategory::All,
             group_by_category: false,
+            group_by_output: false,
+            provider_sort: ProviderSort::default(),
             provider_list_items: Vec::new(),
             filtered_models: Vec::new(),
             detail_scroll: ScrollOffset::default(),
+            recent_models: Vec::new(),
+            favorite_models: BTreeSet::new(),
+            dedupe: false,
+            hidden_deprecated: 0,
         };
 
         app.update_provider_list(providers);
@@ -109,6 +417,94 @@ impl ModelsApp {
         )
     }
 
+    pub fn is_recent_selected(&self) -> bool {
+        matches!(
+            self.provider_list_items.get(self.selected_provider),
+            Some(ProviderListItem::Recent(_))
+        )
+    }
+
+    /// The output group selected in the sidebar, if any.
+    pub fn selected_output(&self) -> Option<OutputCategory> {
+        match self.provider_list_items.get(self.selected_provider) {
+            Some(ProviderListItem::Output(category, _)) => Some(*category),
+            _ => None,
+        }
+    }
+
+    pub fn is_favorites_selected(&self) -> bool {
+        matches!(
+            self.provider_list_items.get(self.selected_provider),
+            Some(ProviderListItem::Favorites(_))
+        )
+    }
+
+    /// Restore the persisted recent and starred models and show their entries.
+    pub fn set_saved_models(
+        &mut self,
+        recent: Vec<String>,
+        favorites: BTreeSet<String>,
+        providers: &[(String, Provider)],
+    ) {
+        self.recent_models = recent;
+        self.favorite_models = favorites;
+        self.rebuild_after_filter_change(providers);
+    }
+
+    pub fn is_favorite(&self, entry: &ModelEntry) -> bool {
+        self.favorite_models
+            .contains(&format!("{}/{}", entry.provider_id, entry.id))
+    }
+
+    /// Star or unstar the selected model, keeping the cursor in place where
+    /// possible. Returns the new state, or `None` with no model selected.
+    pub fn toggle_favorite(&mut self, providers: &[(String, Provider)]) -> Option<bool> {
+        let entry = self.current_model()?;
+        let key = format!("{}/{}", entry.provider_id, entry.id);
+        let starred = if self.favorite_models.remove(&key) {
+            false
+        } else {
+            self.favorite_models.insert(key);
+            true
+        };
+        let prev_model = self.selected_model;
+        self.rebuild_after_filter_change(providers);
+        self.selected_model = prev_model.min(self.filtered_models.len().saturating_sub(1));
+        self.model_list_state.select(Some(self.selected_model + 1));
+        Some(starred)
+    }
+
+    /// Record the selected model as most recently viewed. The "Recent" entry
+    /// picks up the new order the next time the lists are rebuilt, so browsing
+    /// the Recent list doesn't reshuffle it under the cursor.
+    pub fn record_recent_model(&mut self) -> bool {
+        let Some(key) = self
+            .current_model()
+            .map(|e| format!("{}/{}", e.provider_id, e.id))
+        else {
+            return false;
+        };
+        crate::config::push_recent_model(&mut self.recent_models, &key)
+    }
+
+    /// Keyed models that still exist and pass the search and filters, in key order.
+    fn keyed_entries<'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a String>,
+        providers: &[(String, Provider)],
+    ) -> Vec<ModelEntry> {
+        let query = ParsedQuery::parse(&self.search_query);
+        keys.into_iter()
+            .filter_map(|key| {
+                let (provider_id, model_id) = key.split_once('/')?;
+                let (_, provider) = providers.iter().find(|(id, _)| id == provider_id)?;
+                let model = provider.models.get(model_id)?;
+                (query.matches(model_id, model, Some(provider_id)) && self.passes_filters(model))
+                    .then(|| ModelEntry::new(provider_id, model_id, model))
+            })
+            .collect()
+    }
+
     pub fn provider_list_len(&self) -> usize {
         self.provider_list_items.len()
     }
@@ -129,19 +525,33 @@ impl ModelsApp {
             || self.filters.tools
             || self.filters.open_weights
             || self.filters.free
+            || self.filters.released_after.is_some()
+            || self.filters.released_before.is_some()
+            || self.filters.min_knowledge.is_some()
+    }
+
+    /// Provider row count without search or filters: every model bar the
+    /// hidden deprecated ones. Unlike a filter, this never drops the row.
+    fn unfiltered_count(&self, provider: &Provider) -> usize {
+        provider
+            .models
+            .values()
+            .filter(|model| !self.hides_deprecated(model))
+            .count()
+    }
+
+    /// Capability keywords from the search query that are being applied as filters.
+    pub fn search_capabilities(&self) -> Vec<&'static str> {
+        ParsedQuery::parse(&self.search_query).capabilities
     }
 
     fn provider_match_count(&self, provider_id: &str, provider: &Provider) -> usize {
-        let query_lower = self.search_query.to_lowercase();
+        let query = ParsedQuery::parse(&self.search_query);
         provider
             .models
             .iter()
             .filter(|(model_id, model)| {
-                let search_matches = query_lower.is_empty()
-                    || model_id.to_lowercase().contains(&query_lower)
-                    || model.name.to_lowercase().contains(&query_lower)
-                    || provider_id.to_lowercase().contains(&query_lower);
-                search_matches && self.passes_filters(model)
+                query.matches(model_id, model, Some(provider_id)) && self.passes_filters(model)
             })
             .count()
     }
@@ -149,10 +559,37 @@ impl ModelsApp {
     pub fn update_provider_list(&mut self, providers: &[(String, Provider)]) {
         self.provider_list_items.clear();
         self.provider_list_items.push(ProviderListItem::All);
+        if !self.recent_models.is_empty() {
+            let count = self.keyed_entries(&self.recent_models, providers).len();
+            if count > 0 {
+                self.provider_list_items
+                    .push(ProviderListItem::Recent(count));
+            }
+        }
+        if !self.favorite_models.is_empty() {
+            let count = self.keyed_entries(&self.favorite_models, providers).len();
+            if count > 0 {
+                self.provider_list_items
+                    .push(ProviderListItem::Favorites(count));
+            }
+        }
 
         let filtering = self.has_active_filters();
 
-        if self.group_by_category {
+        if self.group_by_output {
+            let mut counts: HashMap<OutputCategory, usize> = HashMap::new();
+            for model in self.all_models(providers).map(|(_, _, model)| model) {
+                *counts.entry(model.output_modality_category()).or_default() += 1;
+            }
+            self.provider_list_items
+                .push(ProviderListItem::OutputHeader);
+            for category in OutputCategory::ALL {
+                if let Some(&count) = counts.get(&category) {
+                    self.provider_list_items
+                        .push(ProviderListItem::Output(category, count));
+                }
+            }
+        } else if self.group_by_category {
             let categories = [
                 ProviderCategory::Origin,
                 ProviderCategory::Cloud,
@@ -180,7 +617,7 @@ impl ModelsApp {
                             }
                             c
                         } else {
-                            provider.models.len()
+                            self.unfiltered_count(provider)
                         };
                         Some((idx, count))
                     })
@@ -191,6 +628,7 @@ impl ModelsApp {
                 }
 
                 items.sort_by(|a, b| providers[a.0].0.cmp(&providers[b.0].0));
+                self.provider_sort.apply(&mut items);
 
                 self.provider_list_items
                     .push(ProviderListItem::CategoryHeader(*cat));
@@ -200,6 +638,7 @@ impl ModelsApp {
                 }
             }
         } else {
+            let mut items = Vec::new();
             for (idx, (id, provider)) in providers.iter().enumerate() {
                 if self.provider_category_filter != ProviderCategory::All
                     && provider_category(id) != self.provider_category_filter
@@ -213,11 +652,16 @@ impl ModelsApp {
                     }
                     c
                 } else {
-                    provider.models.len()
+                    self.unfiltered_count(provider)
                 };
-                self.provider_list_items
-                    .push(ProviderListItem::Provider(idx, count));
+                items.push((idx, count));
             }
+            self.provider_sort.apply(&mut items);
+            self.provider_list_items.extend(
+                items
+                    .into_iter()
+                    .map(|(idx, count)| ProviderListItem::Provider(idx, count)),
+            );
         }
     }
 
@@ -231,7 +675,7 @@ impl ModelsApp {
         loop {
             if !matches!(
                 self.provider_list_items.get(idx),
-                Some(ProviderListItem::CategoryHeader(_))
+                Some(ProviderListItem::CategoryHeader(_) | ProviderListItem::OutputHeader)
             ) {
                 return idx;
             }
@@ -250,6 +694,16 @@ impl ModelsApp {
     }
 
     fn passes_filters(&self, model: &Model) -> bool {
+        !self.hides_deprecated(model) && self.passes_capability_filters(model)
+    }
+
+    fn hides_deprecated(&self, model: &Model) -> bool {
+        !self.filters.show_deprecated && model.status.as_deref() == Some("deprecated")
+    }
+
+    /// Every filter except the deprecated toggle, so hidden deprecated models
+    /// can be counted.
+    fn passes_capability_filters(&self, model: &Model) -> bool {
         if self.filters.reasoning && !model.reasoning {
             return false;
         }
@@ -262,39 +716,95 @@ impl ModelsApp {
         if self.filters.free && !model.is_free() {
             return false;
         }
-        true
+        release_date_in_range(
+            model.release_date.as_deref(),
+            self.filters.released_after.as_deref(),
+            self.filters.released_before.as_deref(),
+        ) && knowledge_on_or_after(
+            model.knowledge.as_deref(),
+            self.filters.min_knowledge.as_deref(),
+        )
     }
 
-    pub fn update_filtered_models(&mut self, providers: &[(String, Provider)]) {
-        let query_lower = self.search_query.to_lowercase();
+    /// `(provider_id, model_id, model)` for every model the "All" list
+    /// shows: the category filter, search, and filters applied.
+    fn all_models<'a>(
+        &'a self,
+        providers: &'a [(String, Provider)],
+    ) -> impl Iterator<Item = (&'a str, &'a str, &'a Model)> + 'a {
+        let query = ParsedQuery::parse(&self.search_query);
         let cat_filter = self.provider_category_filter;
+        providers
+            .iter()
+            .filter(move |(id, _)| {
+                cat_filter == ProviderCategory::All || provider_category(id) == cat_filter
+            })
+            .flat_map(move |(provider_id, provider)| {
+                let query = query.clone();
+                provider
+                    .models
+                    .iter()
+                    .filter(move |(model_id, model)| {
+                        query.matches(model_id, model, Some(provider_id))
+                            && self.passes_filters(model)
+                    })
+                    .map(move |(model_id, model)| (provider_id.as_str(), model_id.as_str(), model))
+            })
+    }
 
-        self.filtered_models = if self.is_all_selected() {
-            let mut entries: Vec<ModelEntry> = providers
+    pub fn update_filtered_models(&mut self, providers: &[(String, Provider)]) {
+        let query = ParsedQuery::parse(&self.search_query);
+        let cat_filter = self.provider_category_filter;
+        // `provider_id` is passed to the query exactly as the list build below
+        // does: `Some` for All and the output groups, `None` for one provider
+        let hidden_in =
+            |provider_id: Option<&str>, provider: &Provider, output: Option<OutputCategory>| {
+                provider
+                    .models
+                    .iter()
+                    .filter(|(model_id, model)| {
+                        output.is_none_or(|output| model.output_modality_category() == output)
+                            && self.hides_deprecated(model)
+                            && query.matches(model_id, model, provider_id)
+                            && self.passes_capability_filters(model)
+                    })
+                    .count()
+            };
+        let output = self.selected_output();
+        let hidden_deprecated = if self.is_all_selected() || output.is_some() {
+            providers
                 .iter()
                 .filter(|(id, _)| {
                     cat_filter == ProviderCategory::All || provider_category(id) == cat_filter
                 })
-                .flat_map(|(provider_id, provider)| {
-                    provider.models.iter().filter_map(|(model_id, model)| {
-                        let search_matches = query_lower.is_empty()
-                            || model_id.to_lowercase().contains(&query_lower)
-                            || model.name.to_lowercase().contains(&query_lower)
-                            || provider_id.to_lowercase().contains(&query_lower);
-
-                        if search_matches && self.passes_filters(model) {
-                            Some(ModelEntry {
-                                id: model_id.clone(),
-                                model: model.clone(),
-                                provider_id: provider_id.clone(),
-                            })
-                        } else {
-                            None
-                        }
-                    })
+                .map(|(id, provider)| hidden_in(Some(id), provider, output))
+                .sum()
+        } else if self.is_recent_selected() || self.is_favorites_selected() {
+            0
+        } else {
+            self.selected_provider_data(providers)
+                .map_or(0, |(_, provider)| hidden_in(None, provider, None))
+        };
+        self.hidden_deprecated = hidden_deprecated;
+
+        self.filtered_models = if self.is_all_selected() || output.is_some() {
+            let mut entries: Vec<ModelEntry> = self
+                .all_models(providers)
+                .filter(|(_, _, model)| {
+                    output.is_none_or(|output| model.output_modality_category() == output)
                 })
+                .map(|(provider_id, model_id, model)| ModelEntry::new(provider_id, model_id, model))
                 .collect();
 
+            if self.dedupe {
+                entries = dedupe_entries(entries);
+            }
+            self.sort_entries(&mut entries);
+            entries
+        } else if self.is_recent_selected() {
+            self.keyed_entries(&self.recent_models, providers)
+        } else if self.is_favorites_selected() {
+            let mut entries = self.keyed_entries(&self.favorite_models, providers);
             self.sort_entries(&mut entries);
             entries
         } else {
@@ -304,16 +814,8 @@ impl ModelsApp {
                     .models
                     .iter()
                     .filter_map(|(model_id, model)| {
-                        let search_matches = query_lower.is_empty()
-                            || model_id.to_lowercase().contains(&query_lower)
-                            || model.name.to_lowercase().contains(&query_lower);
-
-                        if search_matches && self.passes_filters(model) {
-                            Some(ModelEntry {
-                                id: model_id.clone(),
-                                model: model.clone(),
-                                provider_id: provider_id.clone(),
-                            })
+                        if query.matches(model_id, model, None) && self.passes_filters(model) {
+                            Some(ModelEntry::new(&provider_id, model_id, model))
                         } else {
                             None
                         }
@@ -329,65 +831,75 @@ impl ModelsApp {
     }
 
     fn sort_entries(&self, entries: &mut [ModelEntry]) {
+        // Ties fall back to model id, then provider, so re-sorts (and the
+        // HashMap order entries are collected in) never reorder rows
+        entries.sort_by(|a, b| {
+            self.compare_entries(a, b)
+                .then_with(|| a.id.cmp(&b.id))
+                .then_with(|| a.provider_id.cmp(&b.provider_id))
+        });
+    }
+
+    fn compare_entries(&self, a: &ModelEntry, b: &ModelEntry) -> std::cmp::Ordering {
         match self.sort_order {
-            SortOrder::Default => {
-                entries.sort_by(|a, b| a.provider_id.cmp(&b.provider_id).then(a.id.cmp(&b.id)));
-            }
-            SortOrder::ReleaseDate => {
-                entries.sort_by(
-                    |a, b| match (&b.model.release_date, &a.model.release_date) {
-                        (Some(b_date), Some(a_date)) => {
-                            if self.sort_ascending {
-                                a_date.cmp(b_date)
-                            } else {
-                                b_date.cmp(a_date)
-                            }
-                        }
-                        (Some(_), None) => std::cmp::Ordering::Less,
-                        (None, Some(_)) => std::cmp::Ordering::Greater,
-                        (None, None) => a.id.cmp(&b.id),
-                    },
-                );
-            }
+            SortOrder::Default => a.provider_id.cmp(&b.provider_id),
+            SortOrder::ReleaseDate => match (&b.model.release_date, &a.model.release_date) {
+                (Some(b_date), Some(a_date)) => {
+                    if self.sort_ascending {
+                        a_date.cmp(b_date)
+                    } else {
+                        b_date.cmp(a_date)
+                    }
+                }
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            },
             SortOrder::Cost => {
-                entries.sort_by(|a, b| {
-                    let a_cost = a.model.cost.as_ref().and_then(|c| c.input);
-                    let b_cost = b.model.cost.as_ref().and_then(|c| c.input);
-                    match (a_cost, b_cost) {
-                        (Some(a_val), Some(b_val)) => {
-                            let cmp = a_val
-                                .partial_cmp(&b_val)
-                                .unwrap_or(std::cmp::Ordering::Equal);
-                            if self.sort_ascending {
-                                cmp.reverse()
-                            } else {
-                                cmp
-                            }
+                let a_cost = a.model.cost.as_ref().and_then(|c| c.input);
+                let b_cost = b.model.cost.as_ref().and_then(|c| c.input);
+                match (a_cost, b_cost) {
+                    (Some(a_val), Some(b_val)) => {
+                        let cmp = a_val
+                            .partial_cmp(&b_val)
+                            .unwrap_or(std::cmp::Ordering::Equal);
+                        if self.sort_ascending {
+                            cmp.reverse()
+                        } else {
+                            cmp
                         }
-                        (Some(_), None) => std::cmp::Ordering::Less,
-                        (None, Some(_)) => std::cmp::Ordering::Greater,
-                        (None, None) => a.id.cmp(&b.id),
                     }
-                });
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                }
             }
-            SortOrder::Context => {
-                entries.sort_by(|a, b| {
-                    let a_ctx = a.model.limit.as_ref().and_then(|l| l.context);
-                    let b_ctx = b.model.limit.as_ref().and_then(|l| l.context);
-                    match (b_ctx, a_ctx) {
-                        (Some(b_val), Some(a_val)) => {
-                            if self.sort_ascending {
-                                a_val.cmp(&b_val)
-                            } else {
-                                b_val.cmp(&a_val)
-                            }
-                        }
-                        (Some(_), None) => std::cmp::Ordering::Less,
-                        (None, Some(_)) => std::cmp::Ordering::Greater,
-                        (None, None) => a.id.cmp(&b.id),
-                    }
-                });
+            SortOrder::Context => self.compare_limits(a, b, |l| l.context),
+            SortOrder::OutputLimit => self.compare_limits(a, b, |l| l.output),
+        }
+    }
+
+    /// Largest token limit first (smallest when ascending). Models without
+    /// the limit sort last in both directions.
+    fn compare_limits(
+        &self,
+        a: &ModelEntry,
+        b: &ModelEntry,
+        limit: fn(&Limits) -> Option<u64>,
+    ) -> std::cmp::Ordering {
+        let a_val = a.model.limit.as_ref().and_then(limit);
+        let b_val = b.model.limit.as_ref().and_then(limit);
+        match (a_val, b_val) {
+            (Some(a_val), Some(b_val)) => {
+                if self.sort_ascending {
+                    a_val.cmp(&b_val)
+                } else {
+                    b_val.cmp(&a_val)
+                }
             }
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
         }
     }
 
@@ -402,6 +914,23 @@ impl ModelsApp {
         self.reset_detail_scroll();
     }
 
+    /// Select a provider's row by id. Returns `false`, leaving the selection
+    /// alone, when no row has that id.
+    pub fn select_provider_id(
+        &mut self,
+        provider_id: &str,
+        providers: &[(String, Provider)],
+    ) -> bool {
+        let target = self.provider_list_items.iter().position(|item| {
+            matches!(item, ProviderListItem::Provider(idx, _)
+                if providers.get(*idx).is_some_and(|(id, _)| id == provider_id))
+        });
+        if let Some(target) = target {
+            self.select_provider_at_index(target, providers);
+        }
+        target.is_some()
+    }
+
     pub fn current_model(&self) -> Option<&ModelEntry> {
         self.filtered_models.get(self.selected_model)
     }
@@ -410,11 +939,14 @@ impl ModelsApp {
         &self.filtered_models
     }
 
+    /// Models across all providers matching the search and filters ("All" count).
     pub fn filtered_model_count(&self) -> usize {
         self.provider_list_items
             .iter()
             .filter_map(|item| match item {
-                ProviderListItem::Provider(_, count) => Some(count),
+                ProviderListItem::Provider(_, count) | ProviderListItem::Output(_, count) => {
+                    Some(count)
+                }
                 _ => None,
             })
             .sum()
@@ -425,6 +957,28 @@ impl ModelsApp {
             .map(|entry| format!("{}/{}", entry.provider_id, entry.id))
     }
 
+    /// Expand a `display.copy_template` for the selected model.
+    pub fn get_copy_templated(&self, template: &str) -> Option<String> {
+        let entry = self.current_model()?;
+        Some(crate::formatting::expand_template(
+            template,
+            |name| match name {
+                "provider" => entry.provider_id.clone(),
+                "model" => entry.id.clone(),
+                "name" => entry.model.name.clone(),
+                "input_cost" => entry
+                    .model
+                    .cost
+                    .as_ref()
+                    .and_then(|c| c.input)
+                    .map(|v| format!("${}", v))
+                    .unwrap_or_else(|| crate::formatting::EM_DASH.to_string()),
+                "context" => entry.model.context_str(),
+                _ => String::new(),
+            },
+        ))
+    }
+
     pub fn get_copy_model_id(&self) -> Option<String> {
         self.current_model().map(|entry| entry.id.clone())
     }
@@ -517,6 +1071,14 @@ impl ModelsApp {
         }
     }
 
+    pub fn select_model_at(&mut self, index: usize) {
+        if index < self.filtered_models.len() && index != self.selected_model {
+            self.selected_model = index;
+            self.model_list_state.select(Some(self.selected_model + 1));
+            self.reset_detail_scroll();
+        }
+    }
+
     pub fn select_first_model(&mut self) {
         if self.selected_model > 0 {
             self.selected_model = 0;
@@ -581,6 +1143,13 @@ impl ModelsApp {
         self.reset_detail_scroll();
     }
 
+    /// Start from a configured sort order, descending.
+    pub fn set_sort_order(&mut self, order: SortOrder, providers: &[(String, Provider)]) {
+        self.sort_order = order;
+        self.sort_ascending = false;
+        self.update_filtered_models(providers);
+    }
+
     pub fn toggle_sort_dir(&mut self, providers: &[(String, Provider)]) {
         if self.sort_order != SortOrder::Default {
             self.sort_ascending = !self.sort_ascending;
@@ -611,6 +1180,45 @@ impl ModelsApp {
         self.rebuild_after_filter_change(providers);
     }
 
+    /// Cycle the "released after" bound through the last 30d / 90d / 1y / off.
+    pub fn cycle_release_window(&mut self, providers: &[(String, Provider)]) {
+        let next = match self.release_window_days {
+            None => Some(RELEASE_WINDOWS[0]),
+            Some(days) => RELEASE_WINDOWS
+                .iter()
+                .position(|&d| d == days)
+                .and_then(|i| RELEASE_WINDOWS.get(i + 1))
+                .copied(),
+        };
+        self.release_window_days = next;
+        self.filters.released_after = next.map(|days| {
+            (chrono::Local::now().date_naive() - chrono::Duration::days(days))
+                .format("%Y-%m-%d")
+                .to_string()
+        });
+        self.rebuild_after_filter_change(providers);
+    }
+
+    /// Cycle the minimum knowledge cutoff through the last 6mo / 1y / 2y / off.
+    pub fn cycle_knowledge_window(&mut self, providers: &[(String, Provider)]) {
+        let next = match self.knowledge_window_months {
+            None => Some(KNOWLEDGE_WINDOWS[0]),
+            Some(months) => KNOWLEDGE_WINDOWS
+                .iter()
+                .position(|&m| m == months)
+                .and_then(|i| KNOWLEDGE_WINDOWS.get(i + 1))
+                .copied(),
+        };
+        self.knowledge_window_months = next;
+        self.filters.min_knowledge = next.and_then(|months| {
+            chrono::Local::now()
+                .date_naive()
+                .checked_sub_months(chrono::Months::new(months))
+                .map(|date| date.format("%Y-%m").to_string())
+        });
+        self.rebuild_after_filter_change(providers);
+    }
+
     pub fn cycle_provider_category(&mut self, providers: &[(String, Provider)]) {
         self.provider_category_filter = self.provider_category_filter.next();
         self.update_provider_list(providers);
@@ -635,6 +1243,36 @@ impl ModelsApp {
         self.reset_detail_scroll();
     }
 
+    /// Flip providers between alphabetical and most-models-first, keeping
+    /// the selected provider selected.
+    pub fn cycle_provider_sort(&mut self, providers: &[(String, Provider)]) {
+        let selected = self
+            .selected_provider_data(providers)
+            .map(|(id, _)| id.clone());
+        self.provider_sort = self.provider_sort.next();
+        self.update_provider_list(providers);
+        if let Some(id) = selected {
+            self.select_provider_id(&id, providers);
+        }
+    }
+
+    /// Swap the provider rows for output groups, or back.
+    pub fn toggle_output_grouping(&mut self, providers: &[(String, Provider)]) {
+        self.group_by_output = !self.group_by_output;
+        self.update_provider_list(providers);
+        self.select_provider_at_index(self.find_selectable_index(0, true), providers);
+    }
+
+    pub fn toggle_deprecated(&mut self, providers: &[(String, Provider)]) {
+        self.filters.show_deprecated = !self.filters.show_deprecated;
+        self.rebuild_after_filter_change(providers);
+    }
+
+    pub fn toggle_dedupe(&mut self, providers: &[(String, Provider)]) {
+        self.dedupe = !self.dedupe;
+        self.rebuild_after_filter_change(providers);
+    }
+
     pub fn search_input(&mut self, c: char, providers: &[(String, Provider)]) {
         self.search_query.push(c);
         self.rebuild_after_filter_change(providers);
@@ -650,22 +1288,68 @@ impl ModelsApp {
         self.rebuild_after_filter_change(providers);
     }
 
+    /// Clear search, filters, category, and dedupe, restore `sort_order` and
+    /// the configured `show_deprecated`, and select "All" with focus on the
+    /// provider list.
+    pub fn reset_view(
+        &mut self,
+        sort_order: SortOrder,
+        show_deprecated: bool,
+        providers: &[(String, Provider)],
+    ) {
+        self.filters = Filters {
+            show_deprecated,
+            ..Filters::default()
+        };
+        self.release_window_days = None;
+        self.knowledge_window_months = None;
+        self.search_query.clear();
+        self.provider_category_filter = ProviderCategory::All;
+        self.dedupe = false;
+        self.sort_order = sort_order;
+        self.sort_ascending = false;
+        self.focus = Focus::Providers;
+        self.update_provider_list(providers);
+        let all = self
+            .provider_list_items
+            .iter()
+            .position(|item| matches!(item, ProviderListItem::All))
+            .unwrap_or(0);
+        self.select_provider_at_index(all, providers);
+    }
+
+    pub fn set_search(&mut self, query: String, providers: &[(String, Provider)]) {
+        self.search_query = query;
+        self.rebuild_after_filter_change(providers);
+    }
+
     /// Rebuild provider list and model list after any search/filter change.
     /// Preserves the selected provider if it's still visible, otherwise falls back to "All".
     fn rebuild_after_filter_change(&mut self, providers: &[(String, Provider)]) {
-        // Remember which provider was selected (by index into providers slice)
-        let prev_provider_idx = match self.provider_list_items.get(self.selected_provider) {
-            Some(ProviderListItem::Provider(idx, _)) => Some(*idx),
-            _ => None, // All or CategoryHeader
-        };
+        // Remember which provider (by index into providers slice) or pseudo-provider was selected
+        let prev_item = self
+            .provider_list_items
+            .get(self.selected_provider)
+            .copied();
 
         self.update_provider_list(providers);
 
-        // Try to find the previously selected provider in the new list
-        let new_pos = prev_provider_idx.and_then(|prev_idx| {
-            self.provider_list_items.iter().position(
-                |item| matches!(item, ProviderListItem::Provider(idx, _) if *idx == prev_idx),
-            )
+        // Try to find the previously selected item in the new list
+        let new_pos = prev_item.and_then(|prev| {
+            self.provider_list_items
+                .iter()
+                .position(|item| match (prev, item) {
+                    (
+                        ProviderListItem::Provider(prev_idx, _),
+                        ProviderListItem::Provider(idx, _),
+                    ) => *idx == prev_idx,
+                    (ProviderListItem::Output(prev, _), ProviderListItem::Output(category, _)) => {
+                        *category == prev
+                    }
+                    (ProviderListItem::Recent(_), ProviderListItem::Recent(_))
+                    | (ProviderListItem::Favorites(_), ProviderListItem::Favorites(_)) => true,
+                    _ => false,
+                })
         });
 
         self.selected_provider = new_pos.unwrap_or(0);
@@ -677,3 +1361,176 @@ impl ModelsApp {
         self.reset_detail_scroll();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::data::Modalities;
+
+    fn model(name: &str) -> Model {
+        Model {
+            id: name.to_string(),
+            name: name.to_string(),
+            family: None,
+            reasoning: false,
+            tool_call: false,
+            attachment: false,
+            temperature: false,
+            modalities: None,
+            cost: None,
+            limit: None,
+            release_date: None,
+            last_updated: None,
+            knowledge: None,
+            open_weights: false,
+            status: None,
+        }
+    }
+
+    #[test]
+    fn panel_split_clamps_and_keeps_model_list_room() {
+        assert_eq!(PanelSplit::new(5, 90), PanelSplit::new(15, 60));
+        assert_eq!(PanelSplit::new(5, 90).models(), 25);
+
+        let split = PanelSplit::default();
+        assert_eq!(split.resize_providers(PanelSplit::STEP).providers, 25);
+        assert_eq!(split.resize_providers(-PanelSplit::STEP * 3).providers, 15);
+
+        // Providers can't grow into the model list's minimum width
+        let wide = PanelSplit::new(20, 50);
+        assert_eq!(wide.resize_providers(PanelSplit::STEP * 4).providers, 25);
+        assert_eq!(wide.resize_details(PanelSplit::STEP).details, 55);
+        assert_eq!(wide.resize_details(PanelSplit::STEP * 4).models(), 25);
+    }
+
+    #[test]
+    fn sort_ties_order_the_same_regardless_of_input_order() {
+        let mut app = ModelsApp::new(&[]);
+        app.sort_order = SortOrder::ReleaseDate;
+        let mut dated = model("b");
+        dated.release_date = Some("2025-01-01".to_string());
+        let mut older = model("c");
+        older.release_date = Some("2024-01-01".to_string());
+        let mut entries = vec![
+            ModelEntry::new("zai", "b", &dated),
+            ModelEntry::new("azure", "b", &dated),
+            ModelEntry::new("openai", "a", &dated),
+            ModelEntry::new("openai", "c", &older),
+        ];
+        let mut reversed: Vec<ModelEntry> = entries.iter().rev().cloned().collect();
+        app.sort_entries(&mut entries);
+        app.sort_entries(&mut reversed);
+
+        let keys = |entries: &[ModelEntry]| -> Vec<String> {
+            entries
+                .iter()
+                .map(|e| format!("{}/{}", e.provider_id, e.id))
+                .collect()
+        };
+        assert_eq!(keys(&entries), ["openai/a", "azure/b", "zai/b", "openai/c"]);
+        assert_eq!(keys(&entries), keys(&reversed));
+    }
+
+    #[test]
+    fn knowledge_filter_drops_undated_and_older_cutoffs() {
+        let mut app = ModelsApp::new(&[]);
+        app.filters.min_knowledge = Some("2024-06".to_string());
+        let mut m = model("recent");
+        assert!(!app.passes_filters(&m));
+        m.knowledge = Some("2024-10".to_string());
+        assert!(app.passes_filters(&m));
+        m.knowledge = Some("2024".to_string());
+        assert!(!app.passes_filters(&m));
+    }
+
+    #[test]
+    fn parse_separates_capability_keywords_from_terms() {
+        let parsed = ParsedQuery::parse("Reasoning gpt tools reasoning");
+        assert_eq!(parsed.capabilities, vec!["reasoning", "tools"]);
+        assert_eq!(parsed.terms, vec!["gpt".to_string()]);
+    }
+
+    #[test]
+    fn capability_keywords_are_anded() {
+        let parsed = ParsedQuery::parse("reasoning vision");
+        let mut m = model("claude");
+        m.reasoning = true;
+        assert!(!parsed.matches("claude", &m, None));
+
+        m.modalities = Some(Modalities {
+            input: vec!["text".to_string(), "image".to_string()],
+            output: vec!["text".to_string()],
+        });
+        assert!(parsed.matches("claude", &m, None));
+    }
+
+    #[test]
+    fn remaining_terms_must_all_match() {
+        let parsed = ParsedQuery::parse("gpt mini");
+        let m = model("GPT-4o Mini");
+        assert!(parsed.matches("gpt-4o-mini", &m, None));
+        assert!(!parsed.matches("gpt-4o", &model("GPT-4o"), None));
+        assert!(ParsedQuery::parse("openai").matches("gpt-4o", &m, Some("openai")));
+    }
+
+    #[test]
+    fn caret_switches_terms_to_prefix_matching() {
+        let parsed = ParsedQuery::parse("^gpt reasoning");
+        assert!(parsed.prefix);
+        assert_eq!(parsed.terms, vec!["gpt".to_string()]);
+        assert_eq!(parsed.capabilities, vec!["reasoning"]);
+
+        let m = model("GPT-4o Mini");
+        assert!(ParsedQuery::parse("^gpt-4o").matches("gpt-4o-mini", &m, None));
+        assert!(!ParsedQuery::parse("^mini").matches("gpt-4o-mini", &m, None));
+        assert!(ParsedQuery::parse("mini").matches("gpt-4o-mini", &m, None));
+        assert!(!ParsedQuery::parse("^4o").matches("openai/gpt-4o", &model("x"), None));
+    }
+
+    #[test]
+    fn slash_query_matches_as_regex() {
+        let m = model("GPT-4o Mini");
+        let parsed = ParsedQuery::parse("/^gpt-4o(-mini)?$/");
+        assert!(parsed.regex.is_some());
+        assert!(parsed.matches("gpt-4o-mini", &m, None));
+        assert!(!parsed.matches("gpt-4o-mini-2024", &model("x"), None));
+
+        // Doesn't compile: literal search for the text between the slashes
+        let parsed = ParsedQuery::parse("/mini(/");
+        assert!(parsed.regex.is_none());
+        assert_eq!(parsed.terms, vec!["mini(".to_string()]);
+    }
+
+    #[test]
+    fn dedupe_keeps_cheapest_provider_and_lists_offers() {
+        let priced = |name: &str, input: Option<f64>| {
+            let mut m = model(name);
+            m.family = Some("llama".to_string());
+            m.cost = input.map(|input| crate::data::Cost {
+                input: Some(input),
+                output: None,
+                cache_read: None,
+                cache_write: None,
+            });
+            m
+        };
+        let entries = vec![
+            ModelEntry::new("together", "llama-3-70b", &priced("Llama 3 70B", Some(0.9))),
+            ModelEntry::new("groq", "llama3-70b", &priced("Llama 3 70B", Some(0.59))),
+            ModelEntry::new("acme", "llama-3-70b", &priced("llama 3 70b", None)),
+            ModelEntry::new("groq", "llama3-8b", &priced("Llama 3 8B", Some(0.05))),
+        ];
+        let deduped = dedupe_entries(entries);
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].provider_id, "groq");
+        assert_eq!(deduped[0].id, "llama3-70b");
+        let offers: Vec<&str> = deduped[0]
+            .offers
+            .iter()
+            .map(|o| o.provider_id.as_str())
+            .collect();
+        assert_eq!(offers, vec!["groq", "together", "acme"]);
+        assert!(deduped[1].offers.is_empty());
+    }
+}
@@ -6,14 +6,50 @@ use ratatui::{
     Frame,
 };
 
-use super::app::{Filters, Focus, ProviderListItem, SortOrder};
+use super::app::{Filters, Focus, ParsedQuery, ProviderListItem, ProviderSort, SortOrder};
 use crate::formatting::truncate;
 use crate::formatting::EM_DASH;
 use crate::provider_category::{provider_category, ProviderCategory};
-use crate::tui::app::App;
-use crate::tui::ui::{caret, focus_border};
+use crate::tui::app::{App, ListHitArea, ListRegion};
+use crate::tui::glyphs::glyphs;
+use crate::tui::ui::{caret, focus_border, highlight_matches};
 use crate::tui::widgets::scrollable_panel::ScrollablePanel;
 
+/// Glyph column slots (`display.capability_glyphs`): (`has_capability`
+/// keyword, emoji, `--ascii` marker, legend). The emoji are all double-width,
+/// so each slot is two cells.
+pub(in crate::tui) const CAPABILITY_GLYPHS: [(&str, &str, &str, &str); 4] = [
+    ("reasoning", "\u{1f9e0}", "r", "Reasoning"),
+    ("tools", "\u{1f527}", "t", "Tool calling"),
+    ("vision", "\u{1f440}", "v", "Vision (image input)"),
+    ("files", "\u{1f4ce}", "f", "File attachments"),
+];
+
+/// Cells per glyph slot in the current mode.
+fn glyph_slot_width() -> usize {
+    if crate::formatting::ascii() {
+        1
+    } else {
+        2
+    }
+}
+
+/// The glyph column text for a model, one slot per `CAPABILITY_GLYPHS` entry.
+fn capability_glyphs(model: &crate::data::Model) -> String {
+    let ascii = crate::formatting::ascii();
+    CAPABILITY_GLYPHS
+        .iter()
+        .map(
+            |(keyword, emoji, marker, _)| match (model.has_capability(keyword), ascii) {
+                (true, false) => *emoji,
+                (true, true) => *marker,
+                (false, false) => "\u{00b7} ",
+                (false, true) => ".",
+            },
+        )
+        .collect()
+}
+
 fn provider_detail_lines(app: &App) -> Vec<Line<'static>> {
     let Some(entry) = app.models_app.current_model() else {
         return vec![Line::from(Span::styled(
@@ -56,14 +92,7 @@ fn provider_detail_lines(app: &App) -> Vec<Line<'static>> {
             Span::styled("API:  ", Style::default().fg(Color::Gray)),
             Span::raw(provider.api.clone().unwrap_or_else(|| EM_DASH.into())),
         ]),
-        Line::from(vec![
-            Span::styled("Env:  ", Style::default().fg(Color::Gray)),
-            Span::raw(if provider.env.is_empty() {
-                EM_DASH.to_string()
-            } else {
-                provider.env.join(", ")
-            }),
-        ]),
+        Line::from(env_spans(&provider.env, app.config.display.env_status)),
     ];
 
     // Only show keybinding hints for available URLs
@@ -79,22 +108,58 @@ fn provider_detail_lines(app: &App) -> Vec<Line<'static>> {
         hints.push(Span::styled("A ", Style::default().fg(Color::Yellow)));
         hints.push(Span::raw("api"));
     }
+    // `p` always works: it falls back to models.dev
     if !hints.is_empty() {
-        lines.push(Line::from(hints));
+        hints.push(Span::raw("  "));
     }
+    hints.push(Span::styled("p ", Style::default().fg(Color::Yellow)));
+    hints.push(Span::raw(if has_doc || has_api {
+        "site"
+    } else {
+        "models.dev"
+    }));
+    lines.push(Line::from(hints));
 
     lines
 }
 
+/// "Env:" row. With `display.env_status`, each var is followed by a green
+/// check when it's set in this process's environment or a dim cross if not.
+fn env_spans(env: &[String], show_status: bool) -> Vec<Span<'static>> {
+    let mut spans = vec![Span::styled("Env:  ", Style::default().fg(Color::Gray))];
+    if env.is_empty() {
+        spans.push(Span::raw(EM_DASH));
+        return spans;
+    }
+    let g = glyphs();
+    for (i, var) in env.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(", "));
+        }
+        spans.push(Span::raw(var.clone()));
+        if show_status {
+            spans.push(if crate::cli::env::is_set(var) {
+                Span::styled(format!(" {}", g.check), Style::default().fg(Color::Green))
+            } else {
+                Span::styled(
+                    format!(" {}", g.cross),
+                    Style::default().fg(Color::DarkGray),
+                )
+            });
+        }
+    }
+    spans
+}
+
 fn draw_right_panel(f: &mut Frame, area: Rect, app: &App) {
     let lines = provider_detail_lines(app);
 
     // Compute visual height: sum of wrapped line heights + 2 for borders.
     // Word-wrapping can use more lines than char-level div_ceil predicts,
     // so we add 1 extra line for each line that wraps as a buffer.
-    let border_block = Block::default().borders(Borders::ALL);
-    let inner_w = border_block.inner(area).width as usize;
-    let visual_lines: u16 = if inner_w == 0 {
+    // Unwrapped, each line is exactly one row and overflow scrolls horizontally.
+    let inner_w = detail_block(app, " Provider ").inner(area).width as usize;
+    let visual_lines: u16 = if inner_w == 0 || !app.detail_wrap {
         lines.len() as u16
     } else {
         lines
@@ -110,25 +175,27 @@ fn draw_right_panel(f: &mut Frame, area: Rect, app: &App) {
             })
             .sum()
     };
-    let provider_h = visual_lines + 2; // +2 for borders
+    // +2 for borders, or +1 for the title row when flat
+    let provider_h = visual_lines + if app.flat_details { 1 } else { 2 };
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(provider_h), Constraint::Min(0)])
         .split(area);
 
-    draw_provider_detail(f, chunks[0], lines);
+    draw_provider_detail(f, chunks[0], lines, app);
     draw_model_detail(f, chunks[1], app);
 }
 
 pub(in crate::tui) fn draw_main(f: &mut Frame, area: Rect, app: &mut App) {
-    // 3-column layout: providers 20% | models 45% | right panel 35%
+    // 3-column layout: providers | models | right panel, 20/45/35 unless resized
+    let split = app.panel_split;
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(20),
-            Constraint::Percentage(45),
-            Constraint::Percentage(35),
+            Constraint::Percentage(split.providers),
+            Constraint::Percentage(split.models()),
+            Constraint::Percentage(split.details),
         ])
         .split(area);
 
@@ -137,14 +204,31 @@ pub(in crate::tui) fn draw_main(f: &mut Frame, area: Rect, app: &mut App) {
     draw_right_panel(f, chunks[2], app);
 }
 
+/// A sidebar separator row like "── Origin ──────".
+fn separator_item(label: &str, color: Color, width: u16) -> ListItem<'static> {
+    let avail = width.saturating_sub(2) as usize; // account for highlight symbol space
+    let label_len = label.len() + 4; // "── " + label + " "
+    let trailing = if avail > label_len {
+        "\u{2500}".repeat(avail - label_len)
+    } else {
+        String::new()
+    };
+    let text = format!("\u{2500}\u{2500} {} {}", label, trailing);
+    ListItem::new(text).style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+}
+
 fn draw_providers(f: &mut Frame, area: Rect, app: &mut App) {
     let is_focused = app.models_app.focus == Focus::Providers;
     let border_style = focus_border(is_focused);
 
+    let title = match app.models_app.provider_sort {
+        ProviderSort::Name => " Providers ".to_string(),
+        sort => format!(" Providers {} by {} ", glyphs().separator, sort.label()),
+    };
     let outer_block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title(" Providers ");
+        .title(title);
     let inner_area = outer_block.inner(area);
     f.render_widget(outer_block, area);
 
@@ -166,6 +250,11 @@ fn draw_providers(f: &mut Frame, area: Rect, app: &mut App) {
     } else {
         Color::DarkGray
     };
+    let out_color = if app.models_app.group_by_output {
+        Color::Green
+    } else {
+        Color::DarkGray
+    };
 
     let cat_label = if cat_active {
         app.models_app.provider_category_filter.short_label()
@@ -177,7 +266,9 @@ fn draw_providers(f: &mut Frame, area: Rect, app: &mut App) {
         Span::styled("[5]", Style::default().fg(cat_color)),
         Span::raw(format!(" {} ", cat_label)),
         Span::styled("[6]", Style::default().fg(grp_color)),
-        Span::raw(" Grp"),
+        Span::raw(" Grp "),
+        Span::styled("[m]", Style::default().fg(out_color)),
+        Span::raw(" Out"),
     ]);
     f.render_widget(Paragraph::new(filter_line), chunks[0]);
 
@@ -191,22 +282,23 @@ fn draw_providers(f: &mut Frame, area: Rect, app: &mut App) {
                 let text = format!("All ({})", count);
                 items.push(ListItem::new(text).style(Style::default().fg(Color::Green)));
             }
+            ProviderListItem::Recent(count) => {
+                let text = format!("Recent ({})", count);
+                items.push(ListItem::new(text).style(Style::default().fg(Color::Cyan)));
+            }
+            ProviderListItem::Favorites(count) => {
+                let text = format!("\u{2605} Favorites ({})", count);
+                items.push(ListItem::new(text).style(Style::default().fg(Color::Yellow)));
+            }
             ProviderListItem::CategoryHeader(cat) => {
-                let label = cat.label();
-                let color = cat.color();
-                // Create a separator line like "── Origin ──────"
-                let avail = inner_area.width.saturating_sub(2) as usize; // account for highlight symbol space
-                let label_len = label.len() + 4; // "── " + label + " "
-                let trailing = if avail > label_len {
-                    "\u{2500}".repeat(avail - label_len)
-                } else {
-                    String::new()
-                };
-                let text = format!("\u{2500}\u{2500} {} {}", label, trailing);
-                items.push(
-                    ListItem::new(text)
-                        .style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
-                );
+                items.push(separator_item(cat.label(), cat.color(), inner_area.width));
+            }
+            ProviderListItem::OutputHeader => {
+                items.push(separator_item("Output", Color::Magenta, inner_area.width));
+            }
+            ProviderListItem::Output(category, count) => {
+                let text = format!("{} ({})", category.label(), count);
+                items.push(ListItem::new(text));
             }
             ProviderListItem::Provider(idx, count) => {
                 if let Some((id, _)) = app.providers.get(*idx) {
@@ -234,6 +326,12 @@ fn draw_providers(f: &mut Frame, area: Rect, app: &mut App) {
         .highlight_symbol(caret);
 
     f.render_stateful_widget(list, chunks[1], &mut app.models_app.provider_list_state);
+    app.list_areas.push(ListHitArea {
+        region: ListRegion::ModelProviders,
+        area: chunks[1],
+        offset: app.models_app.provider_list_state.offset(),
+        header_rows: 0,
+    });
 }
 
 fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
@@ -254,23 +352,56 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
                 SortOrder::ReleaseDate => "date",
                 SortOrder::Cost => "cost",
                 SortOrder::Context => "ctx",
+                SortOrder::OutputLimit => "out",
                 SortOrder::Default => unreachable!(),
             };
             format!(" {}{}", arrow, label)
         }
     };
 
-    let filter_indicator = format_filters(
+    let mut filter_indicator = format_filters(
         &app.models_app.filters,
         app.models_app.provider_category_filter,
     );
+    if app.models_app.dedupe && app.models_app.is_all_selected() {
+        if !filter_indicator.is_empty() {
+            filter_indicator.push_str(", ");
+        }
+        filter_indicator.push_str("dedupe");
+    }
+    if app.models_app.hidden_deprecated > 0 {
+        if !filter_indicator.is_empty() {
+            filter_indicator.push_str(", ");
+        }
+        filter_indicator.push_str(&format!(
+            "{} deprecated hidden",
+            app.models_app.hidden_deprecated
+        ));
+    }
 
     // Show provider name in title when a specific provider is selected
-    let provider_label = app
-        .models_app
-        .selected_provider_data(&app.providers)
-        .map(|(_, p)| p.name.as_str())
-        .unwrap_or("Models");
+    let provider_label = if app.models_app.is_recent_selected() {
+        "Recent"
+    } else if app.models_app.is_favorites_selected() {
+        "Favorites"
+    } else {
+        app.models_app
+            .selected_provider_data(&app.providers)
+            .map(|(_, p)| p.name.as_str())
+            .unwrap_or("Models")
+    };
+
+    // Surface capability keywords that the search applied as filters
+    let search_caps = app.models_app.search_capabilities();
+    let search_label = if search_caps.is_empty() {
+        app.models_app.search_query.clone()
+    } else {
+        format!(
+            "{} \u{2192} {}",
+            app.models_app.search_query,
+            search_caps.join("+")
+        )
+    };
 
     let title = if app.models_app.search_query.is_empty() && filter_indicator.is_empty() {
         format!(" {} ({}){} ", provider_label, models.len(), sort_indicator)
@@ -287,7 +418,7 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
             " {} ({}) [/{}]{} ",
             provider_label,
             models.len(),
-            app.models_app.search_query,
+            search_label,
             sort_indicator
         )
     } else {
@@ -295,7 +426,7 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
             " {} ({}) [/{}] [{}]{} ",
             provider_label,
             models.len(),
-            app.models_app.search_query,
+            search_label,
             filter_indicator,
             sort_indicator
         )
@@ -308,14 +439,21 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
     let inner_area = outer_block.inner(area);
     f.render_widget(outer_block, area);
 
-    // Fixed column widths: caret(2) + caps(5) + Input(8) Output(8) Context(8) + gaps(3)
+    // Fixed column widths: caret(2) + check/star(3) + caps(5) + Input(8) Output(8) Context(8) + gaps(3)
     let caret_w: u16 = 2;
+    let check_w: u16 = 3;
     let caps_w: u16 = 5; // "RTFO " — 4 indicator chars + 1 space
     let input_w: u16 = 8;
     let output_w: u16 = 8;
     let ctx_w: u16 = 8;
     let num_gaps: u16 = 3;
-    let fixed_w = caret_w + caps_w + input_w + output_w + ctx_w + num_gaps;
+    let glyphs_on = app.config.display.capability_glyphs;
+    let glyph_w = if glyphs_on {
+        (CAPABILITY_GLYPHS.len() * glyph_slot_width()) as u16 + 1
+    } else {
+        0
+    };
+    let fixed_w = caret_w + check_w + caps_w + glyph_w + input_w + output_w + ctx_w + num_gaps;
     let name_width = (inner_area.width.saturating_sub(fixed_w) as usize).max(10);
 
     let header_style = Style::default()
@@ -331,6 +469,7 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
         SortOrder::ReleaseDate => "name",
         SortOrder::Cost => "cost",
         SortOrder::Context => "context",
+        SortOrder::OutputLimit => "output",
     };
     let cost_style = if sort_col == "cost" {
         active_header_style
@@ -342,18 +481,21 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
     let caret = caret(is_focused);
 
     // Build header spans (leading spaces to align with caret)
-    let mut header_spans: Vec<Span> = vec![
-        Span::raw("  "),
-        Span::styled("RTFO ", header_style),
-        Span::styled(
-            format!("{:<width$}", "Model ID", width = name_width),
-            if sort_col == "name" {
-                active_header_style
-            } else {
-                header_style
-            },
-        ),
-    ];
+    let mut header_spans: Vec<Span> = vec![Span::raw("     "), Span::styled("RTFO ", header_style)];
+    if glyphs_on {
+        header_spans.push(Span::styled(
+            format!("{:<width$}", "Caps", width = glyph_w as usize),
+            header_style,
+        ));
+    }
+    header_spans.push(Span::styled(
+        format!("{:<width$}", "Model ID", width = name_width),
+        if sort_col == "name" {
+            active_header_style
+        } else {
+            header_style
+        },
+    ));
     header_spans.push(Span::styled(format!(" {:>8}", "Input"), cost_style));
     header_spans.push(Span::styled(format!(" {:>8}", "Output"), cost_style));
     header_spans.push(Span::styled(
@@ -369,6 +511,9 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
     let mut items: Vec<ListItem> = Vec::with_capacity(models.len() + 1);
     items.push(ListItem::new(Line::from(header_spans)));
 
+    // Capability keywords filter rather than match text, so only free-text terms highlight
+    let search_terms = ParsedQuery::parse(&app.models_app.search_query).terms;
+
     // Model rows
     for (display_idx, entry) in models.iter().enumerate() {
         let is_selected = display_idx == app.models_app.selected_model;
@@ -386,6 +531,15 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
         let ctx = entry.model.context_str();
 
         let prefix = if is_selected { caret } else { "  " };
+        let is_checked = app
+            .model_selections
+            .contains(&format!("{}/{}", entry.provider_id, entry.id));
+        let check = if is_checked { "\u{2713}" } else { " " };
+        let star = if app.models_app.is_favorite(entry) {
+            "\u{2605} "
+        } else {
+            "  "
+        };
         let m = &entry.model;
         let (r_ch, r_color) = if m.reasoning {
             ("R", Color::Cyan)
@@ -409,20 +563,41 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
         };
         let mut row_spans: Vec<Span> = vec![
             Span::styled(prefix, style),
+            Span::styled(check, Style::default().fg(Color::Green)),
+            Span::styled(star, Style::default().fg(Color::Yellow)),
             Span::styled(r_ch, Style::default().fg(r_color)),
             Span::styled(t_ch, Style::default().fg(t_color)),
             Span::styled(f_ch, Style::default().fg(f_color)),
             Span::styled(o_ch, Style::default().fg(o_color)),
             Span::raw(" "),
-            Span::styled(
-                format!(
-                    "{:<width$}",
-                    truncate(&entry.id, name_width.saturating_sub(1)),
-                    width = name_width
-                ),
-                style,
-            ),
         ];
+        if glyphs_on {
+            row_spans.push(Span::raw(format!("{} ", capability_glyphs(m))));
+        }
+        // Deduped rows: "×3 provider" names the cheapest of the collapsed listings
+        let badge = if entry.offers.len() > 1 {
+            format!(" \u{00d7}{} {}", entry.offers.len(), entry.provider_id)
+        } else {
+            String::new()
+        };
+        let badge = truncate(&badge, name_width / 2);
+        let id_width = name_width.saturating_sub(badge.chars().count());
+        let id_text = truncate(&entry.id, id_width.saturating_sub(1));
+        let pad = id_width.saturating_sub(id_text.chars().count());
+        // Deprecated models stay listed but dimmed and struck through
+        let id_style = if m.status.as_deref() == Some("deprecated") {
+            let dim = if is_selected {
+                style
+            } else {
+                style.fg(Color::DarkGray)
+            };
+            dim.add_modifier(Modifier::CROSSED_OUT)
+        } else {
+            style
+        };
+        row_spans.extend(highlight_matches(&id_text, &search_terms, id_style));
+        row_spans.push(Span::styled(badge, Style::default().fg(Color::DarkGray)));
+        row_spans.push(Span::raw(" ".repeat(pad)));
         row_spans.push(Span::styled(format!(" {:>8}", input_cost), style));
         row_spans.push(Span::styled(format!(" {:>8}", output_cost), style));
         row_spans.push(Span::styled(format!(" {:>8}", ctx), style));
@@ -433,17 +608,58 @@ fn draw_models(f: &mut Frame, area: Rect, app: &mut App) {
     let list = List::new(items);
     let mut state = app.models_app.model_list_state;
     f.render_stateful_widget(list, inner_area, &mut state);
+    app.list_areas.push(ListHitArea {
+        region: ListRegion::Models,
+        area: inner_area,
+        offset: state.offset(),
+        header_rows: 1,
+    });
 }
 
-fn draw_provider_detail(f: &mut Frame, area: Rect, lines: Vec<Line<'static>>) {
-    let paragraph = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL).title(" Provider "))
-        .wrap(Wrap { trim: false });
+/// The provider card's block: bordered, or just the title row when
+/// `flat_details` is on.
+fn detail_block<'a>(app: &App, title: impl Into<Line<'a>>) -> Block<'a> {
+    if app.flat_details {
+        Block::default().title(title)
+    } else {
+        Block::default().borders(Borders::ALL).title(title)
+    }
+}
 
+fn draw_provider_detail(f: &mut Frame, area: Rect, lines: Vec<Line<'static>>, app: &App) {
+    if app.detail_wrap {
+        let paragraph = Paragraph::new(lines)
+            .block(detail_block(app, " Provider "))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    // Clamp so scrolling stops once the longest line's end is visible
+    let inner_w = detail_block(app, "").inner(area).width as usize;
+    let max_w = lines.iter().map(Line::width).max().unwrap_or(0);
+    let max_scroll = max_w.saturating_sub(inner_w) as u16;
+    let hscroll = app.detail_hscroll.get().min(max_scroll);
+    app.detail_hscroll.set(hscroll);
+
+    let title = if max_scroll > 0 {
+        format!(" Provider \u{2190}{}/{}\u{2192} ", hscroll, max_scroll)
+    } else {
+        " Provider ".to_string()
+    };
+    let paragraph = Paragraph::new(lines)
+        .block(detail_block(app, title))
+        .scroll((0, hscroll));
     f.render_widget(paragraph, area);
 }
 
-fn section_header_line(width: u16, title: &str) -> Line<'static> {
+fn section_header_line(width: u16, title: &str, flat: bool) -> Line<'static> {
+    if flat {
+        return Line::from(Span::styled(
+            format!("{title}:"),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+    }
     let w = width as usize;
     let prefix = format!("\u{2500}\u{2500} {} ", title);
     let fill_len = w.saturating_sub(prefix.chars().count());
@@ -497,6 +713,7 @@ fn model_detail_lines(app: &App, width: u16) -> Vec<Line<'static>> {
     let label_color = Color::Gray;
     let em = EM_DASH;
     let col_w = (width as usize) / 2;
+    let flat = app.flat_details;
 
     let mut lines: Vec<Line<'static>> = Vec::new();
 
@@ -533,72 +750,114 @@ fn model_detail_lines(app: &App, width: u16) -> Vec<Line<'static>> {
     }
     lines.push(Line::from(provider_spans));
 
-    // ── Capabilities ──────────────────────────────────────────────────────
-    lines.push(Line::from(""));
-    lines.push(section_header_line(width, "Capabilities"));
-
-    let cap_val = |active: bool, color: Color| -> (&'static str, Color) {
-        if active {
-            ("Yes", color)
+    if model.is_embedding_model() {
+        // ── Embedding ─────────────────────────────────────────────────────
+        // Vectors, not chat: capabilities and chat benchmarks don't apply
+        lines.push(Line::from(""));
+        lines.push(section_header_line(width, "Embedding", flat));
+        lines.push(Line::from(vec![
+            Span::styled("Embedding model", Style::default().fg(Color::Magenta)),
+            Span::styled(
+                format!(
+                    " {} returns vectors; no chat benchmarks",
+                    glyphs().separator
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+        let max_input = model
+            .limit
+            .as_ref()
+            .and_then(|l| l.input.or(l.context))
+            .map_or_else(|| em.to_string(), crate::formatting::format_tokens);
+        let (ow_val, ow_col) = if model.open_weights {
+            ("Open", Color::Green)
         } else {
-            ("No", Color::DarkGray)
-        }
-    };
-    let (r_val, r_col) = cap_val(model.reasoning, Color::Cyan);
-    let (t_val, t_col) = cap_val(model.tool_call, Color::Yellow);
-    let (f_val, f_col) = cap_val(model.attachment, Color::Magenta);
-    let (ow_val, ow_col) = if model.open_weights {
-        ("Open", Color::Green)
+            ("Closed", Color::Red)
+        };
+        lines.push(two_pair_line(
+            LabelValue {
+                label: "Max input: ",
+                value: &max_input,
+                color: text_color,
+            },
+            LabelValue {
+                label: "Source: ",
+                value: ow_val,
+                color: ow_col,
+            },
+            col_w,
+        ));
     } else {
-        ("Closed", Color::Red)
-    };
-    let (tmp_val, tmp_col) = cap_val(model.temperature, Color::White);
-    lines.push(two_pair_line(
-        LabelValue {
-            label: "Reasoning: ",
-            value: r_val,
-            color: r_col,
-        },
-        LabelValue {
-            label: "Tools: ",
-            value: t_val,
-            color: t_col,
-        },
-        col_w,
-    ));
-    lines.push(two_pair_line(
-        LabelValue {
-            label: "Source: ",
-            value: ow_val,
-            color: ow_col,
-        },
-        LabelValue {
-            label: "Files: ",
-            value: f_val,
-            color: f_col,
-        },
-        col_w,
-    ));
-    lines.push(two_pair_line(
-        LabelValue {
-            label: "Temp: ",
-            value: tmp_val,
-            color: tmp_col,
-        },
-        LabelValue {
-            label: "",
-            value: "",
-            color: Color::DarkGray,
-        },
-        col_w,
-    ));
+        // ── Capabilities ──────────────────────────────────────────────────────
+        lines.push(Line::from(""));
+        lines.push(section_header_line(width, "Capabilities", flat));
+
+        let cap_val = |active: bool, color: Color| -> (&'static str, Color) {
+            if active {
+                ("Yes", color)
+            } else {
+                ("No", Color::DarkGray)
+            }
+        };
+        let (r_val, r_col) = cap_val(model.reasoning, Color::Cyan);
+        let (t_val, t_col) = cap_val(model.tool_call, Color::Yellow);
+        let (f_val, f_col) = cap_val(model.attachment, Color::Magenta);
+        let (ow_val, ow_col) = if model.open_weights {
+            ("Open", Color::Green)
+        } else {
+            ("Closed", Color::Red)
+        };
+        let (tmp_val, tmp_col) = cap_val(model.temperature, Color::White);
+        lines.push(two_pair_line(
+            LabelValue {
+                label: "Reasoning: ",
+                value: r_val,
+                color: r_col,
+            },
+            LabelValue {
+                label: "Tools: ",
+                value: t_val,
+                color: t_col,
+            },
+            col_w,
+        ));
+        lines.push(two_pair_line(
+            LabelValue {
+                label: "Source: ",
+                value: ow_val,
+                color: ow_col,
+            },
+            LabelValue {
+                label: "Files: ",
+                value: f_val,
+                color: f_col,
+            },
+            col_w,
+        ));
+        lines.push(two_pair_line(
+            LabelValue {
+                label: "Temp: ",
+                value: tmp_val,
+                color: tmp_col,
+            },
+            LabelValue {
+                label: "",
+                value: "",
+                color: Color::DarkGray,
+            },
+            col_w,
+        ));
+    }
 
     // ── Pricing ───────────────────────────────────────────────────────────
     lines.push(Line::from(""));
-    lines.push(section_header_line(width, "Pricing"));
+    lines.push(section_header_line(width, "Pricing", flat));
 
     let free = model.is_free();
     let cost_color = if free { Color::Green } else { text_color };
+    let unit = crate::formatting::cost_unit();
+    let sym = crate::formatting::currency_symbol();
     let fmt_cost = |val: Option<f64>| -> (String, Color) {
         match val {
             None => {
@@ -608,12 +867,14 @@ fn model_detail_lines(app: &App, width: u16) -> Vec<Line<'static>> {
                     (em.to_string(), Color::DarkGray)
                 }
             }
-            Some(0.0) => ("$0/M".to_string(), Color::Green),
+            Some(0.0) => (format!("{sym}0{}", unit.suffix()), Color::Green),
             Some(v) => {
+                let v = crate::formatting::scale_price(v, unit);
                 let formatted = if v.fract() == 0.0 {
-                    format!("${}/M", v as u64)
+                    format!("{sym}{}{}", v as u64, unit.suffix())
                 } else {
-                    format!("${:.2}/M", v)
+                    let decimals = crate::formatting::price_decimals(v, unit, 2);
+                    format!("{sym}{:.*}{}", decimals, v, unit.suffix())
                 };
                 (formatted, cost_color)
             }
@@ -652,9 +913,49 @@ fn model_detail_lines(app: &App, width: u16) -> Vec<Line<'static>> {
         col_w,
     ));
 
+    // Deduped row: every provider's listing, cheapest first
+    if !entry.offers.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(section_header_line(
+            width,
+            &format!("Providers ({})", entry.offers.len()),
+            flat,
+        ));
+        let id_w = entry
+            .offers
+            .iter()
+            .map(|offer| offer.provider_id.chars().count())
+            .max()
+            .unwrap_or(0);
+        for offer in &entry.offers {
+            let is_shown = offer.provider_id == entry.provider_id;
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<id_w$}", offer.provider_id),
+                    Style::default().fg(if is_shown { Color::Cyan } else { text_color }),
+                ),
+                Span::styled(
+                    format!(
+                        "  {:>8} in  {:>8} out",
+                        crate::data::Model::cost_short(offer.input_cost),
+                        crate::data::Model::cost_short(offer.output_cost)
+                    ),
+                    Style::default().fg(text_color),
+                ),
+            ];
+            if offer.model_id != entry.id {
+                spans.push(Span::styled(
+                    format!("  {}", offer.model_id),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
     // ── Limits ────────────────────────────────────────────────────────────
     lines.push(Line::from(""));
-    lines.push(section_header_line(width, "Limits"));
+    lines.push(section_header_line(width, "Limits", flat));
 
     let ctx_str = model.context_str();
     let inp_lim_str = model.input_limit_str();
@@ -691,7 +992,7 @@ fn model_detail_lines(app: &App, width: u16) -> Vec<Line<'static>> {
 
     // ── Modalities ────────────────────────────────────────────────────────
     lines.push(Line::from(""));
-    lines.push(section_header_line(width, "Modalities"));
+    lines.push(section_header_line(width, "Modalities", flat));
 
     let (mod_in, mod_out) = match &model.modalities {
         Some(m) => (
@@ -719,7 +1020,7 @@ fn model_detail_lines(app: &App, width: u16) -> Vec<Line<'static>> {
 
     // ── Dates ─────────────────────────────────────────────────────────────
     lines.push(Line::from(""));
-    lines.push(section_header_line(width, "Dates"));
+    lines.push(section_header_line(width, "Dates", flat));
 
     let released = model.release_date.as_deref().unwrap_or(em);
     let knowledge = model.knowledge.as_deref().unwrap_or(em);
@@ -773,9 +1074,15 @@ fn model_detail_lines(app: &App, width: u16) -> Vec<Line<'static>> {
 fn draw_model_detail(f: &mut Frame, area: Rect, app: &App) {
     let focused = app.models_app.focus == Focus::Details;
     // Inner width for line building (area width minus 2 for borders)
-    let inner_w = area.width.saturating_sub(2);
+    let inner_w = if app.flat_details {
+        area.width
+    } else {
+        area.width.saturating_sub(2)
+    };
     let lines = model_detail_lines(app, inner_w);
-    ScrollablePanel::new("Details", lines, &app.models_app.detail_scroll, focused).render(f, area);
+    ScrollablePanel::new("Details", lines, &app.models_app.detail_scroll, focused)
+        .with_flat(app.flat_details)
+        .render(f, area);
 }
 
 /// Unicode-safe truncation with ellipsis for table cells.
@@ -793,8 +1100,70 @@ pub(super) fn format_filters(filters: &Filters, category: ProviderCategory) -> S
     if filters.free {
         active.push("free");
     }
+    let after = filters
+        .released_after
+        .as_ref()
+        .map(|d| format!("after {}", d));
+    if let Some(after) = &after {
+        active.push(after);
+    }
+    let before = filters
+        .released_before
+        .as_ref()
+        .map(|d| format!("before {}", d));
+    if let Some(before) = &before {
+        active.push(before);
+    }
+    let knowledge = filters
+        .min_knowledge
+        .as_ref()
+        .map(|d| format!("knowledge since {}", d));
+    if let Some(knowledge) = &knowledge {
+        active.push(knowledge);
+    }
     if category != ProviderCategory::All {
         active.push(category.label());
     }
     active.join(", ")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_spans_mark_set_and_unset_vars() {
+        let env = vec!["PATH".to_string(), "MODELS_NO_SUCH_KEY".to_string()];
+        let text = |spans: Vec<Span>| {
+            spans
+                .iter()
+                .map(|s| s.content.to_string())
+                .collect::<String>()
+        };
+        let g = glyphs();
+        assert_eq!(
+            text(env_spans(&env, true)),
+            format!("Env:  PATH {}, MODELS_NO_SUCH_KEY {}", g.check, g.cross)
+        );
+        assert_eq!(
+            text(env_spans(&env, false)),
+            "Env:  PATH, MODELS_NO_SUCH_KEY"
+        );
+    }
+
+    #[test]
+    fn capability_glyphs_fill_one_slot_per_capability() {
+        let model: crate::data::Model = serde_json::from_value(serde_json::json!({
+            "id": "gpt-4o",
+            "name": "GPT-4o",
+            "reasoning": true,
+            "attachment": true,
+            "modalities": { "input": ["text", "image"], "output": ["text"] },
+        }))
+        .unwrap();
+        assert_eq!(
+            capability_glyphs(&model),
+            "\u{1f9e0}\u{00b7} \u{1f440}\u{1f4ce}"
+        );
+    }
+}
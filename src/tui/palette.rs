@@ -0,0 +1,192 @@
+//! Command palette (`:` or Ctrl+P): fuzzy-jump to any provider or model.
+
+use crate::data::Provider;
+
+/// Rows kept after filtering; the modal never shows more than a screenful.
+const MAX_MATCHES: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteTarget {
+    Provider(String),
+    Model {
+        provider_id: String,
+        model_id: String,
+    },
+}
+
+impl PaletteTarget {
+    /// Text matched against the query: `provider` or `provider/model`.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Provider(id) => id.clone(),
+            Self::Model {
+                provider_id,
+                model_id,
+            } => format!("{provider_id}/{model_id}"),
+        }
+    }
+}
+
+pub struct CommandPalette {
+    pub query: String,
+    pub selected: usize,
+    targets: Vec<(PaletteTarget, String)>,
+    /// Indices into `targets`, best match first
+    matches: Vec<usize>,
+}
+
+impl CommandPalette {
+    pub fn new(providers: &[(String, Provider)]) -> Self {
+        let mut targets = Vec::new();
+        for (provider_id, provider) in providers {
+            targets.push(PaletteTarget::Provider(provider_id.clone()));
+            let mut model_ids: Vec<&String> = provider.models.keys().collect();
+            model_ids.sort();
+            targets.extend(model_ids.into_iter().map(|model_id| PaletteTarget::Model {
+                provider_id: provider_id.clone(),
+                model_id: model_id.clone(),
+            }));
+        }
+        let mut palette = Self {
+            query: String::new(),
+            selected: 0,
+            targets: targets
+                .into_iter()
+                .map(|t| {
+                    let label = t.label();
+                    (t, label)
+                })
+                .collect(),
+            matches: Vec::new(),
+        };
+        palette.refilter();
+        palette
+    }
+
+    pub fn input(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn backspace(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn next(&mut self) {
+        if self.selected + 1 < self.matches.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Matching rows as (target, label), best first.
+    pub fn matches(&self) -> impl Iterator<Item = &(PaletteTarget, String)> {
+        self.matches.iter().map(|&i| &self.targets[i])
+    }
+
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    pub fn selected_target(&self) -> Option<&PaletteTarget> {
+        self.matches.get(self.selected).map(|&i| &self.targets[i].0)
+    }
+
+    fn refilter(&mut self) {
+        let query = self.query.to_lowercase();
+        let mut scored: Vec<(i64, usize)> = self
+            .targets
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (_, label))| fuzzy_score(&query, label).map(|score| (score, i)))
+            .collect();
+        // Stable sort keeps provider-then-model order among equal scores
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        scored.truncate(MAX_MATCHES);
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+}
+
+/// Subsequence score of `query` (already lowercase) in `candidate`, or `None`
+/// when the query's characters don't all appear in order. Consecutive runs
+/// and matches at the start of a segment (after `/`, `-`, `.`, `_`) score
+/// higher; skipped characters and long candidates cost a little.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let mut score = 0;
+    let mut prev_matched = false;
+    let mut prev_char: Option<char> = None;
+    let mut query_chars = query.chars().peekable();
+
+    for c in candidate.chars().map(|c| c.to_ascii_lowercase()) {
+        let Some(&want) = query_chars.peek() else {
+            break;
+        };
+        if c == want {
+            query_chars.next();
+            score += 1;
+            if prev_matched {
+                score += 5;
+            }
+            if prev_char.is_none_or(|p| matches!(p, '/' | '-' | '.' | '_')) {
+                score += 3;
+            }
+            prev_matched = true;
+        } else {
+            score -= 1;
+            prev_matched = false;
+        }
+        prev_char = Some(c);
+    }
+    if query_chars.peek().is_some() {
+        return None;
+    }
+    Some(score - candidate.len() as i64 / 8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ProvidersMap;
+
+    #[test]
+    fn fuzzy_score_prefers_contiguous_matches() {
+        assert_eq!(fuzzy_score("xyz", "openai/gpt-4o"), None);
+        let contiguous = fuzzy_score("gpt4o", "openai/gpt-4o").unwrap();
+        let scattered = fuzzy_score("gpt4o", "google/gemini-pro-turbo-4-o").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn palette_lists_providers_and_models() {
+        let providers: ProvidersMap = serde_json::from_value(serde_json::json!({
+            "openai": {
+                "id": "openai",
+                "name": "OpenAI",
+                "models": {
+                    "gpt-4o": { "id": "gpt-4o", "name": "GPT-4o" },
+                    "o3": { "id": "o3", "name": "o3" }
+                }
+            }
+        }))
+        .unwrap();
+        let providers: Vec<(String, Provider)> = providers.into_iter().collect();
+        let mut palette = CommandPalette::new(&providers);
+        assert_eq!(palette.match_count(), 3);
+
+        for c in "4o".chars() {
+            palette.input(c);
+        }
+        assert_eq!(
+            palette.selected_target(),
+            Some(&PaletteTarget::Model {
+                provider_id: "openai".to_string(),
+                model_id: "gpt-4o".to_string(),
+            })
+        );
+    }
+}
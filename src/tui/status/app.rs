@@ -320,6 +320,22 @@ impl StatusApp {
         self.reset_detail_scrolls();
     }
 
+    /// Select by list row, where row 0 is the Overall entry.
+    pub fn select_at(&mut self, index: usize) {
+        if index <= self.filtered_entries.len() && index != self.selected {
+            self.selected = index;
+            self.list_state.select(Some(self.selected));
+            self.reset_detail_scrolls();
+        }
+    }
+
+    /// Clear the search and select the Overall row.
+    pub fn reset_view(&mut self) {
+        self.search_query.clear();
+        self.update_filtered();
+        self.select_first();
+    }
+
     pub fn select_first(&mut self) {
         self.selected = 0;
         self.list_state.select(Some(0));
@@ -1,11 +1,11 @@
 use crate::formatting::{format_relative_time_from_str, truncate};
 use crate::status::{ProviderHealth, StatusSourceMethod, STATUS_REGISTRY};
-use crate::tui::app::App;
+use crate::tui::app::{App, ListHitArea, ListRegion};
 use crate::tui::ui::{
     caret, centered_rect_fixed, selection_style, status_health_icon, status_health_style,
 };
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
@@ -150,7 +150,7 @@ pub(super) fn overall_attention_entries(
                 )
         })
         .collect();
-    entries.sort_by(|a, b| a.health.sort_rank().cmp(&b.health.sort_rank()));
+    entries.sort_by_key(|a| a.health.sort_rank());
     entries
 }
 
@@ -551,6 +551,12 @@ pub(in crate::tui) fn draw_status_main(f: &mut Frame, area: Rect, app: &mut App)
             .title(title),
     );
     f.render_stateful_widget(list, chunks[0], &mut status_app.list_state);
+    app.list_areas.push(ListHitArea {
+        region: ListRegion::StatusProviders,
+        area: chunks[0].inner(Margin::new(1, 1)),
+        offset: status_app.list_state.offset(),
+        header_rows: 0,
+    });
 
     // Detail area: dispatch based on selection
     let detail_area = chunks[1];
@@ -1,12 +1,19 @@
+use std::borrow::Cow;
+use std::time::Instant;
+
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Clear, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
 use super::app::{App, Mode, Tab};
+use super::glyphs::glyphs;
+use super::keymap;
+use super::palette::{CommandPalette, PaletteTarget};
+use crate::api::DataSource;
 use crate::status::ProviderHealth;
 use crate::tui::widgets::scroll_offset::ScrollOffset;
 use crate::tui::widgets::scrollable_panel::ScrollablePanel;
@@ -40,8 +47,18 @@ pub(super) fn selection_style(selected: bool) -> Style {
     }
 }
 
+/// Build a help-popup section header in bold Cyan.
+fn help_section(title: &'static str) -> Line<'static> {
+    Line::from(Span::styled(
+        title,
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
 /// Build a help-popup line: 16-char padded key in Yellow + description.
-fn help_line<'a>(key: &'a str, desc: &'a str) -> Line<'a> {
+fn help_line<'a>(key: &str, desc: impl Into<Cow<'a, str>>) -> Line<'a> {
     Line::from(vec![
         Span::styled(format!("  {:<14}", key), Style::default().fg(Color::Yellow)),
         Span::raw(desc),
@@ -59,11 +76,12 @@ pub(super) fn status_health_style(health: ProviderHealth) -> Style {
 }
 
 pub(super) fn status_health_icon(health: ProviderHealth) -> &'static str {
+    let g = glyphs();
     match health {
-        ProviderHealth::Operational => "●",
-        ProviderHealth::Degraded => "◐",
-        ProviderHealth::Outage => "✗",
-        ProviderHealth::Maintenance => "◆",
+        ProviderHealth::Operational => g.dot,
+        ProviderHealth::Degraded => g.half_dot,
+        ProviderHealth::Outage => g.cross,
+        ProviderHealth::Maintenance => g.diamond,
         ProviderHealth::Unknown => "?",
     }
 }
@@ -115,9 +133,10 @@ pub(in crate::tui) fn visual_line_heights(lines: &[Line<'_>], wrap_width: usize)
 /// The result is styled DarkGray + BOLD, matching the models detail panel pattern.
 #[allow(dead_code)]
 pub(in crate::tui) fn section_header_line(title: &str, width: usize) -> Line<'static> {
-    let prefix = format!("\u{2500}\u{2500} {} ", title);
+    let rule = glyphs().rule;
+    let prefix = format!("{rule}{rule} {} ", title);
     let fill_len = width.saturating_sub(prefix.chars().count());
-    let header = format!("{}{}", prefix, "\u{2500}".repeat(fill_len));
+    let header = format!("{}{}", prefix, rule.repeat(fill_len));
     Line::from(Span::styled(
         header,
         Style::default()
@@ -147,6 +166,43 @@ pub(in crate::tui) fn filter_toggle_spans(toggles: &[(&str, &str, bool)]) -> Vec
     spans
 }
 
+/// Split a list cell into spans, adding bold+underline to case-insensitive
+/// occurrences of any search term so it's clear why a row matched.
+pub(in crate::tui) fn highlight_matches(
+    text: &str,
+    terms: &[String],
+    base: Style,
+) -> Vec<Span<'static>> {
+    let lower = text.to_lowercase();
+    // Byte offsets from the lowercased copy only line up if lowercasing kept the length
+    if text.is_empty() || terms.is_empty() || lower.len() != text.len() {
+        return vec![Span::styled(text.to_string(), base)];
+    }
+    let mut hits = vec![false; text.len()];
+    for term in terms.iter().filter(|t| !t.is_empty()) {
+        let term = term.to_lowercase();
+        for (start, _) in lower.match_indices(&term) {
+            hits[start..start + term.len()].fill(true);
+        }
+    }
+
+    let matched = base.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for i in 1..=text.len() {
+        if i == text.len() || (hits[i] != hits[start] && text.is_char_boundary(i)) {
+            let style = if hits[start] { matched } else { base };
+            spans.push(Span::styled(text[start..i].to_string(), style));
+            start = i;
+        }
+    }
+    spans
+}
+
+/// Smallest terminal the multi-panel layout stays readable in.
+const MIN_WIDTH: u16 = 80;
+const MIN_HEIGHT: u16 = 20;
+
 /// Create a centered rect using fixed width and height
 pub(super) fn centered_rect_fixed(width: u16, height: u16, area: Rect) -> Rect {
     let x = area.x + (area.width.saturating_sub(width)) / 2;
@@ -176,6 +232,16 @@ pub(super) fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect
 }
 
 pub fn draw(f: &mut Frame, app: &mut App) {
+    app.list_areas.clear();
+    if let Some(started) = app.providers_loading {
+        draw_loading(f, started);
+        return;
+    }
+    if f.area().width < MIN_WIDTH || f.area().height < MIN_HEIGHT {
+        draw_too_small(f);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -206,7 +272,7 @@ pub fn draw(f: &mut Frame, app: &mut App) {
 
     // Draw help popup on top if visible
     if app.show_help {
-        draw_help_popup(f, &app.help_scroll, app.current_tab);
+        draw_help_popup(f, &app.help_scroll, &app.help_filter, app.current_tab);
     }
 
     // Draw picker modal on top if visible (agents tab only)
@@ -217,6 +283,115 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             }
         }
     }
+
+    if let Some(palette) = &app.palette {
+        draw_palette(f, palette);
+    }
+
+    if app.show_status_log {
+        draw_status_log(f, app);
+    }
+
+    if app.show_comparison {
+        draw_comparison(f, app);
+    }
+}
+
+/// Command palette: query line over the ranked provider/model matches.
+fn draw_palette(f: &mut Frame, palette: &CommandPalette) {
+    let width = std::cmp::min(70, f.area().width.saturating_sub(4));
+    let height = std::cmp::min(20, f.area().height.saturating_sub(4));
+    let area = centered_rect_fixed(width, height, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(" Jump to provider/model ")
+        .title_bottom(Line::from(format!(" {} matches ", palette.match_count())).centered());
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+
+    let input = Line::from(vec![
+        Span::styled(": ", Style::default().fg(Color::Cyan)),
+        Span::raw(palette.query.as_str()),
+        Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+    ]);
+    f.render_widget(Paragraph::new(input), chunks[0]);
+
+    let items: Vec<ListItem> = palette
+        .matches()
+        .map(|(target, label)| {
+            let kind = match target {
+                PaletteTarget::Provider(_) => "provider",
+                PaletteTarget::Model { .. } => "model",
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(label.as_str()),
+                Span::styled(format!("  {kind}"), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut state = ListState::default();
+    state.select(Some(palette.selected));
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
+
+fn draw_loading(f: &mut Frame, started: Instant) {
+    let g = glyphs();
+    let frame = (started.elapsed().as_millis() / 100) as usize % g.spinner.len();
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(g.spinner[frame], Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" Loading models.dev{}", g.ellipsis)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "q to quit",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    let area = centered_rect_fixed(30, lines.len() as u16, f.area());
+    f.render_widget(
+        Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center),
+        area,
+    );
+}
+
+/// Shown instead of the layout until the terminal reaches `MIN_WIDTH`x`MIN_HEIGHT`.
+fn draw_too_small(f: &mut Frame) {
+    let size = f.area();
+    let lines = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!("Resize to at least {MIN_WIDTH}x{MIN_HEIGHT}")),
+        Line::from(Span::styled(
+            format!("(now {}x{})", size.width, size.height),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+    let height = (lines.len() as u16).min(size.height);
+    let area = centered_rect_fixed(size.width, height, size);
+    f.render_widget(
+        Paragraph::new(lines)
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        area,
+    );
 }
 
 fn draw_header(f: &mut Frame, area: Rect, app: &App) {
@@ -241,7 +416,33 @@ fn draw_header(f: &mut Frame, area: Rect, app: &App) {
         Span::styled("Status", tab_style(Tab::Status)),
         Span::styled("  [/] switch tabs", Style::default().fg(Color::DarkGray)),
     ]));
-    f.render_widget(header, area);
+
+    let source_color = match app.data_source {
+        DataSource::Live => Color::Green,
+        DataSource::Cached { .. } => Color::Yellow,
+        DataSource::File => Color::Cyan,
+    };
+    let source = Line::from(vec![
+        Span::styled(
+            format!("{} ", glyphs().dot),
+            Style::default().fg(source_color),
+        ),
+        Span::styled(
+            format!("{} ", app.data_source.label()),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    let source_width = source.width() as u16;
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(source_width)])
+        .split(area);
+    f.render_widget(header, chunks[0]);
+    f.render_widget(
+        Paragraph::new(source).alignment(ratatui::layout::Alignment::Right),
+        chunks[1],
+    );
 }
 
 fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
@@ -258,17 +459,28 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
 
     match app.mode {
         Mode::Normal => {
-            // Split footer into left and right sections
-            let chunks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Min(0), Constraint::Length(10)])
-                .split(area);
-
             let left_content = match app.current_tab {
+                Tab::Models if !app.model_selections.is_empty() => Line::from(vec![
+                    Span::styled(
+                        format!(" {} selected  ", app.model_selections.len()),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(" Space ", Style::default().fg(Color::Yellow)),
+                    Span::raw("toggle  "),
+                    Span::styled(" y ", Style::default().fg(Color::Yellow)),
+                    Span::raw("copy selected  "),
+                    Span::styled(" / ", Style::default().fg(Color::Yellow)),
+                    Span::raw("search  "),
+                    Span::styled(" 1-7 ", Style::default().fg(Color::Yellow)),
+                    Span::raw("filter"),
+                ]),
                 Tab::Models => Line::from(vec![
                     Span::styled(" q ", Style::default().fg(Color::Yellow)),
                     Span::raw("quit  "),
-                    Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+                    Span::styled(
+                        format!(" {}/{} ", glyphs().up, glyphs().down),
+                        Style::default().fg(Color::Yellow),
+                    ),
                     Span::raw("nav  "),
                     Span::styled(" Tab ", Style::default().fg(Color::Yellow)),
                     Span::raw("switch  "),
@@ -276,7 +488,7 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
                     Span::raw("search  "),
                     Span::styled(" s/S ", Style::default().fg(Color::Yellow)),
                     Span::raw("sort  "),
-                    Span::styled(" 1-6 ", Style::default().fg(Color::Yellow)),
+                    Span::styled(" 1-7 ", Style::default().fg(Color::Yellow)),
                     Span::raw("filter  "),
                     Span::styled(" c ", Style::default().fg(Color::Yellow)),
                     Span::raw("copy"),
@@ -396,11 +608,35 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
                 }
             };
 
-            let right_content = Line::from(vec![
-                Span::styled(" ? ", Style::default().fg(Color::Yellow)),
-                Span::raw("help "),
-            ]);
+            // Pending count prefix (e.g. "5" before `j`) shown like vim's showcmd
+            let mut right_spans = Vec::new();
+            if let Some((row, len)) = app.list_position() {
+                right_spans.push(Span::styled(
+                    format!("row {row}/{len} "),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            if let Some(prefix) = &app.count_prefix {
+                right_spans.push(Span::styled(
+                    prefix.digits.clone(),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+            if app.pending_g.is_some() {
+                right_spans.push(Span::styled("g", Style::default().fg(Color::Cyan)));
+            }
+            right_spans.push(Span::styled(" ? ", Style::default().fg(Color::Yellow)));
+            right_spans.push(Span::raw("help "));
+            let right_content = Line::from(right_spans);
 
+            // Split footer into left and right sections
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Min(0),
+                    Constraint::Length(right_content.width() as u16 + 1),
+                ])
+                .split(area);
             f.render_widget(Paragraph::new(left_content), chunks[0]);
             f.render_widget(
                 Paragraph::new(right_content).alignment(ratatui::layout::Alignment::Right),
@@ -423,190 +659,204 @@ fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
                     .map(|a| &a.search_query)
                     .unwrap_or(&app.models_app.search_query),
             };
-            let content = Line::from(vec![
-                Span::styled(" Search: ", Style::default().fg(Color::Cyan)),
+            // `^` (prefix matching) is only understood by the Models search
+            let prefix_mode =
+                app.current_tab == Tab::Models && search_query.trim_start().starts_with('^');
+            let label = if prefix_mode {
+                " Prefix search: "
+            } else {
+                " Search: "
+            };
+            let mut spans = vec![
+                Span::styled(label, Style::default().fg(Color::Cyan)),
                 Span::raw(search_query),
                 Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
                 Span::raw("  "),
+            ];
+            // Status has no regex search; every other tab falls back to literal
+            if app.current_tab != Tab::Status {
+                if let Some(err) = crate::formatting::search_regex_error(search_query) {
+                    spans.push(Span::styled(
+                        format!("invalid regex ({err}), searching literally  "),
+                        Style::default().fg(Color::Red),
+                    ));
+                }
+            }
+            spans.extend([
                 Span::styled(" Enter/Esc ", Style::default().fg(Color::Yellow)),
                 Span::raw("confirm"),
             ]);
+            let content = Line::from(spans);
+            f.render_widget(Paragraph::new(content), area);
+        }
+        Mode::MinScore => {
+            let content = Line::from(vec![
+                Span::styled(
+                    format!(
+                        " Min {} {} ",
+                        app.benchmarks_app.sort_column.label(),
+                        glyphs().at_least
+                    ),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(app.benchmarks_app.min_score_input.as_str()),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+                Span::raw("  "),
+                Span::styled(" Enter ", Style::default().fg(Color::Yellow)),
+                Span::raw("apply (blank clears)  "),
+                Span::styled(" Esc ", Style::default().fg(Color::Yellow)),
+                Span::raw("cancel"),
+            ]);
+            f.render_widget(Paragraph::new(content), area);
+        }
+        Mode::Palette => {
+            let g = glyphs();
+            let content = Line::from(vec![
+                Span::styled(
+                    format!(" {}/{} ", g.up, g.down),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw("select  "),
+                Span::styled(" Enter ", Style::default().fg(Color::Yellow)),
+                Span::raw("jump  "),
+                Span::styled(" Esc ", Style::default().fg(Color::Yellow)),
+                Span::raw("cancel"),
+            ]);
             f.render_widget(Paragraph::new(content), area);
         }
     };
 }
 
-fn draw_help_popup(f: &mut Frame, scroll: &ScrollOffset, current_tab: Tab) {
+fn draw_help_popup(f: &mut Frame, scroll: &ScrollOffset, filter: &str, current_tab: Tab) {
     let area = centered_rect(50, 70, f.area());
 
     // Clear the area behind the popup
     f.render_widget(Clear, area);
 
-    let help_section = |title: &'static str| -> Line<'static> {
-        Line::from(Span::styled(
-            title,
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        ))
-    };
-
+    let g = glyphs();
     let mut help_text = vec![
         // Common: Navigation
         help_section("Navigation"),
-        help_line("j/↓", "Move down"),
-        help_line("k/↑", "Move up"),
+        help_line(&format!("j/{}", g.down), "Move down"),
+        help_line(&format!("k/{}", g.up), "Move up"),
         help_line("g", "First item"),
         help_line("G", "Last item"),
         help_line("Ctrl+d/PgDn", "Page down"),
         help_line("Ctrl+u/PgUp", "Page up"),
+        help_line("5j / 3Ctrl+d", "Repeat a move with a count"),
         Line::from(""),
         // Common: Panels
         help_section("Panels"),
-        help_line("h/←/l/→", "Switch panels"),
+        help_line(&format!("h/{}/l/{}", g.left, g.right), "Switch panels"),
         help_line("Tab", "Switch panels"),
         Line::from(""),
         // Common: Search
         help_section("Search"),
         help_line("/", "Start search"),
         help_line("Enter/Esc", "Exit search mode"),
+        help_line(&format!("{}/{}", g.up, g.down), "Recall previous searches"),
         help_line("Esc", "Clear search (in normal mode)"),
+        help_line("^text", "Prefix match on id/name (Models)"),
+        help_line("/regex/", "Regex match (not Status)"),
         Line::from(""),
     ];
 
-    // Tab-specific sections
+    // Tab-specific bindings, then reference sections that aren't keys
+    help_text.extend(keymap_help_lines(keymap::tab_sections(current_tab)));
     match current_tab {
         Tab::Models => {
             help_text.extend(vec![
-                help_section("Filters & Sort"),
-                help_line("s", "Cycle sort (name → date → cost → context)"),
-                help_line("S", "Toggle sort direction"),
-                help_line("1", "Toggle reasoning models filter"),
-                help_line("2", "Toggle tools filter"),
-                help_line("3", "Toggle open weights filter"),
-                help_line("4", "Toggle free models filter"),
-                help_line("5", "Cycle provider category filter"),
-                help_line("6", "Toggle category grouping"),
-                Line::from(""),
-                help_section("Copy & Open"),
-                help_line("c", "Copy provider/model"),
-                help_line("C", "Copy model only"),
-                help_line("o", "Open provider docs in browser"),
-                help_line("D", "Copy provider docs URL"),
-                help_line("A", "Copy provider API URL"),
+                help_section("Search Keywords"),
+                help_line("reasoning", "Require reasoning (also: tools)"),
+                help_line("files/vision", "Require attachments / image input"),
+                help_line("open/free", "Require open weights / free"),
                 Line::from(""),
+                help_section("Caps Column (display.capability_glyphs)"),
             ]);
+            // Emoji are two cells wide, so pad by width rather than `help_line`'s char count
+            let ascii = crate::formatting::ascii();
+            help_text.extend(super::models::render::CAPABILITY_GLYPHS.iter().map(
+                |(_, emoji, marker, legend)| {
+                    let (glyph, width) = if ascii { (*marker, 1) } else { (*emoji, 2) };
+                    Line::from(vec![
+                        Span::styled(
+                            format!("  {}{}", glyph, " ".repeat(14 - width)),
+                            Style::default().fg(Color::Yellow),
+                        ),
+                        Span::raw(*legend),
+                    ])
+                },
+            ));
+            help_text.push(Line::from(""));
         }
         Tab::Agents => {
             help_text.extend(vec![
-                help_section("Filters & Sort"),
-                help_line("s", "Cycle sort (name → updated → stars → status)"),
-                help_line("1", "Toggle installed filter"),
-                help_line("2", "Toggle CLI filter"),
-                help_line("3", "Toggle open source filter"),
-                Line::from(""),
-                help_section("Actions"),
-                help_line("o", "Open docs in browser"),
-                help_line("r", "Open GitHub repo in browser"),
-                help_line("c", "Copy agent name"),
-                help_line("a", "Add/remove tracked agents"),
-                Line::from(""),
-                help_section("Search Navigation"),
-                help_line("n", "Next search match"),
-                help_line("N", "Previous search match"),
-                Line::from(""),
                 help_section("Status Indicators"),
                 Line::from(vec![
                     Span::styled(
-                        format!("  {:<14}", "○"),
+                        format!("  {:<14}", g.hollow_dot),
                         Style::default().fg(Color::DarkGray),
                     ),
                     Span::raw("Not tracked"),
                 ]),
                 Line::from(vec![
-                    Span::styled(format!("  {:<14}", "◐"), Style::default().fg(Color::Yellow)),
+                    Span::styled(
+                        format!("  {:<14}", g.half_dot),
+                        Style::default().fg(Color::Yellow),
+                    ),
                     Span::raw("Loading GitHub data"),
                 ]),
                 Line::from(vec![
-                    Span::styled(format!("  {:<14}", "●"), Style::default().fg(Color::Green)),
+                    Span::styled(
+                        format!("  {:<14}", g.dot),
+                        Style::default().fg(Color::Green),
+                    ),
                     Span::raw("Up to date"),
                 ]),
                 Line::from(vec![
-                    Span::styled(format!("  {:<14}", "●"), Style::default().fg(Color::Blue)),
+                    Span::styled(format!("  {:<14}", g.dot), Style::default().fg(Color::Blue)),
                     Span::raw("Update available"),
                 ]),
                 Line::from(vec![
-                    Span::styled(format!("  {:<14}", "✗"), Style::default().fg(Color::Red)),
+                    Span::styled(
+                        format!("  {:<14}", g.cross),
+                        Style::default().fg(Color::Red),
+                    ),
                     Span::raw("Fetch failed"),
                 ]),
                 Line::from(""),
             ]);
         }
-        Tab::Benchmarks => {
-            help_text.extend(vec![
-                help_section("Quick Sort (press again to flip direction)"),
-                help_line("1", "Sort by Intelligence index"),
-                help_line("2", "Sort by Release date"),
-                help_line("3", "Sort by Speed (tok/s)"),
-                Line::from(""),
-                help_section("Filters"),
-                help_line("4", "Cycle source filter (Open/Closed/Mixed)"),
-                help_line("5", "Cycle region filter (US/China/Europe/...)"),
-                help_line("6", "Cycle type filter (Startup/Big Tech/Research)"),
-                help_line("7", "Cycle reasoning filter (All/Reasoning/Non-reasoning)"),
-                Line::from(""),
-                help_section("Sort (full cycle)"),
-                help_line("s", "Open sort picker"),
-                help_line("S", "Toggle sort direction"),
-                Line::from(""),
-                help_section("Actions"),
-                help_line("o", "Open Artificial Analysis page"),
-                Line::from(""),
-                help_section("Compare"),
-                help_line("Space", "Toggle model for comparison (max 8)"),
-                help_line("c", "Clear all selections"),
-                help_line("v", "Cycle view: H2H → Scatter → Radar"),
-                help_line("d", "Show detail overlay (H2H view)"),
-                help_line("x", "Cycle scatter X-axis"),
-                help_line("y", "Cycle scatter Y-axis"),
-                help_line("a", "Cycle radar preset"),
-                help_line("j/k", "Scroll H2H table (when Compare focused)"),
-                help_line("h/l", "Switch focus: List ↔ Compare"),
-                help_line("t", "Toggle left panel: Models ↔ Creators"),
-                Line::from(""),
-            ]);
-        }
-        Tab::Status => {
-            help_text.extend(vec![
-                help_section("Actions"),
-                help_line("o", "Open provider status page"),
-                help_line("r", "Refresh provider status"),
-                help_line("a", "Add/remove tracked providers"),
-                Line::from(""),
-                help_section("Status view"),
-                help_line("Tab/h/l", "Switch list/details focus"),
-                help_line("/", "Search providers"),
-                Line::from(""),
-            ]);
-        }
+        Tab::Benchmarks | Tab::Status => {}
     }
 
     // Common: Tabs and Other
-    help_text.extend(vec![
-        help_section("Tabs"),
-        help_line("[", "Previous tab"),
-        help_line("]", "Next tab"),
-        Line::from(""),
-        help_section("Other"),
-        help_line("q", "Quit"),
-        help_line("?", "Toggle this help"),
-    ]);
+    help_text.extend(keymap_help_lines(keymap::GLOBAL));
 
-    let title = match current_tab {
-        Tab::Models => "Models Help - ? or Esc to close (j/k to scroll)",
-        Tab::Agents => "Agents Help - ? or Esc to close (j/k to scroll)",
-        Tab::Benchmarks => "Benchmarks Help - ? or Esc to close (j/k to scroll)",
-        Tab::Status => "Status Help - ? or Esc to close (j/k to scroll)",
+    let tab = match current_tab {
+        Tab::Models => "Models",
+        Tab::Agents => "Agents",
+        Tab::Benchmarks => "Benchmarks",
+        Tab::Status => "Status",
+    };
+    let (title, help_text) = if filter.is_empty() {
+        let title = format!(
+            "{tab} Help - ? or Esc to close (type to filter, {}/{} to scroll)",
+            g.up, g.down
+        );
+        (title, help_text)
+    } else {
+        let mut matching = filter_help_lines(help_text, filter);
+        if matching.is_empty() {
+            matching.push(Line::from(Span::styled(
+                "No keybindings match",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        (
+            format!("{tab} Help - filter: {filter} (Esc to clear)"),
+            matching,
+        )
     };
 
     ScrollablePanel::new(title, help_text, scroll, true)
@@ -614,11 +864,209 @@ fn draw_help_popup(f: &mut Frame, scroll: &ScrollOffset, current_tab: Tab) {
         .render(f, area);
 }
 
+/// Help rows for keymap sections: one per listed binding, then the notes.
+fn keymap_help_lines(sections: &[keymap::Section]) -> Vec<Line<'static>> {
+    let g = glyphs();
+    let desc = |text: &str| {
+        text.replace("{then}", g.then)
+            .replace("{both}", g.both_ways)
+    };
+    let mut lines = Vec::new();
+    for section in sections {
+        lines.push(help_section(section.title));
+        for binding in section.bindings {
+            if let Some(keys) = binding.keys {
+                lines.push(help_line(keys, desc(binding.desc)));
+            }
+        }
+        for (keys, text) in section.notes {
+            lines.push(help_line(keys, desc(text)));
+        }
+        lines.push(Line::from(""));
+    }
+    lines
+}
+
+/// Help lines containing `filter` (case-insensitive), each under its section
+/// header. Blank spacer lines and sections with no match are dropped.
+fn filter_help_lines<'a>(lines: Vec<Line<'a>>, filter: &str) -> Vec<Line<'a>> {
+    let filter = filter.to_lowercase();
+    let mut out = Vec::new();
+    let mut header: Option<Line<'a>> = None;
+    for line in lines {
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        if text.trim().is_empty() {
+            continue;
+        }
+        if !text.starts_with(' ') {
+            header = Some(line);
+            continue;
+        }
+        if text.to_lowercase().contains(&filter) {
+            if let Some(header) = header.take() {
+                if !out.is_empty() {
+                    out.push(Line::from(""));
+                }
+                out.push(header);
+            }
+            out.push(line);
+        }
+    }
+    out
+}
+
+/// Recent status messages, newest first.
+fn draw_status_log(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let lines: Vec<Line> = if app.status_log.is_empty() {
+        vec![Line::from(Span::styled(
+            "No status messages yet",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        app.status_log
+            .iter()
+            .rev()
+            .map(|(time, msg)| {
+                Line::from(vec![
+                    Span::styled(format!("{time}  "), Style::default().fg(Color::DarkGray)),
+                    Span::raw(msg.clone()),
+                ])
+            })
+            .collect()
+    };
+
+    ScrollablePanel::new(
+        "Status Log - L or Esc to close (j/k to scroll)",
+        lines,
+        &app.status_log_scroll,
+        true,
+    )
+    .render(f, area);
+}
+
+/// Items added with `+`, one column each, models.dev details above
+/// benchmark scores.
+fn draw_comparison(f: &mut Frame, app: &App) {
+    use super::comparison::{comparison_rows, MAX_COMPARISON};
+
+    let area = centered_rect(90, 70, f.area());
+    f.render_widget(Clear, area);
+
+    let entries = app.benchmark_store.entries();
+    let lines: Vec<Line> = if app.comparison.is_empty() {
+        vec![Line::from(Span::styled(
+            "Nothing to compare yet: press + on a model or benchmark entry",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        const LABEL_W: usize = 15;
+        let inner_w = area.width.saturating_sub(2) as usize;
+        let col_w = (inner_w.saturating_sub(LABEL_W) / app.comparison.len()).max(8);
+        let cell = |text: &str| format!("{:<col_w$}", crate::formatting::truncate(text, col_w - 1));
+
+        let mut header = vec![Span::raw(" ".repeat(LABEL_W))];
+        header.extend(app.comparison.iter().map(|item| {
+            Span::styled(
+                cell(&item.label(entries)),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+        }));
+        let mut lines = vec![Line::from(header)];
+        for (label, cells) in comparison_rows(&app.comparison, &app.providers, entries) {
+            if label == "Intelligence" {
+                lines.push(Line::from(""));
+            }
+            let mut spans = vec![Span::styled(
+                format!("{label:<LABEL_W$}"),
+                Style::default().fg(Color::Gray),
+            )];
+            spans.extend(cells.iter().map(|text| Span::raw(cell(text))));
+            lines.push(Line::from(spans));
+        }
+        lines
+    };
+
+    let title = format!(
+        "Comparison ({}/{MAX_COMPARISON}) - V or Esc to close, c to clear (j/k to scroll)",
+        app.comparison.len()
+    );
+    ScrollablePanel::new(title.as_str(), lines, &app.comparison_scroll, true)
+        .with_wrap(false)
+        .render(f, area);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ratatui::text::Line;
 
+    #[test]
+    fn highlight_matches_marks_each_term() {
+        let base = Style::default();
+        let spans = highlight_matches(
+            "GPT-4o mini",
+            &["gpt".to_string(), "mini".to_string()],
+            base,
+        );
+        let texts: Vec<&str> = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(texts, vec!["GPT", "-4o ", "mini"]);
+        assert!(spans[0].style.add_modifier.contains(Modifier::UNDERLINED));
+        assert_eq!(spans[1].style, base);
+
+        let plain = highlight_matches("claude", &[], base);
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain[0].content, "claude");
+    }
+
+    #[test]
+    fn help_filter_keeps_matching_lines_under_their_section() {
+        let lines = vec![
+            Line::from("Navigation"),
+            help_line("g", "First item"),
+            help_line("G", "Last item"),
+            Line::from(""),
+            Line::from("Other"),
+            help_line("q", "Quit"),
+        ];
+        let filtered = filter_help_lines(lines.clone(), "LAST");
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[0].to_string(), "Navigation");
+        assert!(filtered[1].to_string().ends_with("Last item"));
+        assert!(filter_help_lines(lines, "zzz").is_empty());
+    }
+
+    fn render_text(app: &mut App, width: u16, height: u16) -> String {
+        let backend = ratatui::backend::TestBackend::new(width, height);
+        let mut terminal = ratatui::Terminal::new(backend).expect("terminal");
+        terminal.draw(|f| draw(f, app)).expect("draw succeeds");
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|pos| buffer[pos].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn small_terminal_shows_resize_message() {
+        let mut app = App::new(
+            std::collections::HashMap::new(),
+            None,
+            None,
+            crate::benchmarks::BenchmarkStore::empty(),
+        );
+        let small = render_text(&mut app, 60, 15);
+        assert!(small.contains("Terminal too small"));
+        assert!(small.contains("(now 60x15)"));
+
+        let full = render_text(&mut app, MIN_WIDTH, MIN_HEIGHT);
+        assert!(!full.contains("Terminal too small"));
+    }
+
     #[test]
     fn visual_line_height_empty() {
         let line = Line::from("");
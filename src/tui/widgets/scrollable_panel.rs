@@ -40,6 +40,7 @@ pub struct ScrollablePanel<'a> {
     scroll: &'a ScrollOffset,
     focused: bool,
     wrap: bool,
+    flat: bool,
 }
 
 impl<'a> ScrollablePanel<'a> {
@@ -56,6 +57,7 @@ impl<'a> ScrollablePanel<'a> {
             scroll,
             focused,
             wrap: true,
+            flat: false,
         }
     }
 
@@ -73,6 +75,7 @@ impl<'a> ScrollablePanel<'a> {
             scroll,
             focused,
             wrap: true,
+            flat: false,
         }
     }
 
@@ -92,6 +95,13 @@ impl<'a> ScrollablePanel<'a> {
         self
     }
 
+    /// Drop the border and scrollbar so the text copies cleanly; the title
+    /// keeps the top row. Card panels ignore this.
+    pub fn with_flat(mut self, flat: bool) -> Self {
+        self.flat = flat;
+        self
+    }
+
     /// Render the panel into the given area and return computed state.
     pub fn render(self, f: &mut Frame, area: ratatui::layout::Rect) -> ScrollablePanelState {
         let title = self.title;
@@ -101,14 +111,23 @@ impl<'a> ScrollablePanel<'a> {
         if let Some(cards) = self.cards {
             Self::render_cards_inner(f, area, cards, title, scroll, focused)
         } else {
+            let border_style = focus_border(focused);
+            let block = if self.flat {
+                Block::default().title(title).title_style(border_style)
+            } else {
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title(title)
+            };
             Self::render_lines_inner(
                 f,
                 area,
                 self.lines.unwrap_or_default(),
-                title,
+                block,
                 scroll,
-                focused,
                 wrap,
+                !self.flat,
             )
         }
     }
@@ -227,21 +246,15 @@ impl<'a> ScrollablePanel<'a> {
         f: &mut Frame,
         area: ratatui::layout::Rect,
         lines: Vec<Line<'a>>,
-        title: Line<'_>,
+        block: Block<'_>,
         scroll: &ScrollOffset,
-        focused: bool,
         wrap: bool,
+        scrollbar: bool,
     ) -> ScrollablePanelState {
-        let border_style = focus_border(focused);
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(border_style)
-            .title(title);
-
-        let visible_height = area.height.saturating_sub(2);
+        let inner = block.inner(area);
+        let visible_height = inner.height;
         let (visual_total, visual_offsets) = if wrap {
-            let wrap_width = area.width.saturating_sub(2) as usize;
-            wrapped_line_offsets(&lines, wrap_width)
+            wrapped_line_offsets(&lines, inner.width as usize)
         } else {
             let total = lines.len() as u16;
             let offsets = (0..total).collect();
@@ -264,7 +277,7 @@ impl<'a> ScrollablePanel<'a> {
         f.render_widget(paragraph, area);
 
         // Scrollbar
-        if (visual_total as usize) > (visible_height as usize) {
+        if scrollbar && (visual_total as usize) > (visible_height as usize) {
             let scroll_area = area.inner(Margin {
                 vertical: 1,
                 horizontal: 0,